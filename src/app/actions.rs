@@ -14,10 +14,14 @@ pub enum Action {
     Delete,
     DeleteBoard,
     Down,
+    DuplicateBoard,
+    DuplicateCard,
     Accept,
+    GoToBoard,
     GoToMainMenu,
     GoToPreviousViewOrCancel,
     HideUiElement,
+    JumpToBoard,
     Left,
     MoveCardDown,
     MoveCardLeft,
@@ -27,14 +31,19 @@ pub enum Action {
     NewCard,
     NextFocus,
     OpenConfigMenu,
+    OpenBulkActions,
+    OpenUndoHistory,
     PrvFocus,
     Quit,
     Redo,
     ResetUI,
     Right,
     SaveState,
+    SelectAllCardsInBoard,
+    SelectCardRange,
     StopUserInput,
     TakeUserInput,
+    ToggleCardSelection,
     ToggleCommandPalette,
     Undo,
     Up,
@@ -59,10 +68,14 @@ impl Display for Action {
             Action::Delete => "Delete focused element",
             Action::DeleteBoard => "Delete Board",
             Action::Down => "Go down",
+            Action::DuplicateBoard => "Duplicate current board with its cards",
+            Action::DuplicateCard => "Duplicate current card into a board",
             Action::Accept => "Accept",
+            Action::GoToBoard => "Go to board",
             Action::GoToMainMenu => "Go to main menu",
             Action::GoToPreviousViewOrCancel => "Go to previous View or cancel",
             Action::HideUiElement => "Hide Focused element",
+            Action::JumpToBoard => "Jump to board by number",
             Action::Left => "Go left",
             Action::MoveCardDown => "Move card down",
             Action::MoveCardLeft => "Move card left",
@@ -72,14 +85,19 @@ impl Display for Action {
             Action::NewCard => "Create new card in current board",
             Action::NextFocus => "Focus next",
             Action::OpenConfigMenu => "Configure",
+            Action::OpenBulkActions => "Open bulk actions for selected cards",
+            Action::OpenUndoHistory => "Open Undo History",
             Action::PrvFocus => "Focus previous",
             Action::Quit => "Quit",
             Action::Redo => "Redo",
             Action::ResetUI => "Reset UI",
             Action::Right => "Go right",
             Action::SaveState => "Save Kanban state",
+            Action::SelectAllCardsInBoard => "Select all cards in current board",
+            Action::SelectCardRange => "Select cards up to current card",
             Action::StopUserInput => "Stop input mode",
             Action::TakeUserInput => "Enter input mode",
+            Action::ToggleCardSelection => "Toggle card selection",
             Action::ToggleCommandPalette => "Open command palette",
             Action::Undo => "Undo",
             Action::Up => "Go up",
@@ -1,20 +1,30 @@
 use crate::{
     app::{
         actions::Action,
+        board_template::BoardSetTemplate,
+        card_template::CardTemplate,
         handle_exit,
         kanban::{Board, Boards, Card, CardPriority, CardStatus, Cards},
-        state::{AppState, AppStatus, Focus, KeyBindings, PathCheckState},
-        ActionHistory, App, AppConfig, AppReturn, ConfigEnum, DateTimeFormat, MainMenuItem,
-        VisibleBoardsAndCards,
+        merge::{merge_boards, MergeSide, MergeSource, PendingMerge},
+        natural_date::parse_due_date,
+        reminders::parse_reminder_offsets,
+        state::{
+            AppState, AppStatus, ExternalSaveChangeAction, Focus, KeyBindings, PathCheckState,
+        },
+        ActionHistory, App, AppConfig, AppReturn, BulkCardAction, ConfigEnum, DateTimeFormat,
+        MainMenuItem, TagManagerAction, VisibleBoardsAndCards,
     },
     constants::{
-        DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, MOUSE_OUT_OF_BOUNDS_COORDINATES,
+        DEFAULT_TOAST_DURATION, IO_EVENT_WAIT_TIME, MIN_TERM_WIDTH, MOUSE_OUT_OF_BOUNDS_COORDINATES,
     },
     error::NavigationError,
     inputs::{key::Key, mouse::Mouse},
     io::{
-        data_handler::{get_config, save_theme, write_config},
-        io_handler::refresh_visible_boards_and_cards,
+        data_handler::{
+            get_config, save_board_template, save_card_template, save_theme, write_config,
+        },
+        io_handler::{refresh_visible_boards_and_cards, refresh_visible_boards_and_cards_around},
+        todo_txt::parse_todo_txt,
         IoEvent,
     },
     ui::{
@@ -23,20 +33,20 @@ use crate::{
         widgets::{
             command_palette::CommandPaletteWidget,
             toast::{Toast, ToastType},
+            SelfViewportCorrection,
         },
         PopUp, TextColorOptions, TextModifierOptions, View,
     },
     util::{
-        date_format_converter, date_format_finder, get_first_next_focus_keybinding,
-        get_first_prv_focus_keybinding, parse_hex_to_rgb, send_error_toast, send_info_toast,
-        send_warning_toast, send_warning_toast_with_duration, update_current_board_and_card,
-        update_current_visible_boards_and_cards,
+        get_first_next_focus_keybinding, get_first_prv_focus_keybinding, parse_hex_to_rgb,
+        send_error_toast, send_info_toast, send_warning_toast, send_warning_toast_with_duration,
+        update_current_board_and_card, update_current_visible_boards_and_cards,
     },
 };
-use chrono::NaiveDateTime;
+use chrono::Local;
 use linked_hash_map::LinkedHashMap;
-use ratatui::{style::Color, widgets::ListState};
-use std::{fs, path::Path, str::FromStr, time::Duration};
+use ratatui::{layout::Rect, style::Color, widgets::ListState};
+use std::{collections::HashMap, fs, path::Path, str::FromStr, time::Duration};
 use strum::IntoEnumIterator;
 
 /// Enum to represent the direction of navigation a user inputs while browsing boards and cards
@@ -579,6 +589,18 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                 PopUp::CustomHexColorPromptBG | PopUp::CustomHexColorPromptFG => {
                     app.close_popup();
                 }
+                PopUp::GoToBoard
+                | PopUp::MoveSelectedCardsToBoard
+                | PopUp::DuplicateCardToBoard
+                | PopUp::BulkEditPrompt
+                | PopUp::DueDatePrompt
+                | PopUp::ImportTodoTxtPrompt
+                | PopUp::EncryptionPassphrasePrompt
+                | PopUp::SaveCardTemplatePrompt
+                | PopUp::SaveBoardTemplatePrompt
+                | PopUp::TagManagerPrompt => {
+                    app.close_popup();
+                }
                 _ => {}
             }
         }
@@ -709,12 +731,21 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                 app.state.text_buffers.card_description.input(key);
             }
             Focus::CardDueDate => {
-                if app.state.card_being_edited.is_none()
-                    && app.state.z_stack.last() == Some(&PopUp::ViewCard)
-                {
-                    handle_edit_new_card(app);
+                if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else if key == Key::Enter {
+                    if app.state.card_being_edited.is_none()
+                        && app.state.z_stack.last() == Some(&PopUp::ViewCard)
+                    {
+                        handle_edit_new_card(app);
+                    }
+                    app.set_popup(PopUp::DateTimePicker);
+                } else {
+                    open_due_date_prompt(app);
+                    app.state.text_buffers.due_date.input(key);
                 }
-                app.set_popup(PopUp::DateTimePicker);
             }
             Focus::CardPriority => {
                 if app.config.keybindings.next_focus.contains(&key) {
@@ -734,6 +765,15 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     app.set_popup(PopUp::CardStatusSelector);
                 }
             }
+            Focus::ExtraFocus if app.state.current_view == View::NewCard => {
+                if app.config.keybindings.next_focus.contains(&key) {
+                    handle_next_focus(app);
+                } else if app.config.keybindings.prv_focus.contains(&key) {
+                    handle_prv_focus(app);
+                } else if key == Key::Enter {
+                    app.set_popup(PopUp::CardTemplatePicker);
+                }
+            }
             Focus::CardTags => {
                 if let Some((_, current_card)) = &mut app.state.card_being_edited {
                     match key {
@@ -1219,6 +1259,36 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         Some(PopUp::CustomHexColorPromptBG) => {
                             return handle_custom_hex_color_prompt(app, false)
                         }
+                        Some(PopUp::GoToBoard) => {
+                            handle_go_to_board_selection(app);
+                        }
+                        Some(PopUp::MoveSelectedCardsToBoard) => {
+                            handle_move_selected_cards_to_board(app);
+                        }
+                        Some(PopUp::DuplicateCardToBoard) => {
+                            handle_duplicate_card_to_board(app);
+                        }
+                        Some(PopUp::BulkEditPrompt) => {
+                            return handle_bulk_edit_prompt_submit(app);
+                        }
+                        Some(PopUp::DueDatePrompt) => {
+                            return handle_due_date_prompt_submit(app);
+                        }
+                        Some(PopUp::ImportTodoTxtPrompt) => {
+                            return handle_import_todo_txt_prompt_submit(app);
+                        }
+                        Some(PopUp::EncryptionPassphrasePrompt) => {
+                            return handle_encryption_passphrase_prompt_submit(app).await;
+                        }
+                        Some(PopUp::SaveCardTemplatePrompt) => {
+                            return handle_save_card_template_prompt_submit(app);
+                        }
+                        Some(PopUp::SaveBoardTemplatePrompt) => {
+                            return handle_save_board_template_prompt_submit(app);
+                        }
+                        Some(PopUp::TagManagerPrompt) => {
+                            return handle_tag_manager_prompt_submit(app);
+                        }
                         _ => {
                             log::debug!(
                                 "TextInput is not used in the current popup: {:?}",
@@ -1234,6 +1304,40 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         Some(PopUp::CustomHexColorPromptBG) => {
                             app.state.text_buffers.theme_editor_bg_hex.input(key);
                         }
+                        Some(
+                            PopUp::GoToBoard
+                            | PopUp::MoveSelectedCardsToBoard
+                            | PopUp::DuplicateCardToBoard,
+                        ) => match key {
+                            Key::Up => app.go_to_board_prv(),
+                            Key::Down => app.go_to_board_next(),
+                            _ => {
+                                if app.state.text_buffers.go_to_board.input(key) {
+                                    app.state.app_list_states.go_to_board.select(Some(0));
+                                }
+                            }
+                        },
+                        Some(PopUp::BulkEditPrompt) => {
+                            app.state.text_buffers.bulk_edit.input(key);
+                        }
+                        Some(PopUp::DueDatePrompt) => {
+                            app.state.text_buffers.due_date.input(key);
+                        }
+                        Some(PopUp::ImportTodoTxtPrompt) => {
+                            app.state.text_buffers.import_file_path.input(key);
+                        }
+                        Some(PopUp::EncryptionPassphrasePrompt) => {
+                            app.state.text_buffers.encryption_passphrase.input(key);
+                        }
+                        Some(PopUp::SaveCardTemplatePrompt) => {
+                            app.state.text_buffers.card_template_name.input(key);
+                        }
+                        Some(PopUp::SaveBoardTemplatePrompt) => {
+                            app.state.text_buffers.board_template_name.input(key);
+                        }
+                        Some(PopUp::TagManagerPrompt) => {
+                            app.state.text_buffers.tag_manager.input(key);
+                        }
                         _ => {
                             log::debug!(
                                 "No user input handler found for focus: {:?}",
@@ -1356,6 +1460,16 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                         PopUp::ChangeDateFormatPopup => app.change_date_format_popup_prv(),
                         PopUp::FilterByTag => app.filter_by_tag_popup_prv(),
+                        PopUp::GoToBoard
+                        | PopUp::MoveSelectedCardsToBoard
+                        | PopUp::DuplicateCardToBoard => app.go_to_board_prv(),
+                        PopUp::BulkActions => app.bulk_actions_prv(),
+                        PopUp::TagManagerActions => app.tag_manager_actions_prv(),
+                        PopUp::CardTemplatePicker => app.card_template_picker_prv(),
+                        PopUp::BoardTemplatePicker => app.board_template_picker_prv(),
+                        PopUp::UndoHistory => app.undo_history_prv(),
+                        PopUp::ResolveMergeConflicts => app.merge_conflicts_prv(),
+                        PopUp::ExternalSaveChanged => app.external_save_change_actions_prv(),
                         PopUp::ViewCard => {
                             if app.state.focus == Focus::CardDescription {
                                 app.state.text_buffers.card_description.scroll((-1, 0));
@@ -1416,6 +1530,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     View::EditKeybindings => {
                         app.edit_keybindings_prv();
                     }
+                    View::TagManager => {
+                        app.tag_manager_prv();
+                    }
                     View::CreateTheme => {
                         if app.state.focus == Focus::ThemeEditor {
                             app.select_create_theme_prv();
@@ -1472,6 +1589,16 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                         PopUp::ChangeDateFormatPopup => app.change_date_format_popup_next(),
                         PopUp::FilterByTag => app.filter_by_tag_popup_next(),
+                        PopUp::GoToBoard
+                        | PopUp::MoveSelectedCardsToBoard
+                        | PopUp::DuplicateCardToBoard => app.go_to_board_next(),
+                        PopUp::BulkActions => app.bulk_actions_next(),
+                        PopUp::TagManagerActions => app.tag_manager_actions_next(),
+                        PopUp::CardTemplatePicker => app.card_template_picker_next(),
+                        PopUp::BoardTemplatePicker => app.board_template_picker_next(),
+                        PopUp::UndoHistory => app.undo_history_next(),
+                        PopUp::ResolveMergeConflicts => app.merge_conflicts_next(),
+                        PopUp::ExternalSaveChanged => app.external_save_change_actions_next(),
                         PopUp::ViewCard => {
                             if app.state.focus == Focus::CardDescription {
                                 app.state.text_buffers.card_description.scroll((1, 0))
@@ -1535,6 +1662,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     View::EditKeybindings => {
                         app.edit_keybindings_next();
                     }
+                    View::TagManager => {
+                        app.tag_manager_next();
+                    }
                     View::CreateTheme => {
                         if app.state.focus == Focus::ThemeEditor {
                             app.select_create_theme_next();
@@ -1580,6 +1710,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                         }
+                        PopUp::ResolveMergeConflicts => {
+                            handle_merge_conflict_side_selection(app, Some(MergeSide::Theirs));
+                        }
                         _ => {}
                     }
                 } else if app.state.focus == Focus::Body
@@ -1599,6 +1732,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         PopUp::DateTimePicker => {
                             handle_date_time_picker_action(app, None, Some(action));
                         }
+                        PopUp::ResolveMergeConflicts => {
+                            handle_merge_conflict_side_selection(app, Some(MergeSide::Ours));
+                        }
                         _ => {}
                     }
                 } else if app.state.focus == Focus::Body
@@ -1609,6 +1745,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 AppReturn::Continue
             }
             Action::TakeUserInput => {
+                if app.state.focus == Focus::CardDueDate
+                    && (app.state.current_view == View::NewCard
+                        || app.state.z_stack.last() == Some(&PopUp::ViewCard))
+                {
+                    open_due_date_prompt(app);
+                    return AppReturn::Continue;
+                }
                 match app.state.current_view {
                     View::NewBoard | View::NewCard => {
                         app.state.app_status = AppStatus::UserInput;
@@ -1702,6 +1845,46 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                             // This is never reached as tag picker does not handle its own actions
                             return AppReturn::Continue;
                         }
+                        PopUp::GoToBoard => {
+                            handle_go_to_board_selection(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::MoveSelectedCardsToBoard => {
+                            handle_move_selected_cards_to_board(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::DuplicateCardToBoard => {
+                            handle_duplicate_card_to_board(app);
+                            return AppReturn::Continue;
+                        }
+                        PopUp::BulkActions => return handle_bulk_action_selection(app),
+                        PopUp::TagManagerActions => {
+                            return handle_tag_manager_action_selection(app)
+                        }
+                        PopUp::BulkEditPrompt => return handle_bulk_edit_prompt_submit(app),
+                        PopUp::DueDatePrompt => return handle_due_date_prompt_submit(app),
+                        PopUp::ImportTodoTxtPrompt => {
+                            return handle_import_todo_txt_prompt_submit(app)
+                        }
+                        PopUp::EncryptionPassphrasePrompt => {
+                            return handle_encryption_passphrase_prompt_submit(app).await
+                        }
+                        PopUp::CardTemplatePicker => return handle_card_template_selection(app),
+                        PopUp::SaveCardTemplatePrompt => {
+                            return handle_save_card_template_prompt_submit(app)
+                        }
+                        PopUp::BoardTemplatePicker => return handle_board_template_selection(app),
+                        PopUp::SaveBoardTemplatePrompt => {
+                            return handle_save_board_template_prompt_submit(app)
+                        }
+                        PopUp::TagManagerPrompt => return handle_tag_manager_prompt_submit(app),
+                        PopUp::UndoHistory => return handle_undo_history_selection(app),
+                        PopUp::ResolveMergeConflicts => {
+                            return handle_resolve_merge_conflicts(app).await
+                        }
+                        PopUp::ExternalSaveChanged => {
+                            return handle_external_save_change_action(app).await
+                        }
                     }
                     app.close_popup();
                     return AppReturn::Continue;
@@ -1736,6 +1919,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         handle_edit_keybindings_action(app);
                         AppReturn::Continue
                     }
+                    View::TagManager => handle_open_tag_manager_actions(app),
                     View::CreateTheme => {
                         handle_create_theme_action(app);
                         AppReturn::Continue
@@ -1923,6 +2107,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         return AppReturn::Continue;
                     }
                     match app.state.focus {
+                        Focus::Body if !app.state.selected_cards.is_empty() => {
+                            delete_selected_cards(app);
+                            AppReturn::Continue
+                        }
                         Focus::Body => {
                             if let Some(current_board_id) = app.state.current_board_id {
                                 if let Some(current_card_id) = app.state.current_card_id {
@@ -2038,6 +2226,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
             },
             Action::DeleteBoard => {
+                if app.state.current_view == View::LoadLocalSave {
+                    app.dispatch(IoEvent::PruneLocalSaves).await;
+                    return AppReturn::Continue;
+                }
                 if !View::views_with_kanban_board().contains(&app.state.current_view) {
                     return AppReturn::Continue;
                 }
@@ -2130,6 +2322,48 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 };
                 handle_change_card_priority(app, Some(CardPriority::Low))
             }
+            Action::GoToBoard => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || !app.state.z_stack.is_empty()
+                {
+                    return AppReturn::Continue;
+                }
+                if app.boards.is_empty() {
+                    send_warning_toast(&mut app.widgets.toast_widget, "No boards to go to");
+                    return AppReturn::Continue;
+                }
+                app.set_popup(PopUp::GoToBoard);
+                AppReturn::Continue
+            }
+            Action::ToggleCardSelection
+            | Action::SelectCardRange
+            | Action::SelectAllCardsInBoard
+            | Action::OpenBulkActions
+            | Action::DuplicateCard
+            | Action::DuplicateBoard => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.focus != Focus::Body
+                    || !app.state.z_stack.is_empty()
+                {
+                    return AppReturn::Continue;
+                }
+                match action {
+                    Action::ToggleCardSelection => handle_toggle_card_selection(app),
+                    Action::SelectCardRange => handle_select_card_range(app),
+                    Action::SelectAllCardsInBoard => handle_select_all_cards_in_board(app),
+                    Action::DuplicateCard => handle_open_duplicate_card(app),
+                    Action::DuplicateBoard => handle_duplicate_board(app, true),
+                    _ => handle_open_bulk_actions(app),
+                }
+            }
+            Action::JumpToBoard => {
+                if !View::views_with_kanban_board().contains(&app.state.current_view)
+                    || !app.state.z_stack.is_empty()
+                {
+                    return AppReturn::Continue;
+                }
+                handle_jump_to_board(app, key)
+            }
             Action::GoToMainMenu => {
                 match app.state.current_view {
                     View::NewBoard => {
@@ -2635,17 +2869,29 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 AppReturn::Continue
             }
             Action::Undo => {
-                if View::views_with_kanban_board().contains(&app.state.current_view) {
+                if View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.current_view == View::TagManager
+                {
                     app.undo();
                 }
                 AppReturn::Continue
             }
             Action::Redo => {
-                if View::views_with_kanban_board().contains(&app.state.current_view) {
+                if View::views_with_kanban_board().contains(&app.state.current_view)
+                    || app.state.current_view == View::TagManager
+                {
                     app.redo();
                 }
                 AppReturn::Continue
             }
+            Action::OpenUndoHistory => {
+                if View::views_with_kanban_board().contains(&app.state.current_view)
+                    && app.state.z_stack.is_empty()
+                {
+                    app.set_popup(PopUp::UndoHistory);
+                }
+                AppReturn::Continue
+            }
             Action::ClearAllToasts => {
                 app.widgets.toast_widget.toasts.clear();
                 log::info!("Cleared toast messages");
@@ -3011,6 +3257,166 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     }
                 }
             }
+            PopUp::GoToBoard | PopUp::MoveSelectedCardsToBoard | PopUp::DuplicateCardToBoard => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::ExtraFocus => match popup {
+                            PopUp::GoToBoard => handle_go_to_board_selection(app),
+                            PopUp::DuplicateCardToBoard => handle_duplicate_card_to_board(app),
+                            _ => handle_move_selected_cards_to_board(app),
+                        },
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ExtraFocus {
+                    app.go_to_board_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ExtraFocus {
+                    app.go_to_board_next();
+                }
+            }
+            PopUp::ResolveMergeConflicts => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ExtraFocus => {
+                            handle_merge_conflict_side_selection(app, None);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ExtraFocus {
+                    app.merge_conflicts_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ExtraFocus {
+                    app.merge_conflicts_next();
+                }
+            }
+            PopUp::ExternalSaveChanged => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ExtraFocus => {
+                            handle_external_save_change_action(app).await;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ExtraFocus {
+                    app.external_save_change_actions_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ExtraFocus {
+                    app.external_save_change_actions_next();
+                }
+            }
+            PopUp::UndoHistory => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ExtraFocus => {
+                            handle_undo_history_selection(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ExtraFocus {
+                    app.undo_history_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ExtraFocus {
+                    app.undo_history_next();
+                }
+            }
+            PopUp::BulkActions => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ExtraFocus => {
+                            handle_bulk_action_selection(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ExtraFocus {
+                    app.bulk_actions_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ExtraFocus {
+                    app.bulk_actions_next();
+                }
+            }
+            PopUp::TagManagerActions => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ExtraFocus => {
+                            return handle_tag_manager_action_selection(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ExtraFocus {
+                    app.tag_manager_actions_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ExtraFocus {
+                    app.tag_manager_actions_next();
+                }
+            }
+            PopUp::CardTemplatePicker => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ExtraFocus => {
+                            return handle_card_template_selection(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ExtraFocus {
+                    app.card_template_picker_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ExtraFocus {
+                    app.card_template_picker_next();
+                }
+            }
+            PopUp::BoardTemplatePicker => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::ExtraFocus => {
+                            return handle_board_template_selection(app);
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up && mouse_focus == Focus::ExtraFocus {
+                    app.board_template_picker_prv();
+                } else if mouse_scroll_down && mouse_focus == Focus::ExtraFocus {
+                    app.board_template_picker_next();
+                }
+            }
+            PopUp::BulkEditPrompt
+            | PopUp::DueDatePrompt
+            | PopUp::ImportTodoTxtPrompt
+            | PopUp::EncryptionPassphrasePrompt
+            | PopUp::SaveCardTemplatePrompt
+            | PopUp::SaveBoardTemplatePrompt
+            | PopUp::TagManagerPrompt => {
+                if left_button_pressed {
+                    match mouse_focus {
+                        Focus::TextInput => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Focus::CloseButton => {
+                            app.close_popup();
+                        }
+                        _ => {}
+                    }
+                }
+            }
             PopUp::CustomHexColorPromptBG => {
                 if left_button_pressed {
                     match mouse_focus {
@@ -3189,7 +3595,8 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
             | View::EditKeybindings
             | View::HelpMenu
             | View::NewBoard
-            | View::NewCard => {
+            | View::NewCard
+            | View::TagManager => {
                 if left_button_pressed {
                     if let Some(value) = handle_left_click_for_view(app).await {
                         return value;
@@ -3279,6 +3686,9 @@ async fn handle_left_click_for_view(app: &mut App<'_>) -> Option<AppReturn> {
         Focus::EditKeybindingsTable => {
             handle_edit_keybindings_action(app);
         }
+        Focus::TagManagerTable => {
+            return Some(handle_open_tag_manager_actions(app));
+        }
         Focus::CloseButton => match prv_view {
             View::Zen
             | View::TitleBody
@@ -3335,6 +3745,8 @@ async fn handle_left_click_for_view(app: &mut App<'_>) -> Option<AppReturn> {
                 return Some(handle_config_menu_action(app));
             } else if prv_view == View::CreateTheme {
                 return Some(handle_create_theme_action(app));
+            } else if prv_view == View::NewCard {
+                app.set_popup(PopUp::CardTemplatePicker);
             }
         }
         Focus::MainMenu => {
@@ -3382,6 +3794,8 @@ fn handle_scroll_for_view(
             app.config_prv();
         } else if app.state.mouse_focus == Some(Focus::EditKeybindingsTable) {
             app.edit_keybindings_prv();
+        } else if app.state.mouse_focus == Some(Focus::TagManagerTable) {
+            app.tag_manager_prv();
         } else if app.state.mouse_focus == Some(Focus::NewBoardDescription) {
             app.state.text_buffers.board_description.scroll((-1, 0))
         } else if app.state.mouse_focus == Some(Focus::CardDescription) {
@@ -3396,6 +3810,8 @@ fn handle_scroll_for_view(
             app.config_next();
         } else if app.state.mouse_focus == Some(Focus::EditKeybindingsTable) {
             app.edit_keybindings_next();
+        } else if app.state.mouse_focus == Some(Focus::TagManagerTable) {
+            app.tag_manager_next();
         } else if app.state.mouse_focus == Some(Focus::NewBoardDescription) {
             app.state.text_buffers.board_description.scroll((1, 0))
         } else if app.state.mouse_focus == Some(Focus::CardDescription) {
@@ -3679,6 +4095,25 @@ pub fn reset_card_drag_mode(app: &mut App) {
     app.state.hovered_card_dimensions = None;
 }
 
+pub fn handle_terminal_resize(app: &mut App, width: u16, height: u16) {
+    log::debug!("Terminal resized to {}x{}", width, height);
+    let viewport = Rect::new(0, 0, width, height);
+    app.widgets
+        .date_time_picker
+        .set_current_viewport(Some(viewport));
+    app.widgets.tag_picker.set_current_viewport(Some(viewport));
+    if app.state.card_drag_mode {
+        reset_card_drag_mode(app);
+    }
+    app.state.compact_layout = width < MIN_TERM_WIDTH;
+    if let Some(current_board_id) = app.state.current_board_id {
+        let current_card_id = app.state.current_card_id;
+        refresh_visible_boards_and_cards_around(app, current_board_id, current_card_id);
+    } else {
+        refresh_visible_boards_and_cards(app);
+    }
+}
+
 fn handle_config_menu_action(app: &mut App) -> AppReturn {
     fn reset_config(app: &mut App, reset_keybindings: bool, warning_message: &str) {
         let keybindings = app.config.keybindings.clone();
@@ -3687,6 +4122,8 @@ fn handle_config_menu_action(app: &mut App) -> AppReturn {
         if !reset_keybindings {
             app.config.keybindings = keybindings;
         }
+        app.action_history_manager
+            .set_history_limit(app.config.action_history_limit as usize);
         app.state.set_focus(Focus::ConfigTable);
         app.state.app_table_states.config.select(Some(0));
         let write_config_status = write_config(&app.config);
@@ -3741,6 +4178,9 @@ fn handle_config_menu_action(app: &mut App) -> AppReturn {
                     app.edit_keybindings_next();
                 }
             }
+            ConfigEnum::TagColors => {
+                app.set_view(View::TagManager);
+            }
             ConfigEnum::DefaultView => {
                 if app.state.app_list_states.default_view.selected().is_none() {
                     app.select_default_view_next();
@@ -3753,7 +4193,12 @@ fn handle_config_menu_action(app: &mut App) -> AppReturn {
             | ConfigEnum::DisableAnimations
             | ConfigEnum::AutoLogin
             | ConfigEnum::ShowLineNumbers
-            | ConfigEnum::EnableMouseSupport => {
+            | ConfigEnum::EnableMouseSupport
+            | ConfigEnum::EncryptLocalSaves
+            | ConfigEnum::GitCommitOnSync
+            | ConfigEnum::SaveActionHistory
+            | ConfigEnum::SyncBackendType
+            | ConfigEnum::SyncOnExit => {
                 AppConfig::edit_config(
                     app,
                     config_enum,
@@ -3981,6 +4426,14 @@ pub async fn handle_go_to_previous_view(app: &mut App<'_>) -> AppReturn {
         }
         return AppReturn::Continue;
     }
+    if View::views_with_kanban_board().contains(&app.state.current_view)
+        && !app.state.selected_cards.is_empty()
+    {
+        app.state.selected_cards.clear();
+        app.state.card_selection_anchor = None;
+        send_info_toast(&mut app.widgets.toast_widget, "Card selection cleared");
+        return AppReturn::Continue;
+    }
     match app.state.current_view {
         View::MainMenu => handle_exit(app).await,
         View::EditKeybindings => {
@@ -4060,24 +4513,22 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
     if let Some(card_being_edited) = &mut app.state.card_being_edited {
         let card = &mut card_being_edited.1;
         if selected_status == CardStatus::Complete {
-            card.date_completed = chrono::Local::now()
-                .format(app.config.date_time_format.to_parser_string())
-                .to_string();
+            card.date_completed = Some(Local::now());
         } else {
-            card.date_completed = FIELD_NOT_SET.to_string();
+            card.date_completed = None;
         }
         card.card_status = selected_status;
         app.close_popup();
         app.state.set_focus(Focus::CardStatus);
         return AppReturn::Continue;
+    } else if !app.state.selected_cards.is_empty() {
+        if app.state.z_stack.last() == Some(&PopUp::CardStatusSelector) {
+            app.close_popup();
+        }
+        return bulk_change_card_status(app, selected_status);
     } else if let Some(current_board_id) = app.state.current_board_id {
         let mut card_found = String::new();
-        let boards: &mut Boards = if app.filtered_boards.is_empty() {
-            &mut app.boards
-        } else {
-            &mut app.filtered_boards
-        };
-        if let Some(current_board) = boards.get_mut_board_with_id(current_board_id) {
+        if let Some(current_board) = app.boards.get_mut_board_with_id(current_board_id) {
             if let Some(current_card_id) = app.state.current_card_id {
                 if let Some(current_card) =
                     current_board.cards.get_mut_card_with_id(current_card_id)
@@ -4085,15 +4536,11 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
                     let temp_old_card = current_card.clone();
                     current_card.card_status = selected_status.clone();
                     if current_card.card_status == CardStatus::Complete {
-                        current_card.date_completed = chrono::Local::now()
-                            .format(app.config.date_time_format.to_parser_string())
-                            .to_string();
+                        current_card.date_completed = Some(Local::now());
                     } else {
-                        current_card.date_completed = FIELD_NOT_SET.to_string();
+                        current_card.date_completed = None;
                     }
-                    current_card.date_modified = chrono::Local::now()
-                        .format(app.config.date_time_format.to_parser_string())
-                        .to_string();
+                    current_card.date_modified = Some(Local::now());
                     app.action_history_manager
                         .new_action(ActionHistory::EditCard(
                             temp_old_card,
@@ -4111,6 +4558,7 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
             }
         }
         if !card_found.is_empty() {
+            refresh_after_boards_change(app);
             send_info_toast(
                 &mut app.widgets.toast_widget,
                 &format!(
@@ -4153,23 +4601,21 @@ fn handle_change_card_priority(app: &mut App, priority: Option<CardPriority>) ->
         app.close_popup();
         app.state.set_focus(Focus::CardPriority);
         return AppReturn::Continue;
+    } else if !app.state.selected_cards.is_empty() {
+        if app.state.z_stack.last() == Some(&PopUp::CardPrioritySelector) {
+            app.close_popup();
+        }
+        return bulk_change_card_priority(app, selected_priority);
     } else if let Some(current_board_id) = app.state.current_board_id {
         let mut card_found = String::new();
-        let boards: &mut Boards = if app.filtered_boards.is_empty() {
-            &mut app.boards
-        } else {
-            &mut app.filtered_boards
-        };
-        if let Some(current_board) = boards.get_mut_board_with_id(current_board_id) {
+        if let Some(current_board) = app.boards.get_mut_board_with_id(current_board_id) {
             if let Some(current_card_id) = app.state.current_card_id {
                 if let Some(current_card) =
                     current_board.cards.get_mut_card_with_id(current_card_id)
                 {
                     let temp_old_card = current_card.clone();
                     current_card.priority = selected_priority.clone();
-                    current_card.date_modified = chrono::Local::now()
-                        .format(app.config.date_time_format.to_parser_string())
-                        .to_string();
+                    current_card.date_modified = Some(Local::now());
                     app.action_history_manager
                         .new_action(ActionHistory::EditCard(
                             temp_old_card,
@@ -4187,6 +4633,7 @@ fn handle_change_card_priority(app: &mut App, priority: Option<CardPriority>) ->
             }
         }
         if !card_found.is_empty() {
+            refresh_after_boards_change(app);
             send_info_toast(
                 &mut app.widgets.toast_widget,
                 &format!(
@@ -4217,7 +4664,8 @@ fn handle_edit_general_config(app: &mut App) {
     }
     let config_enum = config_enum.unwrap();
     let new_value = app.state.text_buffers.general_config.get_joined_lines();
-    if new_value.is_empty() {
+    // An empty reminder command turns off reminder notifications
+    if new_value.is_empty() && config_enum != ConfigEnum::ReminderCommand {
         log::error!(
             "Could not find new value for config item {}",
             config_item_key
@@ -4454,18 +4902,15 @@ fn handle_date_time_picker_action(app: &mut App, key: Option<Key>, action: Optio
                 | Focus::DTPMinute
                 | Focus::DTPSecond => {
                     if let Some((_, card)) = &mut app.state.card_being_edited {
-                        if let Some(selected_date) = app.widgets.date_time_picker.selected_date_time
-                        {
-                            card.due_date = selected_date
-                                .format(app.config.date_time_format.to_parser_string())
-                                .to_string();
-                        } else {
-                            card.due_date = chrono::Local::now()
-                                .naive_local()
-                                .format(app.config.date_time_format.to_parser_string())
-                                .to_string()
-                        }
-                        log::debug!("Changed due date to {}", card.due_date);
+                        card.due_date = app
+                            .widgets
+                            .date_time_picker
+                            .get_selected_date_time()
+                            .or(Some(Local::now()));
+                        log::debug!(
+                            "Changed due date to {}",
+                            app.config.date_time_format.format_timestamp(card.due_date)
+                        );
                     }
                     app.widgets.date_time_picker.close_date_picker();
                 }
@@ -4501,14 +4946,7 @@ fn handle_new_card_action(app: &mut App) {
         let new_card_description = app.state.text_buffers.card_description.get_joined_lines();
         let new_card_description = new_card_description.trim();
 
-        let corrected_date_time_format =
-            DateTimeFormat::add_time_to_date_format(app.config.date_time_format);
-
-        let new_card_due_date = app
-            .widgets
-            .date_time_picker
-            .get_date_time_as_string(corrected_date_time_format);
-        let new_card_due_date = new_card_due_date.trim();
+        let new_card_due_date = app.widgets.date_time_picker.get_selected_date_time();
         let mut same_name_exists = false;
         let current_board_id = app.state.current_board_id.unwrap_or((0, 0));
         let current_board = app.boards.get_board_with_id(current_board_id);
@@ -4540,14 +4978,18 @@ fn handle_new_card_action(app: &mut App) {
             return;
         }
 
+        let (priority, tags) = if let Some(template) = &app.state.applied_card_template {
+            (template.priority.clone(), template.tags.clone())
+        } else {
+            (CardPriority::Low, vec![])
+        };
         let new_card = Card::new(
             new_card_name,
             new_card_description,
             new_card_due_date,
-            CardPriority::Low,
-            vec![],
+            priority,
+            tags,
             vec![],
-            app.config.date_time_format,
         );
         let current_board = app.boards.get_mut_board_with_id(current_board_id);
         if let Some(current_board) = current_board {
@@ -4585,6 +5027,8 @@ fn handle_new_card_action(app: &mut App) {
         reset_new_card_form(app);
     } else if app.state.focus == Focus::CardDueDate {
         app.set_popup(PopUp::DateTimePicker);
+    } else if app.state.focus == Focus::ExtraFocus {
+        app.set_popup(PopUp::CardTemplatePicker);
     } else if app.state.app_status == AppStatus::Initialized {
         app.state.app_status = AppStatus::UserInput;
     }
@@ -5345,17 +5789,8 @@ fn handle_edit_new_card(app: &mut App) -> AppReturn {
                 true,
             ));
     });
-    if card.due_date != FIELD_NOT_SET && !card.due_date.is_empty() {
-        if let Ok(current_format) = date_format_finder(card.due_date.trim()) {
-            app.widgets.date_time_picker.selected_date_time = match NaiveDateTime::parse_from_str(
-                card.due_date.trim(),
-                current_format.to_parser_string(),
-            ) {
-                Ok(date_time) => Some(date_time),
-                Err(_) => None,
-            };
-        }
-    }
+    app.widgets.date_time_picker.selected_date_time =
+        card.due_date.map(|due_date| due_date.naive_local());
     log::info!("Editing Card '{}'", card.name);
     send_info_toast(
         &mut app.widgets.toast_widget,
@@ -5365,8 +5800,6 @@ fn handle_edit_new_card(app: &mut App) -> AppReturn {
 }
 
 fn handle_edit_card_submit(app: &mut App) -> AppReturn {
-    let mut need_to_send_warning_toast = false;
-    let mut warning_due_date = String::new();
     if app.state.current_board_id.is_none() {
         return AppReturn::Continue;
     }
@@ -5393,28 +5826,10 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
         log::debug!("No card being edited found");
         return AppReturn::Continue;
     };
-    let card_due_date = edited_card.due_date.clone();
-    let parsed_due_date = date_format_converter(card_due_date.trim(), app.config.date_time_format);
-    let parsed_date = match parsed_due_date {
-        Ok(date) => {
-            if date.is_empty() {
-                FIELD_NOT_SET.to_string()
-            } else {
-                date
-            }
-        }
-        Err(_) => {
-            if card_due_date.trim() != FIELD_NOT_SET {
-                need_to_send_warning_toast = true;
-                warning_due_date = card_due_date;
-            }
-            FIELD_NOT_SET.to_string()
-        }
-    };
-    edited_card.due_date = parsed_date;
-    edited_card.date_modified = chrono::Local::now()
-        .format(app.config.date_time_format.to_parser_string())
-        .to_string();
+    edited_card.date_modified = Some(Local::now());
+    edited_card.description = app.state.text_buffers.card_description.get_joined_lines();
+    let card_name = app.state.text_buffers.card_name.get_joined_lines();
+    edited_card.name.clone_from(&card_name);
     app.action_history_manager
         .new_action(ActionHistory::EditCard(
             card.clone(),
@@ -5422,32 +5837,7 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
             board.id,
         ));
     *card = edited_card;
-
-    let description = app.state.text_buffers.card_description.get_joined_lines();
-    card.description = description;
-
-    let card_name = app.state.text_buffers.card_name.get_joined_lines();
-    card.name.clone_from(&card_name);
     app.state.card_being_edited = None;
-    if need_to_send_warning_toast {
-        let all_date_formats = DateTimeFormat::get_all_date_formats()
-            .iter()
-            .map(|x| x.to_human_readable_string())
-            .collect::<Vec<&str>>()
-            .join(", ");
-        send_warning_toast_with_duration(
-            &mut app.widgets.toast_widget,
-            &format!(
-                "Invalid date format '{}'. Please use any of the following {}. Date has been reset and other changes have been saved.",
-                warning_due_date, all_date_formats
-            ),
-            Duration::from_secs(10),
-        );
-        log::warn!(
-            "Invalid date format '{}'. Please use any of the following {}. Date has been reset and other changes have been saved.",
-            warning_due_date, all_date_formats
-        );
-    }
     send_info_toast(
         &mut app.widgets.toast_widget,
         &format!("Changes to Card '{}' saved", card_name),
@@ -5521,13 +5911,26 @@ fn filter_boards(app: &mut App) {
         app.close_popup();
         return;
     }
-    let all_boards = app.boards.clone();
-
     update_current_board_and_card(&mut app.state, None, None);
 
     let filter_tags = app.state.filter_tags.clone().unwrap();
+    app.filtered_boards = get_filtered_boards(&app.boards, &filter_tags);
+    refresh_visible_boards_and_cards(app);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!(
+            "Filtered by {} tags",
+            app.state.filter_tags.clone().unwrap().len()
+        ),
+    );
+    app.close_popup();
+    app.state.app_list_states.filter_by_tag_list.select(None);
+}
+
+/// Boards containing only the cards that have at least one of the filter tags
+fn get_filtered_boards(boards: &Boards, filter_tags: &[String]) -> Boards {
     let mut filtered_boards = Vec::new();
-    for board in all_boards.get_boards() {
+    for board in boards.get_boards() {
         let mut filtered_cards = Vec::new();
         for card in board.cards.get_all_cards() {
             let mut card_tags = card.tags.clone();
@@ -5545,17 +5948,7 @@ fn filter_boards(app: &mut App) {
             });
         }
     }
-    app.filtered_boards = Boards::from(filtered_boards);
-    refresh_visible_boards_and_cards(app);
-    send_info_toast(
-        &mut app.widgets.toast_widget,
-        &format!(
-            "Filtered by {} tags",
-            app.state.filter_tags.clone().unwrap().len()
-        ),
-    );
-    app.close_popup();
-    app.state.app_list_states.filter_by_tag_list.select(None);
+    Boards::from(filtered_boards)
 }
 
 fn handle_command_palette_card_selection(app: &mut App) {
@@ -5612,17 +6005,1414 @@ fn handle_command_palette_board_selection(app: &mut App) {
         return;
     }
     let board_id = all_board_details[board_details_index].1;
-    let mut number_of_times_to_go_right = 0;
-    for (board_index, board) in app.boards.get_boards().iter().enumerate() {
-        if board.id == board_id {
-            number_of_times_to_go_right = board_index;
-            break;
+    go_to_board_with_id(app, board_id);
+}
+
+fn go_to_board_with_id(app: &mut App, board_id: (u64, u64)) {
+    refresh_visible_boards_and_cards_around(app, board_id, None);
+    app.state.set_focus(Focus::Body);
+}
+
+fn handle_go_to_board_selection(app: &mut App) {
+    reset_mouse(app);
+    let search_results = app.get_go_to_board_search_results();
+    let selected_board = app
+        .state
+        .app_list_states
+        .go_to_board
+        .selected()
+        .and_then(|selected_index| search_results.get(selected_index));
+    if let Some((_, board_id, _)) = selected_board {
+        let board_id = *board_id;
+        app.close_popup();
+        go_to_board_with_id(app, board_id);
+    } else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No board selected");
+    }
+}
+
+fn handle_jump_to_board(app: &mut App, key: Key) -> AppReturn {
+    let board_number = match key {
+        Key::Char(c) | Key::Alt(c) | Key::Ctrl(c) => c.to_digit(10).unwrap_or(0) as usize,
+        _ => 0,
+    };
+    if board_number == 0 {
+        log::debug!("Could not get a board number from key {:?}", key);
+        return AppReturn::Continue;
+    }
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    if let Some(board) = boards.get_boards().get(board_number - 1) {
+        let board_id = board.id;
+        reset_mouse(app);
+        go_to_board_with_id(app, board_id);
+    } else {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!("There is no board {}", board_number),
+        );
+    }
+    AppReturn::Continue
+}
+
+fn handle_toggle_card_selection(app: &mut App) -> AppReturn {
+    if let Some(current_card_id) = app.state.current_card_id {
+        if !app.state.selected_cards.remove(&current_card_id) {
+            app.state.selected_cards.insert(current_card_id);
         }
+        app.state.card_selection_anchor = Some(current_card_id);
+    } else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No card to select");
     }
-    for _ in 0..number_of_times_to_go_right {
-        handle_horizontal_navigation(app, NavigationDirection::Right);
+    AppReturn::Continue
+}
+
+/// Selects every card between the last toggled card and the current card when both are in the
+/// current board, otherwise only the current card is selected
+fn handle_select_card_range(app: &mut App) -> AppReturn {
+    let (current_board_id, current_card_id) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(current_board_id), Some(current_card_id)) => (current_board_id, current_card_id),
+            _ => {
+                send_warning_toast(&mut app.widgets.toast_widget, "No card to select");
+                return AppReturn::Continue;
+            }
+        };
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let mut cards_to_select = vec![current_card_id];
+    if let Some(current_board) = boards.get_board_with_id(current_board_id) {
+        let anchor_index = app
+            .state
+            .card_selection_anchor
+            .and_then(|anchor_card_id| current_board.cards.get_card_index(anchor_card_id));
+        let current_index = current_board.cards.get_card_index(current_card_id);
+        if let (Some(anchor_index), Some(current_index)) = (anchor_index, current_index) {
+            let (start_index, end_index) = if anchor_index <= current_index {
+                (anchor_index, current_index)
+            } else {
+                (current_index, anchor_index)
+            };
+            cards_to_select = current_board.cards.get_all_cards()[start_index..=end_index]
+                .iter()
+                .map(|card| card.id)
+                .collect();
+        }
+    }
+    app.state.selected_cards.extend(cards_to_select);
+    app.state.card_selection_anchor = Some(current_card_id);
+    AppReturn::Continue
+}
+
+/// Selects all cards in the current board, or clears them if they are all already selected
+fn handle_select_all_cards_in_board(app: &mut App) -> AppReturn {
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let board_card_ids = app
+        .state
+        .current_board_id
+        .and_then(|current_board_id| boards.get_board_with_id(current_board_id))
+        .map(|current_board| current_board.cards.get_all_card_ids())
+        .unwrap_or_default();
+    if board_card_ids.is_empty() {
+        send_warning_toast(&mut app.widgets.toast_widget, "No cards to select");
+        return AppReturn::Continue;
+    }
+    if board_card_ids
+        .iter()
+        .all(|card_id| app.state.selected_cards.contains(card_id))
+    {
+        for card_id in board_card_ids.iter() {
+            app.state.selected_cards.remove(card_id);
+        }
+    } else {
+        app.state.selected_cards.extend(board_card_ids);
+    }
+    AppReturn::Continue
+}
+
+fn handle_undo_history_selection(app: &mut App) -> AppReturn {
+    let selected_index = app.state.app_list_states.undo_history.selected();
+    app.close_popup();
+    if let Some(selected_index) = selected_index {
+        app.jump_to_action_history_index(selected_index);
+    }
+    AppReturn::Continue
+}
+
+/// Picks the version to keep for the selected conflict, toggles it when no side is given
+fn handle_merge_conflict_side_selection(app: &mut App, side: Option<MergeSide>) {
+    let selected_index = app.state.app_list_states.merge_conflicts.selected();
+    if let (Some(pending_merge), Some(selected_index)) =
+        (app.state.pending_merge.as_mut(), selected_index)
+    {
+        let side = side.unwrap_or(match pending_merge.get_resolution(selected_index) {
+            MergeSide::Ours => MergeSide::Theirs,
+            MergeSide::Theirs => MergeSide::Ours,
+        });
+        pending_merge.set_resolution(selected_index, side);
+        if selected_index >= pending_merge.conflicts.len() {
+            app.state
+                .app_list_states
+                .merge_conflicts
+                .select(Some(pending_merge.conflicts.len().saturating_sub(1)));
+        }
+    }
+}
+
+async fn handle_resolve_merge_conflicts(app: &mut App<'_>) -> AppReturn {
+    app.close_popup();
+    if let Some(pending_merge) = app.state.pending_merge.take() {
+        app.boards.set_boards(pending_merge.merge().boards);
+        app.action_history_manager.reset();
+        log::info!("Merged changes from {}", pending_merge.source);
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Merged changes from {}", pending_merge.source),
+        );
+        app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
+        match pending_merge.source {
+            MergeSource::CloudSave(save_id) => {
+                // The merged boards now contain the cloud save, so it is the base for the next sync
+                app.state.last_synced_save_id = Some(save_id);
+                app.dispatch(IoEvent::SyncLocalData).await;
+            }
+            MergeSource::LocalSave(_) => app.dispatch(IoEvent::SaveLocalData).await,
+        }
+    }
+    AppReturn::Continue
+}
+
+async fn handle_external_save_change_action(app: &mut App<'_>) -> AppReturn {
+    let selected_action = app
+        .state
+        .app_list_states
+        .external_save_change_actions
+        .selected()
+        .and_then(|selected_index| ExternalSaveChangeAction::all().get(selected_index).copied());
+    let selected_action = if let Some(selected_action) = selected_action {
+        selected_action
+    } else {
+        log::debug!("No external save change action selected");
+        return AppReturn::Continue;
+    };
+    app.close_popup();
+    let external_save_change =
+        if let Some(external_save_change) = app.state.external_save_change.take() {
+            external_save_change
+        } else {
+            return AppReturn::Continue;
+        };
+    match selected_action {
+        ExternalSaveChangeAction::Reload => {
+            app.boards.set_boards(external_save_change.boards);
+            app.action_history_manager.reset();
+            log::info!("Reloaded {}", external_save_change.file_name);
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Reloaded {}", external_save_change.file_name),
+            );
+            app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
+        }
+        ExternalSaveChangeAction::Merge => {
+            let merge_result = merge_boards(
+                &external_save_change.base,
+                &app.boards,
+                &external_save_change.boards,
+                &HashMap::new(),
+            );
+            if merge_result.conflicts.is_empty() {
+                app.boards.set_boards(merge_result.boards);
+                app.action_history_manager.reset();
+                log::info!("Merged changes from {}", external_save_change.file_name);
+                send_info_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Merged changes from {}", external_save_change.file_name),
+                );
+                app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
+                app.dispatch(IoEvent::SaveLocalData).await;
+            } else {
+                app.state.pending_merge = Some(PendingMerge::new(
+                    external_save_change.base,
+                    app.boards.clone(),
+                    external_save_change.boards,
+                    MergeSource::LocalSave(external_save_change.file_name),
+                ));
+                app.set_popup(PopUp::ResolveMergeConflicts);
+            }
+        }
+        ExternalSaveChangeAction::KeepMine => {
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!(
+                    "Kept this instance's boards, save to replace {}",
+                    external_save_change.file_name
+                ),
+            );
+        }
+    }
+    AppReturn::Continue
+}
+
+fn handle_open_bulk_actions(app: &mut App) -> AppReturn {
+    if app.state.selected_cards.is_empty() {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "No cards selected, select cards before using bulk actions",
+        );
+        return AppReturn::Continue;
+    }
+    app.set_popup(PopUp::BulkActions);
+    AppReturn::Continue
+}
+
+fn handle_bulk_action_selection(app: &mut App) -> AppReturn {
+    let selected_action = app
+        .state
+        .app_list_states
+        .bulk_actions
+        .selected()
+        .and_then(|selected_index| BulkCardAction::all().get(selected_index).copied());
+    let selected_action = if let Some(selected_action) = selected_action {
+        selected_action
+    } else {
+        log::debug!("No bulk action selected");
+        return AppReturn::Continue;
+    };
+    app.close_popup();
+    match selected_action {
+        BulkCardAction::MoveToBoard => app.set_popup(PopUp::MoveSelectedCardsToBoard),
+        BulkCardAction::SetStatus => app.set_popup(PopUp::CardStatusSelector),
+        BulkCardAction::SetPriority => app.set_popup(PopUp::CardPrioritySelector),
+        BulkCardAction::AddTag
+        | BulkCardAction::RemoveTag
+        | BulkCardAction::SetDueDate
+        | BulkCardAction::SetReminders => {
+            app.set_popup(PopUp::BulkEditPrompt);
+            app.state.bulk_edit_action = Some(selected_action);
+        }
+        BulkCardAction::Delete => delete_selected_cards(app),
+    }
+    AppReturn::Continue
+}
+
+fn handle_bulk_edit_prompt_submit(app: &mut App) -> AppReturn {
+    let input = app
+        .state
+        .text_buffers
+        .bulk_edit
+        .get_joined_lines()
+        .trim()
+        .to_string();
+    let bulk_edit_action = app.state.bulk_edit_action;
+    match bulk_edit_action {
+        Some(BulkCardAction::AddTag) => {
+            if input.is_empty() {
+                send_warning_toast(&mut app.widgets.toast_widget, "Tag cannot be empty");
+                return AppReturn::Continue;
+            }
+            app.close_popup();
+            let edited_cards = bulk_edit_selected_cards(app, |card| {
+                if card.tags.iter().any(|tag| tag.eq_ignore_ascii_case(&input)) {
+                    false
+                } else {
+                    card.tags.push(input.clone());
+                    true
+                }
+            });
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Added tag \"{}\" to {} cards", input, edited_cards),
+            );
+        }
+        Some(BulkCardAction::RemoveTag) => {
+            if input.is_empty() {
+                send_warning_toast(&mut app.widgets.toast_widget, "Tag cannot be empty");
+                return AppReturn::Continue;
+            }
+            app.close_popup();
+            let edited_cards = bulk_edit_selected_cards(app, |card| {
+                let tags_before = card.tags.len();
+                card.tags.retain(|tag| !tag.eq_ignore_ascii_case(&input));
+                card.tags.len() != tags_before
+            });
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Removed tag \"{}\" from {} cards", input, edited_cards),
+            );
+        }
+        Some(BulkCardAction::SetDueDate) => {
+            let due_date = if input.is_empty() {
+                None
+            } else {
+                match parse_due_date(
+                    &input,
+                    app.config.date_time_format,
+                    &app.config.date_picker_calender_format,
+                ) {
+                    Ok(due_date) => Some(due_date),
+                    Err(_) => {
+                        let all_date_formats = DateTimeFormat::get_all_date_formats()
+                            .iter()
+                            .map(|x| x.to_human_readable_string())
+                            .collect::<Vec<&str>>()
+                            .join(", ");
+                        send_warning_toast_with_duration(
+                            &mut app.widgets.toast_widget,
+                            &format!(
+                                "Could not understand date '{}'. Please use a relative date like tomorrow or +3d, or any of the following formats {}",
+                                input, all_date_formats
+                            ),
+                            Duration::from_secs(10),
+                        );
+                        return AppReturn::Continue;
+                    }
+                }
+            };
+            app.close_popup();
+            let edited_cards = bulk_edit_selected_cards(app, |card| {
+                if card.due_date == due_date {
+                    false
+                } else {
+                    card.due_date = due_date;
+                    true
+                }
+            });
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Changed due date of {} cards", edited_cards),
+            );
+        }
+        Some(BulkCardAction::SetReminders) => {
+            let reminders = match parse_reminder_offsets(&input) {
+                Ok(reminders) => reminders,
+                Err(err) => {
+                    send_warning_toast(&mut app.widgets.toast_widget, &err);
+                    return AppReturn::Continue;
+                }
+            };
+            app.close_popup();
+            let edited_cards = bulk_edit_selected_cards(app, |card| {
+                if card.reminders == reminders {
+                    false
+                } else {
+                    card.reminders = reminders.clone();
+                    true
+                }
+            });
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Changed reminders of {} cards", edited_cards),
+            );
+        }
+        _ => {
+            log::debug!("Bulk edit prompt submitted without an action");
+            app.close_popup();
+        }
+    }
+    AppReturn::Continue
+}
+
+fn open_due_date_prompt(app: &mut App) {
+    if app.state.card_being_edited.is_none() && app.state.z_stack.last() == Some(&PopUp::ViewCard) {
+        handle_edit_new_card(app);
+    }
+    app.set_popup(PopUp::DueDatePrompt);
+}
+
+fn handle_due_date_prompt_submit(app: &mut App) -> AppReturn {
+    let input = app
+        .state
+        .text_buffers
+        .due_date
+        .get_joined_lines()
+        .trim()
+        .to_string();
+    let due_date = if input.is_empty() {
+        None
+    } else {
+        match parse_due_date(
+            &input,
+            app.config.date_time_format,
+            &app.config.date_picker_calender_format,
+        ) {
+            Ok(due_date) => Some(due_date),
+            Err(err) => {
+                send_warning_toast(&mut app.widgets.toast_widget, &err);
+                return AppReturn::Continue;
+            }
+        }
+    };
+    app.close_popup();
+    app.widgets.date_time_picker.selected_date_time =
+        due_date.map(|due_date| due_date.naive_local());
+    if let Some((_, card)) = &mut app.state.card_being_edited {
+        card.due_date = due_date;
+    }
+    log::debug!(
+        "Changed due date to {}",
+        app.config.date_time_format.format_timestamp(due_date)
+    );
+    AppReturn::Continue
+}
+
+fn handle_import_todo_txt_prompt_submit(app: &mut App) -> AppReturn {
+    let file_path = app
+        .state
+        .text_buffers
+        .import_file_path
+        .get_joined_lines()
+        .trim()
+        .to_string();
+    if file_path.is_empty() {
+        send_warning_toast(&mut app.widgets.toast_widget, "No file to import given");
+        return AppReturn::Continue;
+    }
+    let board_id = if let Some(board_id) = app.state.current_board_id {
+        board_id
+    } else {
+        app.close_popup();
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "No board Selected / Available",
+        );
+        return AppReturn::Continue;
+    };
+    let (cards, skipped_lines) = match fs::read_to_string(&file_path) {
+        Ok(content) => parse_todo_txt(&content),
+        Err(err) => {
+            send_warning_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Cannot read {}: {}", file_path, err),
+            );
+            return AppReturn::Continue;
+        }
+    };
+    for skipped_line in &skipped_lines {
+        log::warn!("Skipped todo.txt {}", skipped_line);
+    }
+    if !skipped_lines.is_empty() {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "Skipped {} lines that could not be imported, see the log for details",
+                skipped_lines.len()
+            ),
+        );
+    }
+    app.close_popup();
+    let board = if let Some(board) = app.boards.get_mut_board_with_id(board_id) {
+        board
+    } else {
+        log::debug!("Board with id {:?} not found", board_id);
+        return AppReturn::Continue;
+    };
+    let board_name = board.name.clone();
+    let mut batched_actions = vec![];
+    for card in cards {
+        board.cards.add_card(card.clone());
+        batched_actions.push(ActionHistory::CreateCard(card, board_id));
+    }
+    let imported_cards = batched_actions.len();
+    if !batched_actions.is_empty() {
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(batched_actions));
+    }
+    refresh_after_boards_change(app);
+    log::info!("Imported {} cards into '{}'", imported_cards, board_name);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Imported {} cards into '{}'", imported_cards, board_name),
+    );
+    AppReturn::Continue
+}
+
+/// Derives the key for encrypted local saves from the entered passphrase, the derivation runs
+/// in the io thread so the ui is not blocked by argon2
+async fn handle_encryption_passphrase_prompt_submit(app: &mut App<'_>) -> AppReturn {
+    let passphrase = app
+        .state
+        .text_buffers
+        .encryption_passphrase
+        .get_joined_lines();
+    if passphrase.is_empty() {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "Encryption passphrase cannot be empty",
+        );
+        return AppReturn::Continue;
+    }
+    app.close_popup();
+    app.dispatch(IoEvent::UnlockLocalSaves(passphrase)).await;
+    AppReturn::Continue
+}
+
+/// Pre-fills the new card form with the picked template, priority and tags are applied on submit
+fn handle_card_template_selection(app: &mut App) -> AppReturn {
+    let template = app
+        .state
+        .app_list_states
+        .card_template_picker
+        .selected()
+        .and_then(|selected_index| app.card_templates.get(selected_index).cloned());
+    let template = if let Some(template) = template {
+        template
+    } else {
+        log::debug!("No card template selected");
+        app.close_popup();
+        return AppReturn::Continue;
+    };
+    app.close_popup();
+    app.state.text_buffers.card_description =
+        TextBox::from_string_with_newline_sep(template.description.clone(), false);
+    if let Some(due_date) = template.get_due_date(&app.config.date_picker_calender_format) {
+        app.widgets.date_time_picker.selected_date_time = Some(due_date.naive_local());
+    }
+    log::info!("Using card template '{}'", template.name);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Using card template '{}'", template.name),
+    );
+    app.state.applied_card_template = Some(template);
+    AppReturn::Continue
+}
+
+fn handle_save_card_template_prompt_submit(app: &mut App) -> AppReturn {
+    let template_name = app
+        .state
+        .text_buffers
+        .card_template_name
+        .get_joined_lines()
+        .trim()
+        .to_string();
+    if template_name.is_empty() {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "Template name cannot be empty",
+        );
+        return AppReturn::Continue;
+    }
+    let card = app
+        .state
+        .current_board_id
+        .zip(app.state.current_card_id)
+        .and_then(|(board_id, card_id)| {
+            app.boards
+                .get_board_with_id(board_id)
+                .and_then(|board| board.cards.get_card_with_id(card_id))
+        });
+    let card_template = if let Some(card) = card {
+        CardTemplate::from_card(&template_name, card)
+    } else {
+        app.close_popup();
+        send_error_toast(&mut app.widgets.toast_widget, "No card selected");
+        return AppReturn::Continue;
+    };
+    app.close_popup();
+    match save_card_template(&card_template) {
+        Ok(card_template_path) => {
+            log::info!(
+                "Saved card template '{}' to {}",
+                card_template.name,
+                card_template_path
+            );
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Saved card template '{}'", card_template.name),
+            );
+            if let Some(existing_template) = app
+                .card_templates
+                .iter_mut()
+                .find(|template| template.name == card_template.name)
+            {
+                *existing_template = card_template;
+            } else {
+                app.card_templates.push(card_template);
+                app.card_templates.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+        Err(err) => {
+            log::error!("Failed to save card template: {}", err);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Failed to save card template: {}", err),
+            );
+        }
+    }
+    AppReturn::Continue
+}
+
+/// Creates every board of the picked template as one undoable batch, boards whose name is
+/// already taken are skipped
+fn handle_board_template_selection(app: &mut App) -> AppReturn {
+    let template = app
+        .state
+        .app_list_states
+        .board_template_picker
+        .selected()
+        .and_then(|selected_index| app.board_templates.get(selected_index).cloned());
+    app.close_popup();
+    let template = if let Some(template) = template {
+        template
+    } else {
+        log::debug!("No board template selected");
+        return AppReturn::Continue;
+    };
+    let mut batched_actions = vec![];
+    let mut skipped_boards = vec![];
+    for board_entry in &template.boards {
+        if app
+            .boards
+            .get_boards()
+            .iter()
+            .any(|board| board.name == board_entry.name)
+        {
+            skipped_boards.push(board_entry.name.clone());
+            continue;
+        }
+        let new_board = Board::new(&board_entry.name, &board_entry.description);
+        app.boards.add_board(new_board.clone());
+        batched_actions.push(ActionHistory::CreateBoard(new_board));
+    }
+    if let Some(ActionHistory::CreateBoard(first_board)) = batched_actions.first() {
+        update_current_board_and_card(&mut app.state, Some(first_board.id), None);
+    }
+    let created_boards = batched_actions.len();
+    if !batched_actions.is_empty() {
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(batched_actions));
+    }
+    refresh_after_boards_change(app);
+    log::info!(
+        "Created {} boards from template '{}'",
+        created_boards,
+        template.name
+    );
+    if skipped_boards.is_empty() {
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "Created {} boards from template '{}'",
+                created_boards, template.name
+            ),
+        );
+    } else {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "Created {} boards from template '{}', skipped existing boards: {}",
+                created_boards,
+                template.name,
+                skipped_boards.join(", ")
+            ),
+        );
+    }
+    AppReturn::Continue
+}
+
+fn handle_save_board_template_prompt_submit(app: &mut App) -> AppReturn {
+    let template_name = app
+        .state
+        .text_buffers
+        .board_template_name
+        .get_joined_lines()
+        .trim()
+        .to_string();
+    if template_name.is_empty() {
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "Template name cannot be empty",
+        );
+        return AppReturn::Continue;
+    }
+    if app.boards.is_empty() {
+        app.close_popup();
+        send_error_toast(&mut app.widgets.toast_widget, "No boards to save");
+        return AppReturn::Continue;
+    }
+    let board_template = BoardSetTemplate::from_boards(&template_name, &app.boards);
+    app.close_popup();
+    match save_board_template(&board_template) {
+        Ok(board_template_path) => {
+            log::info!(
+                "Saved board template '{}' to {}",
+                board_template.name,
+                board_template_path
+            );
+            send_info_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Saved board template '{}'", board_template.name),
+            );
+            if let Some(existing_template) = app
+                .board_templates
+                .iter_mut()
+                .find(|template| !template.built_in && template.name == board_template.name)
+            {
+                *existing_template = board_template;
+            } else {
+                app.board_templates.push(board_template);
+            }
+        }
+        Err(err) => {
+            log::error!("Failed to save board template: {}", err);
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Failed to save board template: {}", err),
+            );
+        }
+    }
+    AppReturn::Continue
+}
+
+/// Selected cards in board order as (board id, card)
+fn get_selected_cards(app: &App) -> Vec<((u64, u64), Card)> {
+    let mut selected_cards = vec![];
+    for board in app.boards.get_boards() {
+        for card in board.cards.get_all_cards() {
+            if app.state.selected_cards.contains(&card.id) {
+                selected_cards.push((board.id, card.clone()));
+            }
+        }
+    }
+    selected_cards
+}
+
+/// Applies `edit` to every selected card, the closure returns false when it left the card
+/// unchanged. All edits are recorded as a single undoable batch, returns the number of cards edited
+fn bulk_edit_selected_cards<F>(app: &mut App, edit: F) -> usize
+where
+    F: Fn(&mut Card) -> bool,
+{
+    let date_modified = Some(Local::now());
+    let mut batched_actions = vec![];
+    for (board_id, card) in get_selected_cards(app) {
+        let mut edited_card = card.clone();
+        if !edit(&mut edited_card) {
+            continue;
+        }
+        edited_card.date_modified = date_modified;
+        if let Some(board) = app.boards.get_mut_board_with_id(board_id) {
+            if let Some(board_card) = board.cards.get_mut_card_with_id(card.id) {
+                *board_card = edited_card.clone();
+                batched_actions.push(ActionHistory::EditCard(card, edited_card, board_id));
+            }
+        }
+    }
+    let edited_cards = batched_actions.len();
+    if !batched_actions.is_empty() {
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(batched_actions));
+        log::info!("Bulk edited {} cards", edited_cards);
+    }
+    refresh_after_boards_change(app);
+    edited_cards
+}
+
+fn handle_open_tag_manager_actions(app: &mut App) -> AppReturn {
+    let all_tags = app.calculate_tags();
+    let selected_tag = app
+        .state
+        .app_table_states
+        .tag_manager
+        .selected()
+        .and_then(|selected_index| all_tags.get(selected_index));
+    if let Some((tag, _)) = selected_tag {
+        app.state.tag_being_managed = Some(tag.clone());
+        app.set_popup(PopUp::TagManagerActions);
+    } else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No tag selected");
+    }
+    AppReturn::Continue
+}
+
+fn handle_tag_manager_action_selection(app: &mut App) -> AppReturn {
+    let selected_action = app
+        .state
+        .app_list_states
+        .tag_manager_actions
+        .selected()
+        .and_then(|selected_index| TagManagerAction::all().get(selected_index).copied());
+    let selected_action = if let Some(selected_action) = selected_action {
+        selected_action
+    } else {
+        log::debug!("No tag manager action selected");
+        return AppReturn::Continue;
+    };
+    app.close_popup();
+    match selected_action {
+        TagManagerAction::Rename | TagManagerAction::SetColor => {
+            app.state.tag_manager_action = Some(selected_action);
+            app.set_popup(PopUp::TagManagerPrompt);
+        }
+        TagManagerAction::Delete => delete_managed_tag(app),
+    }
+    AppReturn::Continue
+}
+
+fn handle_tag_manager_prompt_submit(app: &mut App) -> AppReturn {
+    let input = app
+        .state
+        .text_buffers
+        .tag_manager
+        .get_joined_lines()
+        .trim()
+        .to_string();
+    let tag = if let Some(tag) = app.state.tag_being_managed.clone() {
+        tag
+    } else {
+        log::debug!("No tag being managed");
+        app.close_popup();
+        return AppReturn::Continue;
+    };
+    match app.state.tag_manager_action {
+        Some(TagManagerAction::Rename) => {
+            if input.is_empty() {
+                send_warning_toast(&mut app.widgets.toast_widget, "Tag cannot be empty");
+                return AppReturn::Continue;
+            }
+            if input == tag {
+                send_warning_toast(&mut app.widgets.toast_widget, "Tag name is unchanged");
+                return AppReturn::Continue;
+            }
+            app.close_popup();
+            rename_tag(app, &tag, &input);
+        }
+        Some(TagManagerAction::SetColor) => {
+            let new_color = if input.is_empty() {
+                None
+            } else {
+                match Color::from_str(&input) {
+                    Ok(color) => Some(color.to_string()),
+                    Err(_) => {
+                        send_error_toast(
+                            &mut app.widgets.toast_widget,
+                            &format!(
+                                "Invalid colour \"{}\", use a colour name like red or a hex code like #ff0000",
+                                input
+                            ),
+                        );
+                        return AppReturn::Continue;
+                    }
+                }
+            };
+            app.close_popup();
+            set_tag_color(app, &tag, new_color);
+        }
+        _ => {
+            app.close_popup();
+        }
+    }
+    AppReturn::Continue
+}
+
+/// Renames a tag on every card, merging it into an existing tag if the new name is taken
+fn rename_tag(app: &mut App, tag: &str, new_name: &str) {
+    let tag_lower = tag.to_lowercase();
+    // Merging keeps the spelling of the tag being merged into
+    let merge_target = app
+        .calculate_tags()
+        .into_iter()
+        .map(|(existing_tag, _)| existing_tag)
+        .find(|existing_tag| {
+            let existing_tag_lower = existing_tag.to_lowercase();
+            existing_tag_lower == new_name.to_lowercase() && existing_tag_lower != tag_lower
+        });
+    let new_tag = merge_target.clone().unwrap_or_else(|| new_name.to_string());
+    let new_tag_lower = new_tag.to_lowercase();
+
+    let mut color_changes = vec![];
+    if new_tag_lower != tag_lower {
+        if let Some(color) = app.config.tag_colors.get(&tag_lower).cloned() {
+            if !app.config.tag_colors.contains_key(&new_tag_lower) {
+                color_changes.push(ActionHistory::SetTagColor(
+                    new_tag_lower.clone(),
+                    None,
+                    Some(color.clone()),
+                ));
+            }
+            color_changes.push(ActionHistory::SetTagColor(
+                tag_lower.clone(),
+                Some(color),
+                None,
+            ));
+        }
+    }
+    if let Err(error_message) = write_tag_color_changes(app, &color_changes) {
+        log::error!("Could not write tag colours: {}", error_message);
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "Could not write to config file",
+        );
+        return;
+    }
+
+    let mut batched_actions = edit_tag_on_all_cards(app, tag, |card_tags| {
+        let mut renamed_tags: Vec<String> = vec![];
+        for card_tag in card_tags.iter() {
+            let renamed_tag = if card_tag.to_lowercase() == tag_lower {
+                new_tag.clone()
+            } else {
+                card_tag.clone()
+            };
+            if !renamed_tags
+                .iter()
+                .any(|existing_tag| existing_tag.to_lowercase() == renamed_tag.to_lowercase())
+            {
+                renamed_tags.push(renamed_tag);
+            }
+        }
+        *card_tags = renamed_tags;
+    });
+    let edited_cards = batched_actions.len();
+    batched_actions.extend(color_changes);
+    record_tag_changes(app, batched_actions);
+
+    let message = if merge_target.is_some() {
+        format!(
+            "Merged tag \"{}\" into \"{}\" on {} cards",
+            tag, new_tag, edited_cards
+        )
+    } else {
+        format!(
+            "Renamed tag \"{}\" to \"{}\" on {} cards",
+            tag, new_tag, edited_cards
+        )
+    };
+    log::info!("{}", message);
+    send_info_toast(&mut app.widgets.toast_widget, &message);
+}
+
+fn delete_managed_tag(app: &mut App) {
+    let tag = if let Some(tag) = app.state.tag_being_managed.clone() {
+        tag
+    } else {
+        log::debug!("No tag being managed");
+        return;
+    };
+    let tag_lower = tag.to_lowercase();
+    let color_changes = if let Some(color) = app.config.tag_colors.get(&tag_lower).cloned() {
+        vec![ActionHistory::SetTagColor(
+            tag_lower.clone(),
+            Some(color),
+            None,
+        )]
+    } else {
+        vec![]
+    };
+    if let Err(error_message) = write_tag_color_changes(app, &color_changes) {
+        log::error!("Could not write tag colours: {}", error_message);
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "Could not write to config file",
+        );
+        return;
+    }
+
+    let mut batched_actions = edit_tag_on_all_cards(app, &tag, |card_tags| {
+        card_tags.retain(|card_tag| card_tag.to_lowercase() != tag_lower);
+    });
+    let edited_cards = batched_actions.len();
+    batched_actions.extend(color_changes);
+    record_tag_changes(app, batched_actions);
+
+    let message = format!("Deleted tag \"{}\" from {} cards", tag, edited_cards);
+    log::info!("{}", message);
+    send_info_toast(&mut app.widgets.toast_widget, &message);
+}
+
+fn set_tag_color(app: &mut App, tag: &str, color: Option<String>) {
+    let tag_lower = tag.to_lowercase();
+    let old_color = app.config.tag_colors.get(&tag_lower).cloned();
+    if old_color == color {
+        send_info_toast(&mut app.widgets.toast_widget, "Tag colour is unchanged");
+        return;
+    }
+    let color_change = ActionHistory::SetTagColor(tag_lower, old_color, color.clone());
+    if let Err(error_message) = write_tag_color_changes(app, std::slice::from_ref(&color_change)) {
+        log::error!("Could not write tag colours: {}", error_message);
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "Could not write to config file",
+        );
+        return;
+    }
+    app.action_history_manager.new_action(color_change);
+    let message = if let Some(color) = color {
+        format!("Set colour of tag \"{}\" to {}", tag, color)
+    } else {
+        format!("Cleared colour of tag \"{}\"", tag)
+    };
+    log::info!("{}", message);
+    send_info_toast(&mut app.widgets.toast_widget, &message);
+}
+
+/// Applies the new colours of SetTagColor actions to the config and writes it once
+fn write_tag_color_changes(app: &mut App, color_changes: &[ActionHistory]) -> Result<(), String> {
+    if color_changes.is_empty() {
+        return Ok(());
+    }
+    let mut config_copy = app.config.clone();
+    for color_change in color_changes {
+        if let ActionHistory::SetTagColor(tag, _, new_color) = color_change {
+            config_copy.set_tag_color(tag, new_color.clone());
+        }
+    }
+    write_config(&config_copy)?;
+    app.config = config_copy;
+    Ok(())
+}
+
+/// Edits the tags of every card carrying the tag, returns the edits for the action history
+fn edit_tag_on_all_cards<F>(app: &mut App, tag: &str, edit: F) -> Vec<ActionHistory>
+where
+    F: Fn(&mut Vec<String>),
+{
+    let tag_lower = tag.to_lowercase();
+    let date_modified = Some(Local::now());
+    let mut batched_actions = vec![];
+    for board in app.boards.get_mut_boards() {
+        let board_id = board.id;
+        for card in board.cards.get_mut_all_cards() {
+            if !card
+                .tags
+                .iter()
+                .any(|card_tag| card_tag.to_lowercase() == tag_lower)
+            {
+                continue;
+            }
+            let old_card = card.clone();
+            edit(&mut card.tags);
+            card.date_modified = date_modified;
+            batched_actions.push(ActionHistory::EditCard(old_card, card.clone(), board_id));
+        }
+    }
+    batched_actions
+}
+
+fn record_tag_changes(app: &mut App, batched_actions: Vec<ActionHistory>) {
+    if !batched_actions.is_empty() {
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(batched_actions));
+    }
+    app.state.all_available_tags = None;
+    refresh_after_boards_change(app);
+}
+
+fn bulk_change_card_status(app: &mut App, status: CardStatus) -> AppReturn {
+    let date_completed = if status == CardStatus::Complete {
+        Some(Local::now())
+    } else {
+        None
+    };
+    let edited_cards = bulk_edit_selected_cards(app, |card| {
+        if card.card_status == status {
+            false
+        } else {
+            card.card_status = status.clone();
+            card.date_completed = date_completed;
+            true
+        }
+    });
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!(
+            "Changed status to \"{}\" for {} cards",
+            status, edited_cards
+        ),
+    );
+    AppReturn::Continue
+}
+
+fn bulk_change_card_priority(app: &mut App, priority: CardPriority) -> AppReturn {
+    let edited_cards = bulk_edit_selected_cards(app, |card| {
+        if card.priority == priority {
+            false
+        } else {
+            card.priority = priority.clone();
+            true
+        }
+    });
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!(
+            "Changed priority to \"{}\" for {} cards",
+            priority, edited_cards
+        ),
+    );
+    AppReturn::Continue
+}
+
+/// The name itself when it is free, otherwise "name (copy)", "name (copy 2)" and so on
+fn get_copy_name<F>(name: &str, name_taken: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    if !name_taken(name) {
+        return name.to_string();
+    }
+    let mut copy_name = format!("{} (copy)", name);
+    let mut copy_number = 2;
+    while name_taken(&copy_name) {
+        copy_name = format!("{} (copy {})", name, copy_number);
+        copy_number += 1;
+    }
+    copy_name
+}
+
+pub fn handle_open_duplicate_card(app: &mut App) -> AppReturn {
+    let current_card_exists = app
+        .state
+        .current_card_id
+        .and_then(|card_id| app.boards.find_board_with_card_id(card_id))
+        .is_some();
+    if current_card_exists {
+        app.set_popup(PopUp::DuplicateCardToBoard);
+    } else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No card selected");
+    }
+    AppReturn::Continue
+}
+
+/// Copies the current card into the board picked in the popup, right after the original when
+/// it is the same board and at the end otherwise
+fn handle_duplicate_card_to_board(app: &mut App) {
+    reset_mouse(app);
+    let search_results = app.get_go_to_board_search_results();
+    let target_board_id = app
+        .state
+        .app_list_states
+        .go_to_board
+        .selected()
+        .and_then(|selected_index| search_results.get(selected_index))
+        .map(|(_, board_id, _)| *board_id);
+    let target_board_id = if let Some(target_board_id) = target_board_id {
+        target_board_id
+    } else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No board selected");
+        return;
+    };
+    app.close_popup();
+    let source = app.state.current_card_id.and_then(|card_id| {
+        app.boards
+            .find_board_with_card_id(card_id)
+            .and_then(|(_, board)| {
+                let card_index = board.cards.get_card_index(card_id)?;
+                let card = board.cards.get_card_with_index(card_index)?;
+                Some((board.id, card_index, card.clone()))
+            })
+    });
+    let (source_board_id, source_card_index, source_card) = if let Some(source) = source {
+        source
+    } else {
+        send_error_toast(&mut app.widgets.toast_widget, "No card selected");
+        return;
+    };
+    let target_board = if let Some(target_board) = app.boards.get_mut_board_with_id(target_board_id)
+    {
+        target_board
+    } else {
+        log::debug!("Board with id {:?} not found", target_board_id);
+        return;
+    };
+    let copy_name = get_copy_name(&source_card.name, |name| {
+        target_board
+            .cards
+            .get_all_cards()
+            .iter()
+            .any(|card| card.name == name)
+    });
+    let new_card = source_card.duplicate(&copy_name);
+    let insert_index = if source_board_id == target_board_id {
+        source_card_index + 1
+    } else {
+        target_board.cards.len()
+    };
+    target_board
+        .cards
+        .add_card_at_index(insert_index, new_card.clone());
+    let target_board_name = target_board.name.clone();
+    update_current_board_and_card(&mut app.state, Some(target_board_id), Some(new_card.id));
+    app.action_history_manager
+        .new_action(ActionHistory::DuplicateCard(
+            new_card.clone(),
+            target_board_id,
+            insert_index,
+        ));
+    refresh_after_boards_change(app);
+    log::info!(
+        "Duplicated card '{}' into board '{}'",
+        source_card.name,
+        target_board_name
+    );
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!(
+            "Duplicated '{}' as '{}' in \"{}\"",
+            source_card.name, new_card.name, target_board_name
+        ),
+    );
+}
+
+/// Copies the current board right after itself, with fresh ids for the board and its cards
+pub fn handle_duplicate_board(app: &mut App, with_cards: bool) -> AppReturn {
+    let source = app.state.current_board_id.and_then(|board_id| {
+        let board_index = app.boards.get_board_index(board_id)?;
+        let board = app.boards.get_board_with_index(board_index)?;
+        Some((board_index, board.clone()))
+    });
+    let (source_board_index, source_board) = if let Some(source) = source {
+        source
+    } else {
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            "No board Selected / Available",
+        );
+        return AppReturn::Continue;
+    };
+    let copy_name = get_copy_name(&source_board.name, |name| {
+        app.boards
+            .get_boards()
+            .iter()
+            .any(|board| board.name == name)
+    });
+    let new_board = source_board.duplicate(&copy_name, with_cards);
+    let insert_index = source_board_index + 1;
+    app.boards
+        .add_board_at_index(insert_index, new_board.clone());
+    update_current_board_and_card(
+        &mut app.state,
+        Some(new_board.id),
+        new_board.cards.get_all_cards().first().map(|card| card.id),
+    );
+    app.action_history_manager
+        .new_action(ActionHistory::DuplicateBoard(
+            new_board.clone(),
+            insert_index,
+        ));
+    refresh_after_boards_change(app);
+    log::info!(
+        "Duplicated board '{}' as '{}'",
+        source_board.name,
+        new_board.name
+    );
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!(
+            "Duplicated board '{}' as '{}'",
+            source_board.name, new_board.name
+        ),
+    );
+    AppReturn::Continue
+}
+
+fn handle_move_selected_cards_to_board(app: &mut App) {
+    reset_mouse(app);
+    let search_results = app.get_go_to_board_search_results();
+    let target_board_id = app
+        .state
+        .app_list_states
+        .go_to_board
+        .selected()
+        .and_then(|selected_index| search_results.get(selected_index))
+        .map(|(_, board_id, _)| *board_id);
+    let target_board_id = if let Some(target_board_id) = target_board_id {
+        target_board_id
+    } else {
+        send_warning_toast(&mut app.widgets.toast_widget, "No board selected");
+        return;
+    };
+    app.close_popup();
+    let mut batched_actions = vec![];
+    for (board_id, card) in get_selected_cards(app) {
+        if board_id == target_board_id {
+            continue;
+        }
+        let moved_from_index =
+            if let Some(moved_from_board) = app.boards.get_mut_board_with_id(board_id) {
+                let moved_from_index = moved_from_board.cards.get_card_index(card.id);
+                moved_from_board.cards.remove_card_with_id(card.id);
+                moved_from_index
+            } else {
+                None
+            };
+        if let (Some(moved_from_index), Some(target_board)) = (
+            moved_from_index,
+            app.boards.get_mut_board_with_id(target_board_id),
+        ) {
+            let moved_to_index = target_board.cards.len();
+            target_board.cards.add_card(card.clone());
+            batched_actions.push(ActionHistory::MoveCardBetweenBoards(
+                card,
+                board_id,
+                target_board_id,
+                moved_from_index,
+                moved_to_index,
+            ));
+        }
+    }
+    let moved_cards = batched_actions.len();
+    if !batched_actions.is_empty() {
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(batched_actions));
+    }
+    let target_board_name = app
+        .boards
+        .get_board_with_id(target_board_id)
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    log::info!("Moved {} cards to board {}", moved_cards, target_board_name);
+    send_info_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Moved {} cards to \"{}\"", moved_cards, target_board_name),
+    );
+    refresh_after_boards_change(app);
+}
+
+fn delete_selected_cards(app: &mut App) {
+    let mut batched_actions = vec![];
+    for (board_id, card) in get_selected_cards(app) {
+        if let Some(board) = app.boards.get_mut_board_with_id(board_id) {
+            board.cards.remove_card_with_id(card.id);
+            batched_actions.push(ActionHistory::DeleteCard(card, board_id));
+        }
+    }
+    let deleted_cards = batched_actions.len();
+    if !batched_actions.is_empty() {
+        app.action_history_manager
+            .new_action(ActionHistory::Batch(batched_actions));
+    }
+    app.state.selected_cards.clear();
+    app.state.card_selection_anchor = None;
+    log::warn!("Deleted {} cards", deleted_cards);
+    send_warning_toast(
+        &mut app.widgets.toast_widget,
+        &format!("Deleted {} cards", deleted_cards),
+    );
+    refresh_after_boards_change(app);
+}
+
+/// Rebuilds the filtered boards and the visible window after a change to `app.boards`
+pub fn refresh_after_boards_change(app: &mut App) {
+    if let Some(filter_tags) = app.state.filter_tags.clone() {
+        if !app.filtered_boards.is_empty() {
+            app.filtered_boards = get_filtered_boards(&app.boards, &filter_tags);
+        }
+    }
+    if let Some(current_board_id) = app.state.current_board_id {
+        let current_card_id = app.state.current_card_id;
+        refresh_visible_boards_and_cards_around(app, current_board_id, current_card_id);
+    } else {
+        refresh_visible_boards_and_cards(app);
     }
-    app.state.set_focus(Focus::Body);
 }
 
 pub async fn handle_login_submit_action(app: &mut App<'_>) {
@@ -5670,6 +7460,7 @@ fn reset_new_card_form(app: &mut App) {
     app.state.text_buffers.card_name.reset();
     app.state.text_buffers.card_description.reset();
     app.widgets.date_time_picker.reset();
+    app.state.applied_card_template = None;
 }
 
 fn reset_login_form(app: &mut App) {
@@ -0,0 +1,99 @@
+use crate::{app::kanban::Boards, constants::FIELD_NOT_SET};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BoardTemplateEntry {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+/// A named set of boards that are created together, cards are never part of a template
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BoardSetTemplate {
+    pub name: String,
+    pub boards: Vec<BoardTemplateEntry>,
+    #[serde(skip)]
+    pub built_in: bool,
+}
+
+impl BoardSetTemplate {
+    fn new_built_in(name: &str, boards: &[(&str, &str)]) -> Self {
+        BoardSetTemplate {
+            name: name.to_string(),
+            boards: boards
+                .iter()
+                .map(|(name, description)| BoardTemplateEntry {
+                    name: name.to_string(),
+                    description: description.to_string(),
+                })
+                .collect(),
+            built_in: true,
+        }
+    }
+
+    pub fn all_built_in() -> Vec<Self> {
+        vec![
+            Self::new_built_in(
+                "Scrum",
+                &[
+                    (
+                        "Backlog",
+                        "Everything that is planned but not yet scheduled",
+                    ),
+                    ("Todo", "Planned for the current sprint"),
+                    ("Doing", "Work in progress"),
+                    ("Review", "Waiting for review or testing"),
+                    ("Done", "Finished in the current sprint"),
+                ],
+            ),
+            Self::new_built_in(
+                "Personal",
+                &[
+                    ("Todo", "Things to do"),
+                    ("Doing", "Things being worked on"),
+                    ("Done", "Things that are done"),
+                    ("Someday", "Ideas for later"),
+                ],
+            ),
+            Self::new_built_in(
+                "Bug triage",
+                &[
+                    ("Reported", "New bug reports"),
+                    ("Confirmed", "Reproduced and ready to be fixed"),
+                    ("In Progress", "Being fixed"),
+                    ("Fixed", "Fix merged, waiting for release"),
+                    ("Won't Fix", "Duplicates, invalid reports and wont fix"),
+                ],
+            ),
+        ]
+    }
+
+    pub fn from_boards(template_name: &str, boards: &Boards) -> Self {
+        BoardSetTemplate {
+            name: template_name.to_string(),
+            boards: boards
+                .get_boards()
+                .iter()
+                .map(|board| BoardTemplateEntry {
+                    name: board.name.clone(),
+                    description: if board.description == FIELD_NOT_SET {
+                        String::new()
+                    } else {
+                        board.description.clone()
+                    },
+                })
+                .collect(),
+            built_in: false,
+        }
+    }
+
+    /// Board names joined with arrows, e.g. "Todo -> Doing -> Done"
+    pub fn summary(&self) -> String {
+        self.boards
+            .iter()
+            .map(|board| board.name.as_str())
+            .collect::<Vec<&str>>()
+            .join(" -> ")
+    }
+}
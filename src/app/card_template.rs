@@ -0,0 +1,67 @@
+use crate::{
+    app::{
+        kanban::{Card, CardPriority},
+        natural_date::parse_natural_date,
+    },
+    constants::FIELD_NOT_SET,
+    ui::widgets::date_time_picker::CalenderType,
+};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CardTemplate {
+    pub name: String,
+    pub description: String,
+    pub priority: CardPriority,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Relative due date like "+3d" or "end of week", resolved when the template is used
+    #[serde(default)]
+    pub due_offset: Option<String>,
+}
+
+impl CardTemplate {
+    /// The due offset is the number of days between the creation and due date of the card
+    pub fn from_card(template_name: &str, card: &Card) -> Self {
+        let description = if card.description == FIELD_NOT_SET {
+            String::new()
+        } else {
+            card.description.clone()
+        };
+        let due_offset = match (card.date_created, card.due_date) {
+            (Some(date_created), Some(due_date)) => {
+                let days = (due_date.date_naive() - date_created.date_naive())
+                    .num_days()
+                    .max(0);
+                Some(format!("+{}d", days))
+            }
+            _ => None,
+        };
+        CardTemplate {
+            name: template_name.to_string(),
+            description,
+            priority: card.priority.clone(),
+            tags: card.tags.clone(),
+            due_offset,
+        }
+    }
+
+    pub fn get_due_date(&self, calender_type: &CalenderType) -> Option<DateTime<Local>> {
+        self.due_offset
+            .as_ref()
+            .and_then(|due_offset| parse_natural_date(due_offset, Local::now(), calender_type))
+    }
+
+    /// Short summary of what the template sets besides the description
+    pub fn summary(&self) -> String {
+        let mut summary = format!("Priority: {}", self.priority);
+        if !self.tags.is_empty() {
+            summary.push_str(&format!(", Tags: {}", self.tags.join(", ")));
+        }
+        if let Some(due_offset) = &self.due_offset {
+            summary.push_str(&format!(", Due: {}", due_offset));
+        }
+        summary
+    }
+}
@@ -1,7 +1,5 @@
-use crate::{
-    app::DateTimeFormat,
-    constants::{FIELD_NA, FIELD_NOT_SET},
-};
+use crate::constants::FIELD_NOT_SET;
+use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt;
@@ -25,6 +23,18 @@ impl Board {
         }
     }
 
+    /// Copy of the board under a new name and id, cards are copied with fresh ids when
+    /// `with_cards` is set
+    pub fn duplicate(&self, name: &str, with_cards: bool) -> Self {
+        let mut board = Board::new(name, &self.description);
+        if with_cards {
+            for card in self.cards.get_all_cards() {
+                board.cards.add_card(card.duplicate(&card.name));
+            }
+        }
+        board
+    }
+
     pub fn from_json(value: &Value) -> Result<Self, String> {
         let id = match value["id"].as_array() {
             Some(id) => {
@@ -60,19 +70,12 @@ impl Board {
             Some(description) => description,
             None => return Err("board description is invalid for board".to_string()),
         };
-        // Mainly for backwards compatibility, recent versions use value["cards"]["cards"] due to Cards being a struct
-        let cards = match value["cards"].as_array() {
+        let cards = match value["cards"]["cards"].as_array() {
             Some(cards) => cards
                 .iter()
                 .map(Card::from_json)
                 .collect::<Result<Cards, String>>()?,
-            None => match value["cards"]["cards"].as_array() {
-                Some(cards) => cards
-                    .iter()
-                    .map(Card::from_json)
-                    .collect::<Result<Cards, String>>()?,
-                None => return Err("board cards is invalid for board".to_string()),
-            },
+            None => return Err("board cards is invalid for board".to_string()),
         };
 
         Ok(Self {
@@ -119,6 +122,9 @@ impl Boards {
     pub fn add_board(&mut self, board: Board) {
         self.boards.push(board);
     }
+    pub fn add_board_at_index(&mut self, index: usize, board: Board) {
+        self.boards.insert(index, board);
+    }
     pub fn get_board_with_id(&self, board_id: (u64, u64)) -> Option<&Board> {
         self.boards.iter().find(|b| b.id == board_id)
     }
@@ -171,6 +177,14 @@ impl Boards {
         self.boards.swap(index_1, index_2);
         Ok(())
     }
+    pub fn move_board(&mut self, from_index: usize, to_index: usize) -> Result<(), BoardSwapError> {
+        if from_index >= self.boards.len() || to_index >= self.boards.len() {
+            return Err(BoardSwapError::IndexOutOfBounds);
+        }
+        let board = self.boards.remove(from_index);
+        self.boards.insert(to_index, board);
+        Ok(())
+    }
 }
 
 impl From<Vec<Board>> for Boards {
@@ -229,14 +243,17 @@ impl CardPriority {
 pub struct Card {
     pub card_status: CardStatus,
     pub comments: Vec<String>,
-    pub date_completed: String,
-    pub date_created: String,
-    pub date_modified: String,
+    pub date_completed: Option<DateTime<Local>>,
+    pub date_created: Option<DateTime<Local>>,
+    pub date_modified: Option<DateTime<Local>>,
     pub description: String,
-    pub due_date: String,
+    pub due_date: Option<DateTime<Local>>,
     pub id: (u64, u64),
     pub name: String,
     pub priority: CardPriority,
+    /// Minutes before the due date at which to remind
+    #[serde(default)]
+    pub reminders: Vec<u64>,
     pub tags: Vec<String>,
 }
 
@@ -244,11 +261,10 @@ impl Card {
     pub fn new(
         name: &str,
         description: &str,
-        due_date: &str,
+        due_date: Option<DateTime<Local>>,
         priority: CardPriority,
         tags: Vec<String>,
         comments: Vec<String>,
-        date_time_format: DateTimeFormat,
     ) -> Self {
         let name = if name.is_empty() { FIELD_NOT_SET } else { name };
         let description = if description.is_empty() {
@@ -256,11 +272,6 @@ impl Card {
         } else {
             description
         };
-        let due_date = if due_date.is_empty() {
-            FIELD_NOT_SET
-        } else {
-            due_date
-        };
         let priority = if priority.to_string().is_empty() {
             CardPriority::Low
         } else {
@@ -273,27 +284,36 @@ impl Card {
             comments
         };
 
-        let corrected_date_time_format = DateTimeFormat::add_time_to_date_format(date_time_format);
-
         Self {
             id: get_id(),
             name: name.to_string(),
             description: description.to_string(),
-            date_created: chrono::Local::now()
-                .format(corrected_date_time_format.to_parser_string())
-                .to_string(),
-            date_modified: chrono::Local::now()
-                .format(corrected_date_time_format.to_parser_string())
-                .to_string(),
-            due_date: due_date.to_string(),
-            date_completed: FIELD_NA.to_string(),
+            date_created: Some(Local::now()),
+            date_modified: Some(Local::now()),
+            due_date,
+            date_completed: None,
             priority,
             card_status: CardStatus::Active,
+            reminders: Vec::new(),
             tags,
             comments,
         }
     }
 
+    /// Copy of the card under a new name and id, created now. The copy has no completion date,
+    /// it is set when the status of the copy is changed to complete
+    pub fn duplicate(&self, name: &str) -> Self {
+        let now = Local::now();
+        Self {
+            id: get_id(),
+            name: name.to_string(),
+            date_created: Some(now),
+            date_modified: Some(now),
+            date_completed: None,
+            ..self.clone()
+        }
+    }
+
     pub fn from_json(value: &Value) -> Result<Self, String> {
         let id = match value["id"].as_array() {
             Some(id) => {
@@ -329,22 +349,14 @@ impl Card {
             Some(description) => description,
             None => return Err("card description is invalid for card".to_string()),
         };
-        let date_created = match value["date_created"].as_str() {
-            Some(date_created) => date_created,
-            None => return Err("card date_created is invalid for card".to_string()),
-        };
-        let date_modified = match value["date_modified"].as_str() {
-            Some(date_modified) => date_modified,
-            None => return Err("card date_modified is invalid for card".to_string()),
-        };
-        let due_date = match value["due_date"].as_str() {
-            Some(due_date) => due_date,
-            None => return Err("card due_date is invalid for card".to_string()),
-        };
-        let date_completed = match value["date_completed"].as_str() {
-            Some(date_completed) => date_completed,
-            None => return Err("card date_completed is invalid for card".to_string()),
-        };
+        let date_created = timestamp_from_json(&value["date_created"])
+            .ok_or("card date_created is invalid for card")?;
+        let date_modified = timestamp_from_json(&value["date_modified"])
+            .ok_or("card date_modified is invalid for card")?;
+        let due_date =
+            timestamp_from_json(&value["due_date"]).ok_or("card due_date is invalid for card")?;
+        let date_completed = timestamp_from_json(&value["date_completed"])
+            .ok_or("card date_completed is invalid for card")?;
         let priority = match value["priority"].as_str() {
             Some(priority) => match priority {
                 "Low" => CardPriority::Low,
@@ -377,21 +389,36 @@ impl Card {
                 .collect(),
             None => return Err("card comments is invalid for card".to_string()),
         };
+        let reminders = match &value["reminders"] {
+            Value::Null => Vec::new(),
+            Value::Array(reminders) => reminders
+                .iter()
+                .map(|r| r.as_u64().ok_or("card reminders is invalid for card"))
+                .collect::<Result<Vec<u64>, &str>>()?,
+            _ => return Err("card reminders is invalid for card".to_string()),
+        };
 
         Ok(Self {
             id,
             name: name.to_string(),
             description: description.to_string(),
-            date_created: date_created.to_string(),
-            date_modified: date_modified.to_string(),
-            due_date: due_date.to_string(),
-            date_completed: date_completed.to_string(),
+            date_created,
+            date_modified,
+            due_date,
+            date_completed,
             priority,
             card_status,
+            reminders,
             tags,
             comments,
         })
     }
+
+    /// Whole days left until the due date, negative once it has passed
+    pub fn get_days_until_due(&self) -> Option<i64> {
+        self.due_date
+            .map(|due_date| due_date.signed_duration_since(Local::now()).num_days())
+    }
 }
 
 impl Default for Card {
@@ -399,18 +426,15 @@ impl Default for Card {
         Self {
             card_status: CardStatus::Active,
             comments: Vec::new(),
-            date_completed: FIELD_NOT_SET.to_string(),
-            date_created: chrono::Local::now()
-                .format(DateTimeFormat::default().to_parser_string())
-                .to_string(),
-            date_modified: chrono::Local::now()
-                .format(DateTimeFormat::default().to_parser_string())
-                .to_string(),
+            date_completed: None,
+            date_created: Some(Local::now()),
+            date_modified: Some(Local::now()),
             description: String::from("Default Card Description"),
-            due_date: FIELD_NOT_SET.to_string(),
+            due_date: None,
             id: get_id(),
             name: String::from("Default Card"),
             priority: CardPriority::Low,
+            reminders: Vec::new(),
             tags: Vec::new(),
         }
     }
@@ -496,3 +520,52 @@ impl FromIterator<Card> for Cards {
 fn get_id() -> (u64, u64) {
     Uuid::new_v4().as_u64_pair()
 }
+
+/// Card dates are stored as RFC 3339 timestamps or null when not set, None if neither
+fn timestamp_from_json(value: &Value) -> Option<Option<DateTime<Local>>> {
+    match value {
+        Value::Null => Some(None),
+        Value::String(timestamp) => DateTime::parse_from_rfc3339(timestamp)
+            .ok()
+            .map(|timestamp| Some(timestamp.with_timezone(&Local))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn duplicated_cards_get_new_ids_and_dates() {
+        let mut card = Card::new(
+            "Card",
+            "Description",
+            None,
+            CardPriority::High,
+            vec!["tag".to_string()],
+            vec![],
+        );
+        card.card_status = CardStatus::Complete;
+        card.date_created = Local::now().checked_sub_signed(Duration::days(3));
+        card.date_completed = Local::now().checked_sub_signed(Duration::days(1));
+
+        let copy = card.duplicate("Card (copy)");
+        assert_ne!(copy.id, card.id);
+        assert_eq!(copy.name, "Card (copy)");
+        assert!(copy.date_created > card.date_created);
+        assert_eq!(copy.date_completed, None);
+        assert_eq!(copy.description, card.description);
+        assert_eq!(copy.tags, card.tags);
+
+        let mut board = Board::new("Board", "");
+        board.cards.add_card(card.clone());
+        let board_copy = board.duplicate("Board (copy)", true);
+        assert_ne!(board_copy.id, board.id);
+        let card_copy = board_copy.cards.get_card_with_index(0).unwrap();
+        assert_ne!(card_copy.id, card.id);
+        assert_eq!(card_copy.date_completed, None);
+        assert!(board.duplicate("Empty", false).cards.is_empty());
+    }
+}
@@ -0,0 +1,741 @@
+use crate::app::{
+    kanban::{Board, Boards, Card, CardPriority, CardStatus},
+    DateTimeFormat,
+};
+use chrono::{DateTime, Local};
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MergeSide {
+    #[default]
+    Ours,
+    Theirs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergeField {
+    BoardDeleted,
+    BoardDescription,
+    BoardName,
+    CardBoard,
+    CardDeleted,
+    CardDescription,
+    CardDueDate,
+    CardName,
+    CardPriority,
+    CardStatus,
+}
+
+impl fmt::Display for MergeField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MergeField::BoardDeleted => write!(f, "Board deleted"),
+            MergeField::BoardDescription => write!(f, "Board description"),
+            MergeField::BoardName => write!(f, "Board name"),
+            MergeField::CardBoard => write!(f, "Board"),
+            MergeField::CardDeleted => write!(f, "Card deleted"),
+            MergeField::CardDescription => write!(f, "Description"),
+            MergeField::CardDueDate => write!(f, "Due date"),
+            MergeField::CardName => write!(f, "Name"),
+            MergeField::CardPriority => write!(f, "Priority"),
+            MergeField::CardStatus => write!(f, "Status"),
+        }
+    }
+}
+
+/// Where the other side of a merge came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeSource {
+    CloudSave(usize),
+    LocalSave(String),
+}
+
+impl MergeSource {
+    /// How the other side is labelled next to "This device" in the conflict popup
+    pub fn theirs_label(&self) -> &str {
+        match self {
+            MergeSource::CloudSave(_) => "Cloud",
+            MergeSource::LocalSave(_) => "Save file",
+        }
+    }
+}
+
+impl fmt::Display for MergeSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MergeSource::CloudSave(save_id) => write!(f, "cloud_save_{}", save_id),
+            MergeSource::LocalSave(file_name) => write!(f, "{}", file_name),
+        }
+    }
+}
+
+/// A board or card id together with the field that conflicts
+pub type MergeConflictKey = ((u64, u64), MergeField);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    pub id: (u64, u64),
+    pub field: MergeField,
+    pub title: String,
+    pub ours: String,
+    pub theirs: String,
+}
+
+impl MergeConflict {
+    pub fn key(&self) -> MergeConflictKey {
+        (self.id, self.field)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MergeResult {
+    pub boards: Boards,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// Three-way merge of two versions of the boards that both started from `base`.
+/// Changes made on only one side are applied, fields changed differently on both
+/// sides are reported as conflicts and resolved using `resolutions` (ours by default)
+pub fn merge_boards(
+    base: &Boards,
+    ours: &Boards,
+    theirs: &Boards,
+    resolutions: &HashMap<MergeConflictKey, MergeSide>,
+) -> MergeResult {
+    let mut merger = Merger {
+        resolutions,
+        conflicts: Vec::new(),
+    };
+
+    let board_order = merge_order(&board_ids(ours), &board_ids(theirs));
+    let mut merged_boards = Vec::new();
+    for board_id in board_order {
+        let merged_board = merger.merge_board(
+            base.get_board_with_id(board_id),
+            ours.get_board_with_id(board_id),
+            theirs.get_board_with_id(board_id),
+        );
+        if let Some(merged_board) = merged_board {
+            merged_boards.push(merged_board);
+        }
+    }
+
+    let base_cards = index_cards(base);
+    let ours_cards = index_cards(ours);
+    let theirs_cards = index_cards(theirs);
+    let board_name = |board_id: (u64, u64)| {
+        ours.get_board_with_id(board_id)
+            .or_else(|| theirs.get_board_with_id(board_id))
+            .map(|board| board.name.clone())
+            .unwrap_or_default()
+    };
+    // Walk the cards in board order so conflicts are always listed in the same order
+    let mut card_ids = card_ids_of(ours);
+    card_ids.extend(
+        card_ids_of(theirs)
+            .into_iter()
+            .filter(|card_id| !ours_cards.contains_key(card_id)),
+    );
+    let mut merged_cards: HashMap<(u64, u64), ((u64, u64), Card)> = HashMap::new();
+    for card_id in card_ids {
+        let base_card = base_cards.get(&card_id).copied();
+        let merged_card = match (
+            base_card,
+            ours_cards.get(&card_id).copied(),
+            theirs_cards.get(&card_id).copied(),
+        ) {
+            (_, Some((ours_board, ours_card)), Some((theirs_board, theirs_card))) => {
+                let card =
+                    merger.merge_card(base_card.map(|(_, card)| card), ours_card, theirs_card);
+                let board_id = merger.merge_field(
+                    (card_id, MergeField::CardBoard),
+                    &ours_card.name,
+                    base_card.map(|(board_id, _)| board_id).as_ref(),
+                    &ours_board,
+                    &theirs_board,
+                    |board_id| board_name(*board_id),
+                );
+                Some((board_id, card))
+            }
+            (None, Some((board_id, card)), None) | (None, None, Some((board_id, card))) => {
+                Some((board_id, card.clone()))
+            }
+            (Some(base_card), Some(kept), None) | (Some(base_card), None, Some(kept)) => {
+                if base_card == kept {
+                    None
+                } else {
+                    let kept_by_ours = ours_cards.contains_key(&card_id);
+                    let (ours_value, theirs_value) = if kept_by_ours {
+                        ("Modified", "Deleted")
+                    } else {
+                        ("Deleted", "Modified")
+                    };
+                    let side = merger.record_conflict(
+                        (card_id, MergeField::CardDeleted),
+                        &kept.1.name,
+                        ours_value.to_string(),
+                        theirs_value.to_string(),
+                    );
+                    if (side == MergeSide::Ours) == kept_by_ours {
+                        Some((kept.0, kept.1.clone()))
+                    } else {
+                        None
+                    }
+                }
+            }
+            (Some(_), None, None) | (None, None, None) => None,
+        };
+        if let Some(merged_card) = merged_card {
+            merged_cards.insert(card_id, merged_card);
+        }
+    }
+
+    for board in merged_boards.iter_mut() {
+        let ours_card_ids = ours
+            .get_board_with_id(board.id)
+            .map(|board| board.cards.get_all_card_ids())
+            .unwrap_or_default();
+        let theirs_card_ids = theirs
+            .get_board_with_id(board.id)
+            .map(|board| board.cards.get_all_card_ids())
+            .unwrap_or_default();
+        let card_order = merge_order(&ours_card_ids, &theirs_card_ids);
+        for card_id in card_order {
+            if let Some((board_id, card)) = merged_cards.get(&card_id) {
+                if *board_id == board.id {
+                    board.cards.add_card(card.clone());
+                }
+            }
+        }
+    }
+
+    MergeResult {
+        boards: Boards::from(merged_boards),
+        conflicts: merger.conflicts,
+    }
+}
+
+/// A merge waiting for the user to resolve its conflicts before it is applied
+#[derive(Debug, Clone)]
+pub struct PendingMerge {
+    pub base: Boards,
+    pub ours: Boards,
+    pub theirs: Boards,
+    pub source: MergeSource,
+    pub conflicts: Vec<MergeConflict>,
+    pub resolutions: HashMap<MergeConflictKey, MergeSide>,
+}
+
+impl PendingMerge {
+    pub fn new(base: Boards, ours: Boards, theirs: Boards, source: MergeSource) -> Self {
+        let mut pending_merge = Self {
+            base,
+            ours,
+            theirs,
+            source,
+            conflicts: Vec::new(),
+            resolutions: HashMap::new(),
+        };
+        pending_merge.conflicts = pending_merge.merge().conflicts;
+        pending_merge
+    }
+
+    pub fn merge(&self) -> MergeResult {
+        merge_boards(&self.base, &self.ours, &self.theirs, &self.resolutions)
+    }
+
+    pub fn get_resolution(&self, conflict_index: usize) -> MergeSide {
+        self.conflicts
+            .get(conflict_index)
+            .and_then(|conflict| self.resolutions.get(&conflict.key()))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Resolving a conflict can change which other conflicts exist (e.g. keeping a deleted board)
+    pub fn set_resolution(&mut self, conflict_index: usize, side: MergeSide) {
+        if let Some(conflict) = self.conflicts.get(conflict_index) {
+            self.resolutions.insert(conflict.key(), side);
+            self.conflicts = self.merge().conflicts;
+        }
+    }
+}
+
+struct Merger<'a> {
+    resolutions: &'a HashMap<MergeConflictKey, MergeSide>,
+    conflicts: Vec<MergeConflict>,
+}
+
+impl Merger<'_> {
+    fn record_conflict(
+        &mut self,
+        (id, field): MergeConflictKey,
+        title: &str,
+        ours: String,
+        theirs: String,
+    ) -> MergeSide {
+        self.conflicts.push(MergeConflict {
+            id,
+            field,
+            title: title.to_string(),
+            ours,
+            theirs,
+        });
+        self.resolutions
+            .get(&(id, field))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn merge_field<T: PartialEq + Clone>(
+        &mut self,
+        key: MergeConflictKey,
+        title: &str,
+        base: Option<&T>,
+        ours: &T,
+        theirs: &T,
+        describe: impl Fn(&T) -> String,
+    ) -> T {
+        if ours == theirs {
+            return ours.clone();
+        }
+        match base {
+            Some(base) if base == ours => return theirs.clone(),
+            Some(base) if base == theirs => return ours.clone(),
+            _ => {}
+        }
+        match self.record_conflict(key, title, describe(ours), describe(theirs)) {
+            MergeSide::Ours => ours.clone(),
+            MergeSide::Theirs => theirs.clone(),
+        }
+    }
+
+    /// Only the board fields are merged here, cards are placed afterwards
+    fn merge_board(
+        &mut self,
+        base: Option<&Board>,
+        ours: Option<&Board>,
+        theirs: Option<&Board>,
+    ) -> Option<Board> {
+        let mut merged_board = match (base, ours, theirs) {
+            (_, Some(ours), Some(theirs)) => {
+                let mut merged_board = ours.clone();
+                merged_board.name = self.merge_field(
+                    (ours.id, MergeField::BoardName),
+                    &ours.name,
+                    base.map(|board| &board.name),
+                    &ours.name,
+                    &theirs.name,
+                    String::clone,
+                );
+                merged_board.description = self.merge_field(
+                    (ours.id, MergeField::BoardDescription),
+                    &ours.name,
+                    base.map(|board| &board.description),
+                    &ours.description,
+                    &theirs.description,
+                    String::clone,
+                );
+                merged_board
+            }
+            (None, Some(added), None) | (None, None, Some(added)) => added.clone(),
+            (Some(base), Some(kept), None) | (Some(base), None, Some(kept)) => {
+                if base == kept {
+                    return None;
+                }
+                let kept_by_ours = ours.is_some();
+                let (ours_value, theirs_value) = if kept_by_ours {
+                    ("Modified", "Deleted")
+                } else {
+                    ("Deleted", "Modified")
+                };
+                let side = self.record_conflict(
+                    (kept.id, MergeField::BoardDeleted),
+                    &kept.name,
+                    ours_value.to_string(),
+                    theirs_value.to_string(),
+                );
+                if (side == MergeSide::Ours) != kept_by_ours {
+                    return None;
+                }
+                kept.clone()
+            }
+            (Some(_), None, None) | (None, None, None) => return None,
+        };
+        merged_board.cards.reset();
+        Some(merged_board)
+    }
+
+    fn merge_card(&mut self, base: Option<&Card>, ours: &Card, theirs: &Card) -> Card {
+        let id = ours.id;
+        let title = ours.name.clone();
+        let mut merged_card = ours.clone();
+        merged_card.name = self.merge_field(
+            (id, MergeField::CardName),
+            &title,
+            base.map(|card| &card.name),
+            &ours.name,
+            &theirs.name,
+            String::clone,
+        );
+        merged_card.description = self.merge_field(
+            (id, MergeField::CardDescription),
+            &title,
+            base.map(|card| &card.description),
+            &ours.description,
+            &theirs.description,
+            String::clone,
+        );
+        merged_card.due_date = self.merge_field(
+            (id, MergeField::CardDueDate),
+            &title,
+            base.map(|card| &card.due_date),
+            &ours.due_date,
+            &theirs.due_date,
+            describe_timestamp,
+        );
+        merged_card.priority = self.merge_field(
+            (id, MergeField::CardPriority),
+            &title,
+            base.map(|card| &card.priority),
+            &ours.priority,
+            &theirs.priority,
+            CardPriority::to_string,
+        );
+        // The completion date always follows the status it belongs to
+        let base_status = base.map(|card| (card.card_status.clone(), card.date_completed));
+        (merged_card.card_status, merged_card.date_completed) = self.merge_field(
+            (id, MergeField::CardStatus),
+            &title,
+            base_status.as_ref(),
+            &(ours.card_status.clone(), ours.date_completed),
+            &(theirs.card_status.clone(), theirs.date_completed),
+            |(card_status, _): &(CardStatus, Option<DateTime<Local>>)| card_status.to_string(),
+        );
+        merged_card.tags = merge_list(base.map(|card| &card.tags), &ours.tags, &theirs.tags);
+        merged_card.comments = merge_list(
+            base.map(|card| &card.comments),
+            &ours.comments,
+            &theirs.comments,
+        );
+        merged_card.reminders = merge_list(
+            base.map(|card| &card.reminders),
+            &ours.reminders,
+            &theirs.reminders,
+        );
+        // Not worth asking about, keep whichever side touched the card
+        if base.map(|card| &card.date_modified) == Some(&ours.date_modified) {
+            merged_card.date_modified = theirs.date_modified;
+        }
+        merged_card
+    }
+}
+
+/// The merge has no access to the configured date format, so an unambiguous one is used
+fn describe_timestamp(timestamp: &Option<DateTime<Local>>) -> String {
+    DateTimeFormat::YearMonthDayTime.format_timestamp(*timestamp)
+}
+
+/// Tags, comments and reminders are merged as sets, additions and removals from both sides are kept
+fn merge_list<T: Clone + PartialEq>(base: Option<&Vec<T>>, ours: &[T], theirs: &[T]) -> Vec<T> {
+    let empty_list = Vec::new();
+    let base = base.unwrap_or(&empty_list);
+    let mut merged_list = ours
+        .iter()
+        .filter(|item| theirs.contains(item) || !base.contains(item))
+        .cloned()
+        .collect::<Vec<T>>();
+    for item in theirs {
+        if !base.contains(item) && !merged_list.contains(item) {
+            merged_list.push(item.clone());
+        }
+    }
+    merged_list
+}
+
+/// Keeps our order and inserts items only present on their side after their predecessor
+fn merge_order(ours: &[(u64, u64)], theirs: &[(u64, u64)]) -> Vec<(u64, u64)> {
+    let mut merged_order = ours.to_vec();
+    for (index, id) in theirs.iter().enumerate() {
+        if merged_order.contains(id) {
+            continue;
+        }
+        let position = theirs[..index]
+            .iter()
+            .rev()
+            .find_map(|previous_id| merged_order.iter().position(|id| id == previous_id))
+            .map(|position| position + 1)
+            .unwrap_or(0);
+        merged_order.insert(position, *id);
+    }
+    merged_order
+}
+
+fn board_ids(boards: &Boards) -> Vec<(u64, u64)> {
+    boards.get_boards().iter().map(|board| board.id).collect()
+}
+
+fn card_ids_of(boards: &Boards) -> Vec<(u64, u64)> {
+    boards
+        .get_boards()
+        .iter()
+        .flat_map(|board| board.cards.get_all_card_ids())
+        .collect()
+}
+
+/// Card id to the id of the board holding it and the card itself
+type CardIndex<'a> = HashMap<(u64, u64), ((u64, u64), &'a Card)>;
+
+fn index_cards(boards: &Boards) -> CardIndex<'_> {
+    let mut cards = HashMap::new();
+    for board in boards.get_boards() {
+        for card in board.cards.get_all_cards() {
+            cards.insert(card.id, (board.id, card));
+        }
+    }
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(name: &str) -> Card {
+        Card::new(name, "", None, CardPriority::Low, Vec::new(), Vec::new())
+    }
+
+    /// Two boards, the first holding two cards
+    fn base_boards() -> Boards {
+        let mut todo = Board::new("Todo", "Things to do");
+        todo.cards.add_card(card("First"));
+        todo.cards.add_card(card("Second"));
+        Boards::from(vec![todo, Board::new("Done", "Things that are done")])
+    }
+
+    fn board_id(boards: &Boards, index: usize) -> (u64, u64) {
+        boards.get_board_with_index(index).unwrap().id
+    }
+
+    fn card_id(boards: &Boards, index: usize) -> (u64, u64) {
+        boards
+            .get_board_with_index(0)
+            .unwrap()
+            .cards
+            .get_card_with_index(index)
+            .unwrap()
+            .id
+    }
+
+    fn get_card(boards: &Boards, card_id: (u64, u64)) -> &Card {
+        let (_, board) = boards.find_board_with_card_id(card_id).unwrap();
+        board.cards.get_card_with_id(card_id).unwrap()
+    }
+
+    fn edit_card(boards: &mut Boards, card_id: (u64, u64), edit: impl FnOnce(&mut Card)) {
+        let card = boards
+            .get_mut_boards()
+            .iter_mut()
+            .find_map(|board| board.cards.get_mut_card_with_id(card_id))
+            .unwrap();
+        edit(card);
+    }
+
+    fn merge(base: &Boards, ours: &Boards, theirs: &Boards) -> MergeResult {
+        merge_boards(base, ours, theirs, &HashMap::new())
+    }
+
+    #[test]
+    fn unchanged_sides_merge_to_base() {
+        let base = base_boards();
+        let result = merge(&base, &base, &base);
+        assert!(result.conflicts.is_empty());
+        assert_eq!(result.boards, base);
+    }
+
+    #[test]
+    fn changes_to_different_fields_are_combined() {
+        let base = base_boards();
+        let first_card = card_id(&base, 0);
+        let mut ours = base.clone();
+        edit_card(&mut ours, first_card, |card| {
+            card.name = "Renamed".to_string()
+        });
+        let mut theirs = base.clone();
+        edit_card(&mut theirs, first_card, |card| {
+            card.priority = CardPriority::High
+        });
+        theirs.get_mut_board_with_index(1).unwrap().description = "Finished work".to_string();
+
+        let result = merge(&base, &ours, &theirs);
+        assert!(result.conflicts.is_empty());
+        let merged_card = get_card(&result.boards, first_card);
+        assert_eq!(merged_card.name, "Renamed");
+        assert_eq!(merged_card.priority, CardPriority::High);
+        assert_eq!(
+            result.boards.get_board_with_index(1).unwrap().description,
+            "Finished work"
+        );
+    }
+
+    #[test]
+    fn cards_added_on_either_side_are_kept() {
+        let base = base_boards();
+        let todo = board_id(&base, 0);
+        let mut ours = base.clone();
+        let our_card = card("Ours");
+        ours.get_mut_board_with_id(todo)
+            .unwrap()
+            .cards
+            .add_card(our_card.clone());
+        let mut theirs = base.clone();
+        let their_card = card("Theirs");
+        theirs
+            .get_mut_board_with_id(todo)
+            .unwrap()
+            .cards
+            .add_card(their_card.clone());
+
+        let result = merge(&base, &ours, &theirs);
+        assert!(result.conflicts.is_empty());
+        let merged_ids = result
+            .boards
+            .get_board_with_id(todo)
+            .unwrap()
+            .cards
+            .get_all_card_ids();
+        // Their card goes right after its predecessor, ahead of ours
+        assert_eq!(
+            merged_ids,
+            vec![
+                card_id(&base, 0),
+                card_id(&base, 1),
+                their_card.id,
+                our_card.id
+            ]
+        );
+    }
+
+    #[test]
+    fn card_deleted_on_one_side_and_untouched_on_the_other_is_removed() {
+        let base = base_boards();
+        let second_card = card_id(&base, 1);
+        let mut theirs = base.clone();
+        theirs
+            .get_mut_board_with_index(0)
+            .unwrap()
+            .cards
+            .remove_card_with_id(second_card);
+
+        let result = merge(&base, &base, &theirs);
+        assert!(result.conflicts.is_empty());
+        assert!(result.boards.find_board_with_card_id(second_card).is_none());
+    }
+
+    #[test]
+    fn card_moved_to_another_board_follows_the_move() {
+        let base = base_boards();
+        let first_card = card_id(&base, 0);
+        let done = board_id(&base, 1);
+        let mut theirs = base.clone();
+        let moved_card = theirs
+            .get_mut_board_with_index(0)
+            .unwrap()
+            .cards
+            .remove_card_with_id(first_card)
+            .unwrap();
+        theirs
+            .get_mut_board_with_id(done)
+            .unwrap()
+            .cards
+            .add_card(moved_card);
+
+        let result = merge(&base, &base, &theirs);
+        assert!(result.conflicts.is_empty());
+        let (_, board) = result.boards.find_board_with_card_id(first_card).unwrap();
+        assert_eq!(board.id, done);
+    }
+
+    #[test]
+    fn same_field_changed_on_both_sides_is_a_conflict() {
+        let base = base_boards();
+        let first_card = card_id(&base, 0);
+        let mut ours = base.clone();
+        edit_card(&mut ours, first_card, |card| card.name = "Ours".to_string());
+        let mut theirs = base.clone();
+        edit_card(&mut theirs, first_card, |card| {
+            card.name = "Theirs".to_string()
+        });
+
+        let result = merge(&base, &ours, &theirs);
+        assert_eq!(result.conflicts.len(), 1);
+        let conflict = &result.conflicts[0];
+        assert_eq!(conflict.key(), (first_card, MergeField::CardName));
+        assert_eq!(conflict.ours, "Ours");
+        assert_eq!(conflict.theirs, "Theirs");
+        assert_eq!(get_card(&result.boards, first_card).name, "Ours");
+
+        let resolutions = HashMap::from([(conflict.key(), MergeSide::Theirs)]);
+        let resolved = merge_boards(&base, &ours, &theirs, &resolutions);
+        assert_eq!(get_card(&resolved.boards, first_card).name, "Theirs");
+    }
+
+    #[test]
+    fn edit_against_delete_is_a_conflict() {
+        let base = base_boards();
+        let second_card = card_id(&base, 1);
+        let mut ours = base.clone();
+        edit_card(&mut ours, second_card, |card| {
+            card.description = "Still needed".to_string()
+        });
+        let mut theirs = base.clone();
+        theirs
+            .get_mut_board_with_index(0)
+            .unwrap()
+            .cards
+            .remove_card_with_id(second_card);
+
+        let mut pending_merge = PendingMerge::new(base, ours, theirs, MergeSource::CloudSave(1));
+        assert_eq!(pending_merge.conflicts.len(), 1);
+        assert_eq!(
+            pending_merge.conflicts[0].key(),
+            (second_card, MergeField::CardDeleted)
+        );
+        assert!(pending_merge
+            .merge()
+            .boards
+            .find_board_with_card_id(second_card)
+            .is_some());
+
+        pending_merge.set_resolution(0, MergeSide::Theirs);
+        assert_eq!(pending_merge.get_resolution(0), MergeSide::Theirs);
+        assert!(pending_merge
+            .merge()
+            .boards
+            .find_board_with_card_id(second_card)
+            .is_none());
+    }
+
+    #[test]
+    fn lists_keep_additions_and_removals_from_both_sides() {
+        let base = vec!["a", "b", "c"];
+        let ours = vec!["a", "c", "d"];
+        let theirs = vec!["a", "b", "e"];
+        assert_eq!(merge_list(Some(&base), &ours, &theirs), vec!["a", "d", "e"]);
+        assert_eq!(merge_list(None, &["a"], &["b", "a"]), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn order_keeps_ours_and_inserts_theirs_after_predecessor() {
+        let ours = vec![(1, 1), (2, 2), (3, 3)];
+        let theirs = vec![(1, 1), (4, 4), (2, 2), (5, 5)];
+        assert_eq!(
+            merge_order(&ours, &theirs),
+            vec![(1, 1), (4, 4), (2, 2), (5, 5), (3, 3)]
+        );
+        assert_eq!(merge_order(&[], &[(6, 6)]), vec![(6, 6)]);
+        assert_eq!(
+            merge_order(&[(7, 7)], &[(6, 6), (7, 7)]),
+            vec![(6, 6), (7, 7)]
+        );
+    }
+}
@@ -3,23 +3,35 @@ use crate::{
         actions::Action,
         app_helper::{
             handle_edit_keybinding_mode, handle_general_actions, handle_mouse_action,
-            handle_user_input_mode, prepare_config_for_new_app,
+            handle_terminal_resize, handle_user_input_mode, prepare_config_for_new_app,
+            refresh_after_boards_change,
         },
+        board_template::BoardSetTemplate,
+        card_template::CardTemplate,
         kanban::{Board, Boards, Card, CardPriority, CardStatus},
-        state::{AppStatus, Focus, KeyBindingEnum, KeyBindings},
+        reminders::split_command,
+        state::{
+            AppStatus, ExternalSaveChangeAction, Focus, KeyBindingEnum, KeyBindings, SyncStatus,
+        },
     },
     constants::{
-        DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_NO_OF_BOARDS_PER_PAGE,
-        DEFAULT_NO_OF_CARDS_PER_BOARD, DEFAULT_TICKRATE, DEFAULT_VIEW, FIELD_NA,
-        IO_EVENT_WAIT_TIME, MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD, MAX_TICKRATE,
-        MAX_WARNING_DUE_DATE_DAYS, MIN_NO_BOARDS_PER_PAGE, MIN_NO_CARDS_PER_BOARD, MIN_TICKRATE,
-        MIN_WARNING_DUE_DATE_DAYS,
+        DEFAULT_ACTION_HISTORY_LIMIT, DEFAULT_AUTO_SYNC_INTERVAL,
+        DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_DAYS_TO_KEEP_SAVES,
+        DEFAULT_NO_OF_BOARDS_PER_PAGE, DEFAULT_NO_OF_CARDS_PER_BOARD,
+        DEFAULT_SAVES_TO_KEEP_PER_DAY, DEFAULT_TICKRATE, DEFAULT_VIEW,
+        DEFAULT_WEEKLY_SAVES_TO_KEEP, FIELD_NA, FIELD_NOT_SET, IO_EVENT_WAIT_TIME,
+        MAX_ACTION_HISTORY_LIMIT, MAX_AUTO_SYNC_INTERVAL, MAX_DAYS_TO_KEEP_SAVES,
+        MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD, MAX_SAVES_TO_KEEP_PER_DAY, MAX_TICKRATE,
+        MAX_WARNING_DUE_DATE_DAYS, MAX_WEEKLY_SAVES_TO_KEEP, MIN_ACTION_HISTORY_LIMIT,
+        MIN_NO_BOARDS_PER_PAGE, MIN_NO_CARDS_PER_BOARD, MIN_TICKRATE, MIN_WARNING_DUE_DATE_DAYS,
+        SAVE_DIRECTORY_CHECK_INTERVAL, SUPABASE_ANON_KEY, SUPABASE_URL,
     },
     inputs::{key::Key, mouse::Mouse},
     io::{
         data_handler::{self, get_available_local_save_files, get_default_save_directory},
-        io_handler::refresh_visible_boards_and_cards,
+        io_handler::detect_external_save_change,
         logger::{get_logs, RUST_KANBAN_LOGGER},
+        sync_backend::SyncBackendType,
         IoEvent,
     },
     ui::{
@@ -28,16 +40,20 @@ use crate::{
         widgets::{date_time_picker::CalenderType, Widgets},
         PopUp, TextColorOptions, TextModifierOptions, View,
     },
-    util::{send_error_toast, send_error_toast_with_duration, send_info_toast, send_warning_toast},
+    util::{
+        fuzzy_match_score, send_error_toast, send_error_toast_with_duration, send_info_toast,
+        send_warning_toast,
+    },
 };
+use chrono::{DateTime, Local};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, warn};
-use ratatui::widgets::TableState;
+use ratatui::{style::Color, widgets::TableState};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use state::AppState;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt::{self, Display, Formatter},
     path::PathBuf,
     str::FromStr,
@@ -49,7 +65,12 @@ use strum_macros::EnumIter;
 
 pub mod actions;
 pub mod app_helper;
+pub mod board_template;
+pub mod card_template;
 pub mod kanban;
+pub mod merge;
+pub mod natural_date;
+pub mod reminders;
 pub mod state;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -74,12 +95,75 @@ pub enum ActionHistory {
     CreateBoard(Board),
     /// old_card, new_card, board_id
     EditCard(Card, Card, (u64, u64)),
+    /// board_id, moved_from_index, moved_to_index
+    MoveBoard((u64, u64), usize, usize),
+    /// card copy, board_id, index the copy was inserted at
+    DuplicateCard(Card, (u64, u64), usize),
+    /// board copy, index the copy was inserted at
+    DuplicateBoard(Board, usize),
+    /// tag (lowercase), old colour, new colour
+    SetTagColor(String, Option<String>, Option<String>),
+    /// actions applied together, undone and redone as one
+    Batch(Vec<ActionHistory>),
+}
+
+impl ActionHistory {
+    pub fn describe(&self) -> String {
+        match self {
+            ActionHistory::DeleteCard(card, _) => format!("Delete Card '{}'", card.name),
+            ActionHistory::CreateCard(card, _) => format!("Create Card '{}'", card.name),
+            ActionHistory::DeleteBoard(board) => format!("Delete Board '{}'", board.name),
+            ActionHistory::MoveCardBetweenBoards(card, _, _, _, _) => {
+                format!("Move Card '{}'", card.name)
+            }
+            ActionHistory::MoveCardWithinBoard(_, moved_from_index, moved_to_index) => format!(
+                "Move Card from position {} to {}",
+                moved_from_index + 1,
+                moved_to_index + 1
+            ),
+            ActionHistory::CreateBoard(board) => format!("Create Board '{}'", board.name),
+            ActionHistory::EditCard(_, new_card, _) => format!("Edit Card '{}'", new_card.name),
+            ActionHistory::MoveBoard(_, moved_from_index, moved_to_index) => format!(
+                "Move Board from position {} to {}",
+                moved_from_index + 1,
+                moved_to_index + 1
+            ),
+            ActionHistory::DuplicateCard(card, _, _) => {
+                format!("Duplicate Card '{}'", card.name)
+            }
+            ActionHistory::DuplicateBoard(board, _) => format!("Duplicate Board '{}'", board.name),
+            ActionHistory::SetTagColor(tag, _, _) => format!("Set Colour of Tag '{}'", tag),
+            ActionHistory::Batch(batched_actions) => {
+                format!("{} changes", batched_actions.len())
+            }
+        }
+    }
+
+    /// Whether undoing or redoing this entry writes the config
+    fn changes_config(&self) -> bool {
+        match self {
+            ActionHistory::SetTagColor(_, _, _) => true,
+            ActionHistory::Batch(batched_actions) => {
+                batched_actions.iter().any(ActionHistory::changes_config)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActionHistoryEntry {
+    pub action: ActionHistory,
+    /// unix timestamp of when the action was performed
+    pub timestamp: i64,
 }
 
 #[derive(Default)]
 pub struct ActionHistoryManager {
-    pub history: Vec<ActionHistory>,
+    pub history: Vec<ActionHistoryEntry>,
     pub history_index: usize,
+    /// 0 means the history is not limited
+    pub history_limit: usize,
 }
 
 impl ActionHistoryManager {
@@ -87,13 +171,35 @@ impl ActionHistoryManager {
         if self.history_index != self.history.len() {
             self.history.truncate(self.history_index);
         }
-        self.history.push(action);
+        self.history.push(ActionHistoryEntry {
+            action,
+            timestamp: chrono::Local::now().timestamp(),
+        });
         self.history_index += 1;
+        self.enforce_history_limit();
     }
     pub fn reset(&mut self) {
         self.history.clear();
         self.history_index = 0;
     }
+    pub fn set_history_limit(&mut self, history_limit: usize) {
+        self.history_limit = history_limit;
+        self.enforce_history_limit();
+    }
+    /// Replaces the history with one restored from disk, keeping the current limit
+    pub fn restore(&mut self, history: Vec<ActionHistoryEntry>, history_index: usize) {
+        self.history_index = history_index.min(history.len());
+        self.history = history;
+        self.enforce_history_limit();
+    }
+    fn enforce_history_limit(&mut self) {
+        if self.history_limit == 0 || self.history.len() <= self.history_limit {
+            return;
+        }
+        let overflow = self.history.len() - self.history_limit;
+        self.history.drain(..overflow);
+        self.history_index = self.history_index.saturating_sub(overflow);
+    }
 }
 
 /// Hashmap of board id to a vector of card id's
@@ -113,6 +219,8 @@ pub struct App<'a> {
     pub last_io_event_time: Option<Instant>,
     pub all_themes: Vec<Theme>,
     pub current_theme: Theme,
+    pub board_templates: Vec<BoardSetTemplate>,
+    pub card_templates: Vec<CardTemplate>,
     pub action_history_manager: ActionHistoryManager,
     pub main_menu: MainMenu,
     pub widgets: Widgets<'a>,
@@ -139,6 +247,10 @@ impl App<'_> {
             config.date_picker_calender_format.clone(),
         );
         widgets.toast_widget.toasts = toasts;
+        let action_history_manager = ActionHistoryManager {
+            history_limit: config.action_history_limit as usize,
+            ..ActionHistoryManager::default()
+        };
         let mut app = Self {
             io_tx,
             actions,
@@ -153,7 +265,9 @@ impl App<'_> {
             last_io_event_time: None,
             all_themes,
             current_theme: theme,
-            action_history_manager: ActionHistoryManager::default(),
+            board_templates: BoardSetTemplate::all_built_in(),
+            card_templates: vec![],
+            action_history_manager,
             main_menu: MainMenu::default(),
             widgets,
         };
@@ -203,6 +317,18 @@ impl App<'_> {
             AppReturn::Continue
         }
     }
+    pub fn handle_resize(&mut self, width: u16, height: u16) -> AppReturn {
+        handle_terminal_resize(self, width, height);
+        AppReturn::Continue
+    }
+    /// Number of boards that fit on a page, a single board when the compact layout is active
+    pub fn get_no_of_boards_to_show(&self) -> u16 {
+        if self.state.compact_layout {
+            1
+        } else {
+            self.config.no_of_boards_to_show
+        }
+    }
     pub fn get_first_keybinding(&self, keybinding_enum: KeyBindingEnum) -> Option<String> {
         self.config
             .keybindings
@@ -224,6 +350,60 @@ impl App<'_> {
     pub fn loaded(&mut self) {
         self.is_loading = false;
     }
+    /// True while an io event is queued or being handled
+    pub fn has_pending_io_events(&self) -> bool {
+        self.is_loading || self.io_tx.capacity() < self.io_tx.max_capacity()
+    }
+    pub fn get_sync_status(&self) -> SyncStatus {
+        if self.state.queued_sync.is_some() {
+            SyncStatus::Pending
+        } else if self.state.last_sync_error.is_some() {
+            SyncStatus::Error
+        } else if self.state.last_synced_boards.as_ref() == Some(&self.boards) {
+            SyncStatus::Synced
+        } else {
+            SyncStatus::Pending
+        }
+    }
+    /// Called every tick, looks for save files written by another instance or edited by hand
+    pub async fn handle_save_directory_check(&mut self) {
+        if self.is_loading
+            || self.state.app_status == AppStatus::UserInput
+            || self.state.z_stack.contains(&PopUp::ExternalSaveChanged)
+            || self.state.z_stack.contains(&PopUp::ResolveMergeConflicts)
+            || self.state.last_save_directory_check.elapsed()
+                < Duration::from_secs(SAVE_DIRECTORY_CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.state.last_save_directory_check = Instant::now();
+        self.dispatch(IoEvent::CheckSaveDirectory).await;
+    }
+    /// Called every tick, syncs when a queued sync is due for a retry or when the auto sync
+    /// interval has passed with unsynced changes
+    pub async fn handle_auto_sync(&mut self) {
+        if !self.main_menu.logged_in
+            || self.is_loading
+            || self.state.pending_merge.is_some()
+            || self.state.app_status == AppStatus::UserInput
+        {
+            return;
+        }
+        let now = Instant::now();
+        let retry_due = self
+            .state
+            .queued_sync
+            .as_ref()
+            .is_some_and(|queued_sync| queued_sync.next_retry <= now);
+        let interval_due = self.config.auto_sync_interval > 0
+            && now.duration_since(self.state.last_auto_sync_time)
+                >= Duration::from_secs(self.config.auto_sync_interval as u64 * 60)
+            && self.get_sync_status() != SyncStatus::Synced;
+        if retry_due || interval_due {
+            self.state.last_auto_sync_time = now;
+            self.dispatch(IoEvent::SyncLocalData).await;
+        }
+    }
     pub fn get_current_focus(&self) -> &Focus {
         &self.state.focus
     }
@@ -238,174 +418,169 @@ impl App<'_> {
             send_error_toast(&mut self.widgets.toast_widget, "No more actions to undo");
         } else {
             let history_index = self.action_history_manager.history_index - 1;
-            let history = self.action_history_manager.history[history_index].clone();
-            match history {
-                ActionHistory::DeleteCard(card, board_id) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        board.cards.add_card(card.clone());
-                        self.action_history_manager.history_index -= 1;
-                        refresh_visible_boards_and_cards(self);
-                        send_info_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Undo Delete Card '{}'", card.name),
-                        );
-                    } else {
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Could not undo delete card '{}' as the board with id '{:?}' was not found", card.name, board_id)
-                        );
-                    }
-                }
-                ActionHistory::CreateCard(card, board_id) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        board.cards.remove_card_with_id(card.id);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index -= 1;
-                        send_info_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Undo Create Card '{}'", card.name),
-                        );
-                    } else {
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Could not undo create card '{}' as the board with id '{:?}' was not found", card.name, board_id)
-                        );
-                    }
-                }
-                ActionHistory::MoveCardBetweenBoards(
-                    card,
-                    moved_from_board_id,
-                    moved_to_board_id,
-                    moved_from_index,
-                    moved_to_index,
-                ) => {
-                    let moved_to_board = self.boards.get_board_with_id(moved_to_board_id);
-                    let moved_from_board = self.boards.get_board_with_id(moved_from_board_id);
-                    if moved_to_board.is_none() || moved_from_board.is_none() {
-                        debug!("Could not undo move card '{}' as the move to board with id '{:?}' or the move from board with id '{:?}' was not found", card.name, moved_to_board_id, moved_from_board_id);
-                        return;
-                    }
-
-                    let moved_from_board = moved_from_board.unwrap();
-                    if moved_from_index > moved_from_board.cards.len() {
-                        debug!("bad index for undo move card, from board {:?}, to board {:?}, from index {}, to index {}", moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index);
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!(
-                                "Could not undo move card '{}' as the index's were invalid",
-                                card.name
-                            ),
-                        );
-                    }
-
-                    let moved_to_board = self
-                        .boards
-                        .get_mut_board_with_id(moved_to_board_id)
-                        .unwrap();
-                    moved_to_board.cards.remove_card_with_id(card.id);
-
-                    let moved_from_board = self
-                        .boards
-                        .get_mut_board_with_id(moved_from_board_id)
-                        .unwrap();
-                    moved_from_board
-                        .cards
-                        .add_card_at_index(moved_from_index, card.clone());
-
-                    refresh_visible_boards_and_cards(self);
+            let history = self.action_history_manager.history[history_index]
+                .action
+                .clone();
+            match self.undo_action(&history) {
+                Ok(undo_message) => {
                     self.action_history_manager.history_index -= 1;
+                    refresh_after_boards_change(self);
                     send_info_toast(
                         &mut self.widgets.toast_widget,
-                        &format!("Undo Move Card '{}'", card.name),
+                        &format!("Undo {}", undo_message),
                     );
                 }
-                ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        if moved_from_index >= board.cards.len()
-                            || moved_to_index >= board.cards.len()
-                        {
-                            send_error_toast(
-                                &mut self.widgets.toast_widget,
-                                &format!(
-                                    "Could not undo move card '{}' as the index's were invalid",
-                                    FIELD_NA
-                                ),
-                            );
-                            return;
-                        }
-                        let card_name = board
-                            .cards
-                            .get_mut_card_with_index(moved_to_index)
-                            .unwrap()
-                            .name
-                            .clone();
-                        board.cards.swap(moved_from_index, moved_to_index);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index -= 1;
-                        send_info_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Undo Move Card '{}'", card_name),
-                        );
-                    } else {
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Could not undo move card '{}' as the board with id '{:?}' was not found",FIELD_NA, board_id)
-                        );
-                    }
+                Err(error_message) => {
+                    send_error_toast(&mut self.widgets.toast_widget, &error_message);
                 }
-                ActionHistory::DeleteBoard(board) => {
-                    self.boards.add_board(board.clone());
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index -= 1;
-                    send_info_toast(
-                        &mut self.widgets.toast_widget,
-                        &format!("Undo Delete Board '{}'", board.name),
-                    );
+            }
+        }
+    }
+
+    /// Reverts a single history entry, returns a description of what was undone
+    fn undo_action(&mut self, history: &ActionHistory) -> Result<String, String> {
+        match history {
+            ActionHistory::DeleteCard(card, board_id) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    board.cards.add_card(card.clone());
+                    Ok(format!("Delete Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not undo delete card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    ))
                 }
-                ActionHistory::CreateBoard(board) => {
-                    self.boards.remove_board_with_id(board.id);
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index -= 1;
-                    send_info_toast(
-                        &mut self.widgets.toast_widget,
-                        &format!("Undo Create Board '{}'", board.name),
-                    );
+            }
+            ActionHistory::CreateCard(card, board_id) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    board.cards.remove_card_with_id(card.id);
+                    Ok(format!("Create Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not undo create card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    ))
                 }
-                ActionHistory::EditCard(old_card, _, board_id) => {
-                    let mut card_name = String::new();
-                    let mut card_found = false;
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        if let Some(card) = board.cards.get_mut_card_with_id(old_card.id) {
-                            *card = old_card.clone();
-                            card_name.clone_from(&card.name);
-                            card_found = true;
-                        } else {
-                            send_error_toast(
-                                &mut self.widgets.toast_widget,
-                                &format!(
-                                    "Could not undo edit card '{}' as the card was not found",
-                                    old_card.name
-                                ),
-                            );
-                        }
-                    } else {
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Could not undo edit card '{}' as the board with id '{:?}' was not found", old_card.name, board_id)
-                        );
-                    }
-                    if card_found {
-                        self.action_history_manager.history_index -= 1;
+            }
+            ActionHistory::MoveCardBetweenBoards(
+                card,
+                moved_from_board_id,
+                moved_to_board_id,
+                moved_from_index,
+                moved_to_index,
+            ) => {
+                let moved_to_board = self.boards.get_board_with_id(*moved_to_board_id);
+                let moved_from_board = self.boards.get_board_with_id(*moved_from_board_id);
+                if moved_to_board.is_none() || moved_from_board.is_none() {
+                    debug!("Could not undo move card '{}' as the move to board with id '{:?}' or the move from board with id '{:?}' was not found", card.name, moved_to_board_id, moved_from_board_id);
+                    return Err(format!(
+                        "Could not undo move card '{}' as the board was not found",
+                        card.name
+                    ));
+                }
+
+                let moved_from_board = moved_from_board.unwrap();
+                if *moved_from_index > moved_from_board.cards.len() {
+                    debug!("bad index for undo move card, from board {:?}, to board {:?}, from index {}, to index {}", moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index);
+                    return Err(format!(
+                        "Could not undo move card '{}' as the index's were invalid",
+                        card.name
+                    ));
+                }
+
+                let moved_to_board = self
+                    .boards
+                    .get_mut_board_with_id(*moved_to_board_id)
+                    .unwrap();
+                moved_to_board.cards.remove_card_with_id(card.id);
+
+                let moved_from_board = self
+                    .boards
+                    .get_mut_board_with_id(*moved_from_board_id)
+                    .unwrap();
+                moved_from_board
+                    .cards
+                    .add_card_at_index(*moved_from_index, card.clone());
+
+                Ok(format!("Move Card '{}'", card.name))
+            }
+            ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    if *moved_from_index >= board.cards.len()
+                        || *moved_to_index >= board.cards.len()
+                    {
+                        return Err(format!(
+                            "Could not undo move card '{}' as the index's were invalid",
+                            FIELD_NA
+                        ));
                     }
-                    if !card_name.is_empty() {
-                        send_info_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Undo Edit Card '{}'", card_name),
-                        );
-                        refresh_visible_boards_and_cards(self);
+                    let card_name = board
+                        .cards
+                        .get_mut_card_with_index(*moved_to_index)
+                        .unwrap()
+                        .name
+                        .clone();
+                    board.cards.swap(*moved_from_index, *moved_to_index);
+                    Ok(format!("Move Card '{}'", card_name))
+                } else {
+                    Err(format!(
+                        "Could not undo move card '{}' as the board with id '{:?}' was not found",
+                        FIELD_NA, board_id
+                    ))
+                }
+            }
+            ActionHistory::DeleteBoard(board) => {
+                self.boards.add_board(board.clone());
+                Ok(format!("Delete Board '{}'", board.name))
+            }
+            ActionHistory::CreateBoard(board) => {
+                self.boards.remove_board_with_id(board.id);
+                Ok(format!("Create Board '{}'", board.name))
+            }
+            ActionHistory::DuplicateCard(card, board_id, _) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    board.cards.remove_card_with_id(card.id);
+                    Ok(format!("Duplicate Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not undo duplicate card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    ))
+                }
+            }
+            ActionHistory::DuplicateBoard(board, _) => {
+                self.boards.remove_board_with_id(board.id);
+                Ok(format!("Duplicate Board '{}'", board.name))
+            }
+            ActionHistory::EditCard(old_card, _, board_id) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    if let Some(card) = board.cards.get_mut_card_with_id(old_card.id) {
+                        *card = old_card.clone();
+                        Ok(format!("Edit Card '{}'", card.name))
+                    } else {
+                        Err(format!(
+                            "Could not undo edit card '{}' as the card was not found",
+                            old_card.name
+                        ))
                     }
+                } else {
+                    Err(format!(
+                        "Could not undo edit card '{}' as the board with id '{:?}' was not found",
+                        old_card.name, board_id
+                    ))
                 }
             }
+            ActionHistory::MoveBoard(board_id, moved_from_index, moved_to_index) => self
+                .move_board_for_history(*board_id, *moved_to_index, *moved_from_index)
+                .map_err(|error_message| format!("Could not undo move board {}", error_message)),
+            ActionHistory::SetTagColor(tag, old_color, _) => self
+                .set_tag_color_for_history(tag, old_color)
+                .map_err(|error_message| {
+                    format!("Could not undo set tag colour {}", error_message)
+                }),
+            ActionHistory::Batch(batched_actions) => {
+                self.apply_batch_for_history(batched_actions, true)
+            }
         }
     }
 
@@ -414,177 +589,315 @@ impl App<'_> {
             send_error_toast(&mut self.widgets.toast_widget, "No more actions to redo");
         } else {
             let history_index = self.action_history_manager.history_index;
-            let history = self.action_history_manager.history[history_index].clone();
-            match history {
-                ActionHistory::DeleteCard(card, board_id) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        board.cards.remove_card_with_id(card.id);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        send_info_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Redo Delete Card '{}'", card.name),
-                        );
-                    } else {
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Could not redo delete card '{}' as the board with id '{:?}' was not found", card.name, board_id)
-                        );
-                    }
-                }
-                ActionHistory::CreateCard(card, board_id) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        board.cards.add_card(card.clone());
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        send_info_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Redo Create Card '{}'", card.name),
-                        );
-                    } else {
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Could not redo create card '{}' as the board with id '{:?}' was not found", card.name, board_id)
-                        );
-                    }
-                }
-                ActionHistory::MoveCardBetweenBoards(
-                    card,
-                    moved_from_board_id,
-                    moved_to_board_id,
-                    moved_from_index,
-                    moved_to_index,
-                ) => {
-                    let moved_to_board = self.boards.get_board_with_id(moved_to_board_id);
-                    let moved_from_board = self.boards.get_board_with_id(moved_from_board_id);
-                    if moved_to_board.is_none() || moved_from_board.is_none() {
-                        debug!("Could not undo move card '{}' as the move to board with id '{:?}' or the move from board with id '{:?}' was not found", card.name, moved_to_board_id, moved_from_board_id);
-                        return;
-                    }
-
-                    let moved_to_board = moved_to_board.unwrap();
-                    if moved_to_index > moved_to_board.cards.len() {
-                        debug!("bad index for redo move card, from board {:?}, to board {:?}, from index {}, to index {}", moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index);
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!(
-                                "Could not redo move card '{}' as the index's were invalid",
-                                card.name
-                            ),
-                        );
-                        return;
-                    }
-
-                    let moved_from_board = self
-                        .boards
-                        .get_mut_board_with_id(moved_from_board_id)
-                        .unwrap();
-                    moved_from_board.cards.remove_card_with_id(card.id);
-
-                    let moved_to_board = self
-                        .boards
-                        .get_mut_board_with_id(moved_to_board_id)
-                        .unwrap();
-                    moved_to_board
-                        .cards
-                        .add_card_at_index(moved_to_index, card.clone());
-
-                    refresh_visible_boards_and_cards(self);
+            let history = self.action_history_manager.history[history_index]
+                .action
+                .clone();
+            match self.redo_action(&history) {
+                Ok(redo_message) => {
                     self.action_history_manager.history_index += 1;
+                    refresh_after_boards_change(self);
                     send_info_toast(
                         &mut self.widgets.toast_widget,
-                        &format!("Redo Move Card '{}'", card.name),
+                        &format!("Redo {}", redo_message),
                     );
                 }
-                ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        if moved_from_index >= board.cards.len()
-                            || moved_to_index >= board.cards.len()
-                        {
-                            send_error_toast(
-                                &mut self.widgets.toast_widget,
-                                &format!(
-                                    "Could not redo move card '{}' as the index's were invalid",
-                                    FIELD_NA
-                                ),
-                            );
-                            return;
-                        }
-                        let card_name = board
-                            .cards
-                            .get_card_with_index(moved_to_index)
-                            .unwrap()
-                            .name
-                            .clone();
-                        board.cards.swap(moved_from_index, moved_to_index);
-                        refresh_visible_boards_and_cards(self);
-                        self.action_history_manager.history_index += 1;
-                        send_info_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Redo Move Card '{}'", card_name),
-                        );
-                    } else {
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Could not redo move card '{}' as the board with id '{:?}' was not found", FIELD_NA, board_id)
-                        );
-                    }
+                Err(error_message) => {
+                    send_error_toast(&mut self.widgets.toast_widget, &error_message);
                 }
-                ActionHistory::DeleteBoard(board) => {
-                    self.boards.remove_board_with_id(board.id);
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index += 1;
-                    send_info_toast(
-                        &mut self.widgets.toast_widget,
-                        &format!("Redo Delete Board '{}'", board.name),
-                    );
+            }
+        }
+    }
+
+    /// Re-applies a single history entry, returns a description of what was redone
+    fn redo_action(&mut self, history: &ActionHistory) -> Result<String, String> {
+        match history {
+            ActionHistory::DeleteCard(card, board_id) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    board.cards.remove_card_with_id(card.id);
+                    Ok(format!("Delete Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not redo delete card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    ))
                 }
-                ActionHistory::CreateBoard(board) => {
-                    self.boards.add_board(board.clone());
-                    refresh_visible_boards_and_cards(self);
-                    self.action_history_manager.history_index += 1;
-                    send_info_toast(
-                        &mut self.widgets.toast_widget,
-                        &format!("Redo Create Board '{}'", board.name),
-                    );
+            }
+            ActionHistory::CreateCard(card, board_id) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    board.cards.add_card(card.clone());
+                    Ok(format!("Create Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not redo create card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    ))
                 }
-                ActionHistory::EditCard(_, new_card, board_id) => {
-                    let mut card_name = String::new();
-                    let mut card_found = false;
-                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
-                        if let Some(card) = board.cards.get_mut_card_with_id(new_card.id) {
-                            *card = new_card.clone();
-                            card_name.clone_from(&card.name);
-                            card_found = true;
-                        } else {
-                            send_error_toast(
-                                &mut self.widgets.toast_widget,
-                                &format!(
-                                    "Could not redo edit card '{}' as the card was not found",
-                                    new_card.name
-                                ),
-                            );
-                        }
-                    } else {
-                        send_error_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Could not redo edit card '{}' as the board with id '{:?}' was not found", new_card.name, board_id)
-                        );
-                    }
-                    if card_found {
-                        self.action_history_manager.history_index += 1;
+            }
+            ActionHistory::MoveCardBetweenBoards(
+                card,
+                moved_from_board_id,
+                moved_to_board_id,
+                moved_from_index,
+                moved_to_index,
+            ) => {
+                let moved_to_board = self.boards.get_board_with_id(*moved_to_board_id);
+                let moved_from_board = self.boards.get_board_with_id(*moved_from_board_id);
+                if moved_to_board.is_none() || moved_from_board.is_none() {
+                    debug!("Could not redo move card '{}' as the move to board with id '{:?}' or the move from board with id '{:?}' was not found", card.name, moved_to_board_id, moved_from_board_id);
+                    return Err(format!(
+                        "Could not redo move card '{}' as the board was not found",
+                        card.name
+                    ));
+                }
+
+                let moved_to_board = moved_to_board.unwrap();
+                if *moved_to_index > moved_to_board.cards.len() {
+                    debug!("bad index for redo move card, from board {:?}, to board {:?}, from index {}, to index {}", moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index);
+                    return Err(format!(
+                        "Could not redo move card '{}' as the index's were invalid",
+                        card.name
+                    ));
+                }
+
+                let moved_from_board = self
+                    .boards
+                    .get_mut_board_with_id(*moved_from_board_id)
+                    .unwrap();
+                moved_from_board.cards.remove_card_with_id(card.id);
+
+                let moved_to_board = self
+                    .boards
+                    .get_mut_board_with_id(*moved_to_board_id)
+                    .unwrap();
+                moved_to_board
+                    .cards
+                    .add_card_at_index(*moved_to_index, card.clone());
+
+                Ok(format!("Move Card '{}'", card.name))
+            }
+            ActionHistory::MoveCardWithinBoard(board_id, moved_from_index, moved_to_index) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    if *moved_from_index >= board.cards.len()
+                        || *moved_to_index >= board.cards.len()
+                    {
+                        return Err(format!(
+                            "Could not redo move card '{}' as the index's were invalid",
+                            FIELD_NA
+                        ));
                     }
-                    if !card_name.is_empty() {
-                        send_info_toast(
-                            &mut self.widgets.toast_widget,
-                            &format!("Redo Edit Card '{}'", card_name),
-                        );
-                        refresh_visible_boards_and_cards(self);
+                    let card_name = board
+                        .cards
+                        .get_card_with_index(*moved_to_index)
+                        .unwrap()
+                        .name
+                        .clone();
+                    board.cards.swap(*moved_from_index, *moved_to_index);
+                    Ok(format!("Move Card '{}'", card_name))
+                } else {
+                    Err(format!(
+                        "Could not redo move card '{}' as the board with id '{:?}' was not found",
+                        FIELD_NA, board_id
+                    ))
+                }
+            }
+            ActionHistory::DeleteBoard(board) => {
+                self.boards.remove_board_with_id(board.id);
+                Ok(format!("Delete Board '{}'", board.name))
+            }
+            ActionHistory::CreateBoard(board) => {
+                self.boards.add_board(board.clone());
+                Ok(format!("Create Board '{}'", board.name))
+            }
+            ActionHistory::DuplicateCard(card, board_id, index) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    let index = (*index).min(board.cards.len());
+                    board.cards.add_card_at_index(index, card.clone());
+                    Ok(format!("Duplicate Card '{}'", card.name))
+                } else {
+                    Err(format!(
+                        "Could not redo duplicate card '{}' as the board with id '{:?}' was not found",
+                        card.name, board_id
+                    ))
+                }
+            }
+            ActionHistory::DuplicateBoard(board, index) => {
+                let index = (*index).min(self.boards.len());
+                self.boards.add_board_at_index(index, board.clone());
+                Ok(format!("Duplicate Board '{}'", board.name))
+            }
+            ActionHistory::EditCard(_, new_card, board_id) => {
+                if let Some(board) = self.boards.get_mut_board_with_id(*board_id) {
+                    if let Some(card) = board.cards.get_mut_card_with_id(new_card.id) {
+                        *card = new_card.clone();
+                        Ok(format!("Edit Card '{}'", card.name))
+                    } else {
+                        Err(format!(
+                            "Could not redo edit card '{}' as the card was not found",
+                            new_card.name
+                        ))
                     }
+                } else {
+                    Err(format!(
+                        "Could not redo edit card '{}' as the board with id '{:?}' was not found",
+                        new_card.name, board_id
+                    ))
                 }
             }
+            ActionHistory::MoveBoard(board_id, moved_from_index, moved_to_index) => self
+                .move_board_for_history(*board_id, *moved_from_index, *moved_to_index)
+                .map_err(|error_message| format!("Could not redo move board {}", error_message)),
+            ActionHistory::SetTagColor(tag, _, new_color) => self
+                .set_tag_color_for_history(tag, new_color)
+                .map_err(|error_message| {
+                    format!("Could not redo set tag colour {}", error_message)
+                }),
+            ActionHistory::Batch(batched_actions) => {
+                self.apply_batch_for_history(batched_actions, false)
+            }
+        }
+    }
+
+    /// Undoes or redoes every action of a batch, or none of them if one fails
+    fn apply_batch_for_history(
+        &mut self,
+        batched_actions: &[ActionHistory],
+        undo: bool,
+    ) -> Result<String, String> {
+        let boards_snapshot = self.boards.clone();
+        let config_snapshot = if batched_actions.iter().any(ActionHistory::changes_config) {
+            Some(self.config.clone())
+        } else {
+            None
+        };
+        let result = if undo {
+            batched_actions
+                .iter()
+                .rev()
+                .try_for_each(|batched_action| self.undo_action(batched_action).map(|_| ()))
+        } else {
+            batched_actions
+                .iter()
+                .try_for_each(|batched_action| self.redo_action(batched_action).map(|_| ()))
+        };
+        if let Err(error_message) = result {
+            self.restore_history_snapshot(boards_snapshot, config_snapshot);
+            return Err(error_message);
+        }
+        Ok(format!("{} changes", batched_actions.len()))
+    }
+
+    /// Puts back the boards and config taken before a failed undo or redo
+    fn restore_history_snapshot(&mut self, boards: Boards, config: Option<AppConfig>) {
+        self.boards = boards;
+        if let Some(config) = config {
+            if let Err(error_message) = data_handler::write_config(&config) {
+                error!(
+                    "Could not restore config after a failed undo or redo: {}",
+                    error_message
+                );
+            }
+            self.config = config;
+        }
+    }
+
+    /// Sets or clears the colour of a tag for undo and redo, writing the config
+    fn set_tag_color_for_history(
+        &mut self,
+        tag: &str,
+        color: &Option<String>,
+    ) -> Result<String, String> {
+        let mut config_copy = self.config.clone();
+        config_copy.set_tag_color(tag, color.clone());
+        if data_handler::write_config(&config_copy).is_err() {
+            return Err(format!("'{}' as the config could not be written", tag));
+        }
+        self.config = config_copy;
+        Ok(format!("Set Colour of Tag '{}'", tag))
+    }
+
+    /// Moves a board back or forth for undo and redo, checking it is where the history expects
+    fn move_board_for_history(
+        &mut self,
+        board_id: (u64, u64),
+        expected_index: usize,
+        target_index: usize,
+    ) -> Result<String, String> {
+        let board_name = match self.boards.get_board_with_id(board_id) {
+            Some(board) => board.name.clone(),
+            None => {
+                return Err(format!(
+                    "as the board with id '{:?}' was not found",
+                    board_id
+                ))
+            }
+        };
+        if self.boards.get_board_index(board_id) != Some(expected_index) {
+            return Err(format!("'{}' as the index's were invalid", board_name));
+        }
+        match self.boards.move_board(expected_index, target_index) {
+            Ok(_) => Ok(format!("Move Board '{}'", board_name)),
+            Err(_) => Err(format!("'{}' as the index's were invalid", board_name)),
+        }
+    }
+
+    /// Undoes or redoes entries until the history index matches the target index, all or nothing
+    pub fn jump_to_action_history_index(&mut self, target_index: usize) {
+        let target_index = target_index.min(self.action_history_manager.history.len());
+        let starting_index = self.action_history_manager.history_index;
+        let boards_snapshot = self.boards.clone();
+        let config_snapshot = if self.action_history_manager.history
+            [target_index.min(starting_index)..target_index.max(starting_index)]
+            .iter()
+            .any(|entry| entry.action.changes_config())
+        {
+            Some(self.config.clone())
+        } else {
+            None
+        };
+        let mut jump_error = None;
+        while self.action_history_manager.history_index > target_index {
+            let history = self.action_history_manager.history
+                [self.action_history_manager.history_index - 1]
+                .action
+                .clone();
+            if let Err(error_message) = self.undo_action(&history) {
+                jump_error = Some(error_message);
+                break;
+            }
+            self.action_history_manager.history_index -= 1;
+        }
+        while jump_error.is_none() && self.action_history_manager.history_index < target_index {
+            let history = self.action_history_manager.history
+                [self.action_history_manager.history_index]
+                .action
+                .clone();
+            if let Err(error_message) = self.redo_action(&history) {
+                jump_error = Some(error_message);
+                break;
+            }
+            self.action_history_manager.history_index += 1;
+        }
+        if jump_error.is_some() {
+            self.restore_history_snapshot(boards_snapshot, config_snapshot);
+            self.action_history_manager.history_index = starting_index;
+        }
+        refresh_after_boards_change(self);
+        let current_index = self.action_history_manager.history_index;
+        if let Some(error_message) = jump_error {
+            send_error_toast(&mut self.widgets.toast_widget, &error_message);
+        } else if current_index < starting_index {
+            send_info_toast(
+                &mut self.widgets.toast_widget,
+                &format!("Undid {} actions", starting_index - current_index),
+            );
+        } else if current_index > starting_index {
+            send_info_toast(
+                &mut self.widgets.toast_widget,
+                &format!("Redid {} actions", current_index - starting_index),
+            );
         }
     }
+
     fn select_next(current_index: Option<usize>, items_len: usize) -> usize {
         match current_index {
             Some(i) => {
@@ -864,49 +1177,249 @@ impl App<'_> {
             self.state.app_list_states.edit_specific_style[1].selected(),
             TextColorOptions::iter().count(),
         );
-        self.state.app_list_states.edit_specific_style[1].select(Some(i));
+        self.state.app_list_states.edit_specific_style[1].select(Some(i));
+    }
+    pub fn select_edit_style_bg_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state.app_list_states.edit_specific_style[1].selected(),
+            TextColorOptions::iter().count(),
+        );
+        self.state.app_list_states.edit_specific_style[1].select(Some(i));
+    }
+    pub fn select_edit_style_modifier_next(&mut self) {
+        let i = Self::select_next(
+            self.state.app_list_states.edit_specific_style[2].selected(),
+            TextModifierOptions::iter().count(),
+        );
+        self.state.app_list_states.edit_specific_style[2].select(Some(i));
+    }
+    pub fn select_edit_style_modifier_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state.app_list_states.edit_specific_style[2].selected(),
+            TextModifierOptions::iter().count(),
+        );
+        self.state.app_list_states.edit_specific_style[2].select(Some(i));
+    }
+    pub fn select_card_priority_next(&mut self) {
+        let i = Self::select_next(
+            self.state.app_list_states.card_priority_selector.selected(),
+            CardPriority::all().len(),
+        );
+        self.state
+            .app_list_states
+            .card_priority_selector
+            .select(Some(i));
+    }
+    pub fn select_card_priority_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state.app_list_states.card_priority_selector.selected(),
+            CardPriority::all().len(),
+        );
+        self.state
+            .app_list_states
+            .card_priority_selector
+            .select(Some(i));
+    }
+    pub fn go_to_board_next(&mut self) {
+        let search_results_len = self.get_go_to_board_search_results().len();
+        if search_results_len > 0 {
+            let i = Self::select_next(
+                self.state.app_list_states.go_to_board.selected(),
+                search_results_len,
+            );
+            self.state.app_list_states.go_to_board.select(Some(i));
+        }
+    }
+    pub fn go_to_board_prv(&mut self) {
+        let search_results_len = self.get_go_to_board_search_results().len();
+        if search_results_len > 0 {
+            let i = Self::select_previous(
+                self.state.app_list_states.go_to_board.selected(),
+                search_results_len,
+            );
+            self.state.app_list_states.go_to_board.select(Some(i));
+        }
+    }
+    pub fn bulk_actions_next(&mut self) {
+        let i = Self::select_next(
+            self.state.app_list_states.bulk_actions.selected(),
+            BulkCardAction::all().len(),
+        );
+        self.state.app_list_states.bulk_actions.select(Some(i));
+    }
+    pub fn bulk_actions_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state.app_list_states.bulk_actions.selected(),
+            BulkCardAction::all().len(),
+        );
+        self.state.app_list_states.bulk_actions.select(Some(i));
+    }
+    pub fn card_template_picker_next(&mut self) {
+        if self.card_templates.is_empty() {
+            return;
+        }
+        let i = Self::select_next(
+            self.state.app_list_states.card_template_picker.selected(),
+            self.card_templates.len(),
+        );
+        self.state
+            .app_list_states
+            .card_template_picker
+            .select(Some(i));
+    }
+    pub fn card_template_picker_prv(&mut self) {
+        if self.card_templates.is_empty() {
+            return;
+        }
+        let i = Self::select_previous(
+            self.state.app_list_states.card_template_picker.selected(),
+            self.card_templates.len(),
+        );
+        self.state
+            .app_list_states
+            .card_template_picker
+            .select(Some(i));
+    }
+    pub fn board_template_picker_next(&mut self) {
+        if self.board_templates.is_empty() {
+            return;
+        }
+        let i = Self::select_next(
+            self.state.app_list_states.board_template_picker.selected(),
+            self.board_templates.len(),
+        );
+        self.state
+            .app_list_states
+            .board_template_picker
+            .select(Some(i));
+    }
+    pub fn board_template_picker_prv(&mut self) {
+        if self.board_templates.is_empty() {
+            return;
+        }
+        let i = Self::select_previous(
+            self.state.app_list_states.board_template_picker.selected(),
+            self.board_templates.len(),
+        );
+        self.state
+            .app_list_states
+            .board_template_picker
+            .select(Some(i));
+    }
+    pub fn tag_manager_next(&mut self) {
+        let tag_count = self.calculate_tags().len();
+        if tag_count == 0 {
+            return;
+        }
+        let i = Self::select_next(
+            self.state.app_table_states.tag_manager.selected(),
+            tag_count,
+        );
+        self.state.app_table_states.tag_manager.select(Some(i));
     }
-    pub fn select_edit_style_bg_prv(&mut self) {
+    pub fn tag_manager_prv(&mut self) {
+        let tag_count = self.calculate_tags().len();
+        if tag_count == 0 {
+            return;
+        }
         let i = Self::select_previous(
-            self.state.app_list_states.edit_specific_style[1].selected(),
-            TextColorOptions::iter().count(),
+            self.state.app_table_states.tag_manager.selected(),
+            tag_count,
         );
-        self.state.app_list_states.edit_specific_style[1].select(Some(i));
+        self.state.app_table_states.tag_manager.select(Some(i));
     }
-    pub fn select_edit_style_modifier_next(&mut self) {
+    pub fn tag_manager_actions_next(&mut self) {
         let i = Self::select_next(
-            self.state.app_list_states.edit_specific_style[2].selected(),
-            TextModifierOptions::iter().count(),
+            self.state.app_list_states.tag_manager_actions.selected(),
+            TagManagerAction::all().len(),
         );
-        self.state.app_list_states.edit_specific_style[2].select(Some(i));
+        self.state
+            .app_list_states
+            .tag_manager_actions
+            .select(Some(i));
     }
-    pub fn select_edit_style_modifier_prv(&mut self) {
+    pub fn tag_manager_actions_prv(&mut self) {
         let i = Self::select_previous(
-            self.state.app_list_states.edit_specific_style[2].selected(),
-            TextModifierOptions::iter().count(),
+            self.state.app_list_states.tag_manager_actions.selected(),
+            TagManagerAction::all().len(),
         );
-        self.state.app_list_states.edit_specific_style[2].select(Some(i));
+        self.state
+            .app_list_states
+            .tag_manager_actions
+            .select(Some(i));
     }
-    pub fn select_card_priority_next(&mut self) {
+    pub fn external_save_change_actions_next(&mut self) {
         let i = Self::select_next(
-            self.state.app_list_states.card_priority_selector.selected(),
-            CardPriority::all().len(),
+            self.state
+                .app_list_states
+                .external_save_change_actions
+                .selected(),
+            ExternalSaveChangeAction::all().len(),
         );
         self.state
             .app_list_states
-            .card_priority_selector
+            .external_save_change_actions
             .select(Some(i));
     }
-    pub fn select_card_priority_prv(&mut self) {
+    pub fn external_save_change_actions_prv(&mut self) {
         let i = Self::select_previous(
-            self.state.app_list_states.card_priority_selector.selected(),
-            CardPriority::all().len(),
+            self.state
+                .app_list_states
+                .external_save_change_actions
+                .selected(),
+            ExternalSaveChangeAction::all().len(),
         );
         self.state
             .app_list_states
-            .card_priority_selector
+            .external_save_change_actions
             .select(Some(i));
     }
+    pub fn undo_history_next(&mut self) {
+        let i = Self::select_next(
+            self.state.app_list_states.undo_history.selected(),
+            self.action_history_manager.history.len() + 1,
+        );
+        self.state.app_list_states.undo_history.select(Some(i));
+    }
+    pub fn undo_history_prv(&mut self) {
+        let i = Self::select_previous(
+            self.state.app_list_states.undo_history.selected(),
+            self.action_history_manager.history.len() + 1,
+        );
+        self.state.app_list_states.undo_history.select(Some(i));
+    }
+    pub fn merge_conflicts_next(&mut self) {
+        let conflicts_len = self
+            .state
+            .pending_merge
+            .as_ref()
+            .map(|pending_merge| pending_merge.conflicts.len())
+            .unwrap_or(0);
+        if conflicts_len == 0 {
+            return;
+        }
+        let i = Self::select_next(
+            self.state.app_list_states.merge_conflicts.selected(),
+            conflicts_len,
+        );
+        self.state.app_list_states.merge_conflicts.select(Some(i));
+    }
+    pub fn merge_conflicts_prv(&mut self) {
+        let conflicts_len = self
+            .state
+            .pending_merge
+            .as_ref()
+            .map(|pending_merge| pending_merge.conflicts.len())
+            .unwrap_or(0);
+        if conflicts_len == 0 {
+            return;
+        }
+        let i = Self::select_previous(
+            self.state.app_list_states.merge_conflicts.selected(),
+            conflicts_len,
+        );
+        self.state.app_list_states.merge_conflicts.select(Some(i));
+    }
     pub fn filter_by_tag_popup_next(&mut self) {
         let all_tags_len = self
             .state
@@ -1067,6 +1580,133 @@ impl App<'_> {
                 self.state.set_focus(Focus::TextInput);
                 self.state.app_status = AppStatus::UserInput;
             }
+            PopUp::GoToBoard | PopUp::MoveSelectedCardsToBoard => {
+                self.state.text_buffers.go_to_board.reset();
+                self.state.app_list_states.go_to_board.select(Some(0));
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::DuplicateCardToBoard => {
+                // Without a search term the results are in board order, so start on the current board
+                let current_board_index = self
+                    .state
+                    .current_board_id
+                    .and_then(|board_id| self.boards.get_board_index(board_id))
+                    .unwrap_or(0);
+                self.state.text_buffers.go_to_board.reset();
+                self.state
+                    .app_list_states
+                    .go_to_board
+                    .select(Some(current_board_index));
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::BulkActions => {
+                self.state.app_list_states.bulk_actions.select(Some(0));
+            }
+            PopUp::UndoHistory => {
+                self.state
+                    .app_list_states
+                    .undo_history
+                    .select(Some(self.action_history_manager.history_index));
+            }
+            PopUp::ExternalSaveChanged => {
+                self.state
+                    .app_list_states
+                    .external_save_change_actions
+                    .select(Some(0));
+            }
+            PopUp::ResolveMergeConflicts => {
+                self.state.app_list_states.merge_conflicts.select(Some(0));
+            }
+            PopUp::BulkEditPrompt => {
+                self.state.text_buffers.bulk_edit.reset();
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::TagManagerActions => {
+                self.state
+                    .app_list_states
+                    .tag_manager_actions
+                    .select(Some(0));
+            }
+            PopUp::TagManagerPrompt => {
+                let prefill = match (self.state.tag_manager_action, &self.state.tag_being_managed) {
+                    (Some(TagManagerAction::Rename), Some(tag)) => tag.clone(),
+                    (Some(TagManagerAction::SetColor), Some(tag)) => self
+                        .config
+                        .tag_colors
+                        .get(&tag.to_lowercase())
+                        .cloned()
+                        .unwrap_or_default(),
+                    _ => String::new(),
+                };
+                self.state.text_buffers.tag_manager =
+                    TextBox::from_string_with_newline_sep(prefill, true);
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::DueDatePrompt => {
+                self.state.text_buffers.due_date.reset();
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::ImportTodoTxtPrompt => {
+                self.state.text_buffers.import_file_path.reset();
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::EncryptionPassphrasePrompt => {
+                self.state.text_buffers.encryption_passphrase.reset();
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::CardTemplatePicker => {
+                let selected_index = if self.card_templates.is_empty() {
+                    None
+                } else {
+                    Some(0)
+                };
+                self.state
+                    .app_list_states
+                    .card_template_picker
+                    .select(selected_index);
+                self.state.app_status = AppStatus::Initialized;
+            }
+            PopUp::BoardTemplatePicker => {
+                let selected_index = if self.board_templates.is_empty() {
+                    None
+                } else {
+                    Some(0)
+                };
+                self.state
+                    .app_list_states
+                    .board_template_picker
+                    .select(selected_index);
+                self.state.app_status = AppStatus::Initialized;
+            }
+            PopUp::SaveBoardTemplatePrompt => {
+                self.state.text_buffers.board_template_name.reset();
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
+            PopUp::SaveCardTemplatePrompt => {
+                let card_name = self
+                    .state
+                    .current_board_id
+                    .zip(self.state.current_card_id)
+                    .and_then(|(board_id, card_id)| {
+                        self.boards
+                            .get_board_with_id(board_id)
+                            .and_then(|board| board.cards.get_card_with_id(card_id))
+                    })
+                    .map(|card| card.name.clone())
+                    .unwrap_or_default();
+                self.state.text_buffers.card_template_name =
+                    TextBox::from_string_with_newline_sep(card_name, true);
+                self.state.set_focus(Focus::TextInput);
+                self.state.app_status = AppStatus::UserInput;
+            }
             PopUp::DateTimePicker => {
                 self.widgets.date_time_picker.open_date_picker();
             }
@@ -1082,6 +1722,43 @@ impl App<'_> {
                 PopUp::CustomHexColorPromptBG | PopUp::CustomHexColorPromptFG => {
                     self.state.app_status = AppStatus::Initialized;
                 }
+                PopUp::GoToBoard
+                | PopUp::MoveSelectedCardsToBoard
+                | PopUp::DuplicateCardToBoard => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.go_to_board.reset();
+                }
+                PopUp::BulkEditPrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.bulk_edit.reset();
+                    self.state.bulk_edit_action = None;
+                }
+                PopUp::TagManagerPrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.tag_manager.reset();
+                    self.state.tag_manager_action = None;
+                }
+                PopUp::DueDatePrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.due_date.reset();
+                    self.state.set_focus(Focus::CardDueDate);
+                }
+                PopUp::ImportTodoTxtPrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.import_file_path.reset();
+                }
+                PopUp::EncryptionPassphrasePrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.encryption_passphrase.reset();
+                }
+                PopUp::SaveCardTemplatePrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.card_template_name.reset();
+                }
+                PopUp::SaveBoardTemplatePrompt => {
+                    self.state.app_status = AppStatus::Initialized;
+                    self.state.text_buffers.board_template_name.reset();
+                }
                 PopUp::ViewCard => {
                     self.state.app_status = AppStatus::Initialized;
                     if self.state.card_being_edited.is_some() {
@@ -1157,6 +1834,35 @@ impl App<'_> {
         }
     }
 
+    /// Boards matching the go to board search text, best fuzzy match first
+    /// Returns (board index, board id, board name)
+    pub fn get_go_to_board_search_results(&self) -> Vec<(usize, (u64, u64), String)> {
+        let search_term = self.state.text_buffers.go_to_board.get_joined_lines();
+        let boards = if self.filtered_boards.is_empty()
+            || matches!(
+                self.state.z_stack.last(),
+                Some(PopUp::MoveSelectedCardsToBoard | PopUp::DuplicateCardToBoard)
+            ) {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        let mut scored_results = boards
+            .get_boards()
+            .iter()
+            .enumerate()
+            .filter_map(|(board_index, board)| {
+                fuzzy_match_score(search_term.trim(), &board.name)
+                    .map(|score| (score, board_index, board.id, board.name.clone()))
+            })
+            .collect::<Vec<(i64, usize, (u64, u64), String)>>();
+        scored_results.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored_results
+            .into_iter()
+            .map(|(_, board_index, board_id, board_name)| (board_index, board_id, board_name))
+            .collect()
+    }
+
     pub fn calculate_tags(&self) -> Vec<(String, u32)> {
         let mut tags: Vec<(String, String)> = vec![];
         for board in self.boards.get_boards() {
@@ -1219,6 +1925,62 @@ impl Display for MainMenuItem {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+pub enum BulkCardAction {
+    MoveToBoard,
+    SetStatus,
+    SetPriority,
+    AddTag,
+    RemoveTag,
+    SetDueDate,
+    SetReminders,
+    Delete,
+}
+
+impl Display for BulkCardAction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            BulkCardAction::MoveToBoard => write!(f, "Move to Board"),
+            BulkCardAction::SetStatus => write!(f, "Set Status"),
+            BulkCardAction::SetPriority => write!(f, "Set Priority"),
+            BulkCardAction::AddTag => write!(f, "Add Tag"),
+            BulkCardAction::RemoveTag => write!(f, "Remove Tag"),
+            BulkCardAction::SetDueDate => write!(f, "Set Due Date"),
+            BulkCardAction::SetReminders => write!(f, "Set Reminders"),
+            BulkCardAction::Delete => write!(f, "Delete"),
+        }
+    }
+}
+
+impl BulkCardAction {
+    pub fn all() -> Vec<BulkCardAction> {
+        BulkCardAction::iter().collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+pub enum TagManagerAction {
+    Rename,
+    SetColor,
+    Delete,
+}
+
+impl Display for TagManagerAction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            TagManagerAction::Rename => write!(f, "Rename or Merge"),
+            TagManagerAction::SetColor => write!(f, "Set Colour"),
+            TagManagerAction::Delete => write!(f, "Delete from all Cards"),
+        }
+    }
+}
+
+impl TagManagerAction {
+    pub fn all() -> Vec<TagManagerAction> {
+        TagManagerAction::iter().collect()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MainMenu {
     pub items: Vec<MainMenuItem>,
@@ -1379,6 +2141,13 @@ impl DateTimeFormat {
             _ => date_format,
         }
     }
+    /// Card dates are only formatted for display, FIELD_NOT_SET when missing
+    pub fn format_timestamp(&self, timestamp: Option<DateTime<Local>>) -> String {
+        match timestamp {
+            Some(timestamp) => timestamp.format(self.to_parser_string()).to_string(),
+            None => FIELD_NOT_SET.to_string(),
+        }
+    }
 }
 
 impl Display for DateTimeFormat {
@@ -1389,23 +2158,39 @@ impl Display for DateTimeFormat {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AppConfig {
+    pub action_history_limit: u16,
     pub always_load_last_save: bool,
     pub auto_login: bool,
+    pub auto_sync_interval: u16,
     pub date_time_format: DateTimeFormat,
+    pub days_to_keep_saves: u16,
     pub default_theme: String,
     pub default_view: View,
     pub disable_animations: bool,
     pub disable_scroll_bar: bool,
     pub enable_mouse_support: bool,
+    pub encrypt_local_saves: bool,
+    pub git_commit_on_sync: bool,
     pub keybindings: KeyBindings,
     pub no_of_boards_to_show: u16,
     pub no_of_cards_to_show: u16,
     pub date_picker_calender_format: CalenderType,
+    pub reminder_command: String,
     pub save_directory: PathBuf,
+    pub save_action_history: bool,
     pub save_on_exit: bool,
+    pub saves_to_keep_per_day: u16,
     pub show_line_numbers: bool,
+    pub sync_backend_anon_key: String,
+    pub sync_backend_type: SyncBackendType,
+    pub sync_backend_url: String,
+    pub sync_directory: PathBuf,
+    pub sync_on_exit: bool,
+    /// lowercase tag to a colour name or hex code
+    pub tag_colors: BTreeMap<String, String>,
     pub tickrate: u16,
     pub warning_delta: u16,
+    pub weekly_saves_to_keep: u16,
 }
 
 impl Default for AppConfig {
@@ -1413,23 +2198,38 @@ impl Default for AppConfig {
         let default_view = DEFAULT_VIEW;
         let default_theme = Theme::default();
         Self {
+            action_history_limit: DEFAULT_ACTION_HISTORY_LIMIT,
             always_load_last_save: true,
             auto_login: true,
+            auto_sync_interval: DEFAULT_AUTO_SYNC_INTERVAL,
             date_time_format: DateTimeFormat::default(),
+            days_to_keep_saves: DEFAULT_DAYS_TO_KEEP_SAVES,
             default_theme: default_theme.name,
             default_view,
             disable_animations: false,
             disable_scroll_bar: false,
             enable_mouse_support: true,
+            encrypt_local_saves: false,
+            git_commit_on_sync: false,
             keybindings: KeyBindings::default(),
             no_of_boards_to_show: DEFAULT_NO_OF_BOARDS_PER_PAGE,
             no_of_cards_to_show: DEFAULT_NO_OF_CARDS_PER_BOARD,
             date_picker_calender_format: CalenderType::default(),
+            reminder_command: String::new(),
             save_directory: get_default_save_directory(),
+            save_action_history: false,
             save_on_exit: true,
+            saves_to_keep_per_day: DEFAULT_SAVES_TO_KEEP_PER_DAY,
             show_line_numbers: true,
+            sync_backend_anon_key: SUPABASE_ANON_KEY.to_string(),
+            sync_backend_type: SyncBackendType::default(),
+            sync_backend_url: SUPABASE_URL.to_string(),
+            sync_directory: PathBuf::new(),
+            sync_on_exit: false,
+            tag_colors: BTreeMap::new(),
             tickrate: DEFAULT_TICKRATE,
             warning_delta: DEFAULT_CARD_WARNING_DUE_DATE_DAYS,
+            weekly_saves_to_keep: DEFAULT_WEEKLY_SAVES_TO_KEEP,
         }
     }
 }
@@ -1460,7 +2260,24 @@ impl AppConfig {
                     }
                     ConfigEnum::DefaultTheme => (self.default_theme.clone(), 14),
                     ConfigEnum::DateFormat => (self.date_time_format.to_string(), 15),
-                    ConfigEnum::Keybindings => ("".to_string(), 16),
+                    ConfigEnum::SaveActionHistory => (self.save_action_history.to_string(), 16),
+                    ConfigEnum::ActionHistoryLimit => (self.action_history_limit.to_string(), 17),
+                    ConfigEnum::EncryptLocalSaves => (self.encrypt_local_saves.to_string(), 18),
+                    ConfigEnum::SyncBackendType => (self.sync_backend_type.to_string(), 19),
+                    ConfigEnum::SyncBackendUrl => (self.sync_backend_url.clone(), 20),
+                    ConfigEnum::SyncBackendAnonKey => (self.sync_backend_anon_key.clone(), 21),
+                    ConfigEnum::SyncDirectory => {
+                        (self.sync_directory.to_string_lossy().to_string(), 22)
+                    }
+                    ConfigEnum::GitCommitOnSync => (self.git_commit_on_sync.to_string(), 23),
+                    ConfigEnum::AutoSyncInterval => (self.auto_sync_interval.to_string(), 24),
+                    ConfigEnum::SyncOnExit => (self.sync_on_exit.to_string(), 25),
+                    ConfigEnum::SavesToKeepPerDay => (self.saves_to_keep_per_day.to_string(), 26),
+                    ConfigEnum::DaysToKeepSaves => (self.days_to_keep_saves.to_string(), 27),
+                    ConfigEnum::WeeklySavesToKeep => (self.weekly_saves_to_keep.to_string(), 28),
+                    ConfigEnum::ReminderCommand => (self.reminder_command.clone(), 29),
+                    ConfigEnum::Keybindings => ("".to_string(), 30),
+                    ConfigEnum::TagColors => (format!("{} coloured", self.tag_colors.len()), 31),
                 };
                 (enum_variant.to_string(), value.to_string(), index)
             })
@@ -1475,27 +2292,42 @@ impl AppConfig {
 
     pub fn get_value_as_string(&self, config_enum: ConfigEnum) -> String {
         match config_enum {
+            ConfigEnum::ActionHistoryLimit => self.action_history_limit.to_string(),
             ConfigEnum::AlwaysLoadLastSave => self.always_load_last_save.to_string(),
             ConfigEnum::AutoLogin => self.auto_login.to_string(),
+            ConfigEnum::AutoSyncInterval => self.auto_sync_interval.to_string(),
             ConfigEnum::DateFormat => self.date_time_format.to_string(),
+            ConfigEnum::DaysToKeepSaves => self.days_to_keep_saves.to_string(),
             ConfigEnum::DefaultTheme => self.default_theme.clone(),
             ConfigEnum::DefaultView => self.default_view.to_string(),
             ConfigEnum::DisableAnimations => self.disable_animations.to_string(),
             ConfigEnum::DisableScrollBar => self.disable_scroll_bar.to_string(),
             ConfigEnum::EnableMouseSupport => self.enable_mouse_support.to_string(),
+            ConfigEnum::EncryptLocalSaves => self.encrypt_local_saves.to_string(),
+            ConfigEnum::GitCommitOnSync => self.git_commit_on_sync.to_string(),
             ConfigEnum::Keybindings => {
                 // This should never be called
                 debug!("Keybindings should not be called from get_value_as_str");
                 "".to_string()
             }
+            ConfigEnum::TagColors => format!("{} coloured", self.tag_colors.len()),
             ConfigEnum::NoOfBoardsToShow => self.no_of_boards_to_show.to_string(),
             ConfigEnum::NoOfCardsToShow => self.no_of_cards_to_show.to_string(),
             ConfigEnum::DatePickerCalenderFormat => self.date_picker_calender_format.to_string(),
+            ConfigEnum::ReminderCommand => self.reminder_command.clone(),
+            ConfigEnum::SaveActionHistory => self.save_action_history.to_string(),
             ConfigEnum::SaveDirectory => self.save_directory.to_string_lossy().to_string(),
             ConfigEnum::SaveOnExit => self.save_on_exit.to_string(),
+            ConfigEnum::SavesToKeepPerDay => self.saves_to_keep_per_day.to_string(),
             ConfigEnum::ShowLineNumbers => self.show_line_numbers.to_string(),
+            ConfigEnum::SyncBackendAnonKey => self.sync_backend_anon_key.clone(),
+            ConfigEnum::SyncBackendType => self.sync_backend_type.to_string(),
+            ConfigEnum::SyncBackendUrl => self.sync_backend_url.clone(),
+            ConfigEnum::SyncDirectory => self.sync_directory.to_string_lossy().to_string(),
+            ConfigEnum::SyncOnExit => self.sync_on_exit.to_string(),
             ConfigEnum::Tickrate => self.tickrate.to_string(),
             ConfigEnum::WarningDelta => self.warning_delta.to_string(),
+            ConfigEnum::WeeklySavesToKeep => self.weekly_saves_to_keep.to_string(),
         }
     }
 
@@ -1506,12 +2338,20 @@ impl AppConfig {
             ConfigEnum::DisableAnimations => (!self.disable_animations).to_string(),
             ConfigEnum::DisableScrollBar => (!self.disable_scroll_bar).to_string(),
             ConfigEnum::EnableMouseSupport => (!self.enable_mouse_support).to_string(),
+            ConfigEnum::EncryptLocalSaves => (!self.encrypt_local_saves).to_string(),
+            ConfigEnum::GitCommitOnSync => (!self.git_commit_on_sync).to_string(),
+            ConfigEnum::SaveActionHistory => (!self.save_action_history).to_string(),
             ConfigEnum::SaveOnExit => (!self.save_on_exit).to_string(),
             ConfigEnum::ShowLineNumbers => (!self.show_line_numbers).to_string(),
+            ConfigEnum::SyncOnExit => (!self.sync_on_exit).to_string(),
             ConfigEnum::DatePickerCalenderFormat => match self.date_picker_calender_format {
                 CalenderType::MondayFirst => CalenderType::SundayFirst.to_string(),
                 CalenderType::SundayFirst => CalenderType::MondayFirst.to_string(),
             },
+            ConfigEnum::SyncBackendType => match self.sync_backend_type {
+                SyncBackendType::Supabase => SyncBackendType::Filesystem.to_string(),
+                SyncBackendType::Filesystem => SyncBackendType::Supabase.to_string(),
+            },
             _ => {
                 debug!("Invalid config enum to toggle: {}", config_enum);
                 "".to_string()
@@ -1519,6 +2359,21 @@ impl AppConfig {
         }
     }
 
+    pub fn get_tag_color(&self, tag: &str) -> Option<Color> {
+        self.tag_colors
+            .get(&tag.to_lowercase())
+            .and_then(|color| Color::from_str(color).ok())
+    }
+
+    /// Sets the colour for a tag, None removes it
+    pub fn set_tag_color(&mut self, tag: &str, color: Option<String>) {
+        if let Some(color) = color {
+            self.tag_colors.insert(tag.to_lowercase(), color);
+        } else {
+            self.tag_colors.remove(&tag.to_lowercase());
+        }
+    }
+
     pub fn edit_config(app: &mut App, config_enum: ConfigEnum, edited_value: &str) {
         let mut config_copy = app.config.clone();
         let result = config_enum.edit_config(&mut config_copy, edited_value);
@@ -1526,6 +2381,19 @@ impl AppConfig {
             let write_status = data_handler::write_config(&config_copy);
             if write_status.is_ok() {
                 app.config = config_copy;
+                if config_enum == ConfigEnum::ActionHistoryLimit {
+                    app.action_history_manager
+                        .set_history_limit(app.config.action_history_limit as usize);
+                }
+                if config_enum == ConfigEnum::SyncBackendType {
+                    app.main_menu.logged_in = app.config.sync_backend_type
+                        == SyncBackendType::Filesystem
+                        || app.state.user_login_data.auth_token.is_some();
+                    app.state.passphrase_derived_encryption_key = None;
+                    app.state.cloud_data = None;
+                    app.state.last_synced_boards = None;
+                    app.state.last_sync_error = None;
+                }
                 send_info_toast(&mut app.widgets.toast_widget, "Config updated");
             } else {
                 send_error_toast(
@@ -1607,9 +2475,18 @@ impl AppConfig {
             KeyBindingEnum::DeleteCard => {
                 self.keybindings.delete_card = value.to_vec();
             }
+            KeyBindingEnum::DuplicateBoard => {
+                self.keybindings.duplicate_board = value.to_vec();
+            }
+            KeyBindingEnum::DuplicateCard => {
+                self.keybindings.duplicate_card = value.to_vec();
+            }
             KeyBindingEnum::Down => {
                 self.keybindings.down = value.to_vec();
             }
+            KeyBindingEnum::GoToBoard => {
+                self.keybindings.go_to_board = value.to_vec();
+            }
             KeyBindingEnum::GoToMainMenu => {
                 self.keybindings.go_to_main_menu = value.to_vec();
             }
@@ -1619,6 +2496,9 @@ impl AppConfig {
             KeyBindingEnum::HideUiElement => {
                 self.keybindings.hide_ui_element = value.to_vec();
             }
+            KeyBindingEnum::JumpToBoard => {
+                self.keybindings.jump_to_board = value.to_vec();
+            }
             KeyBindingEnum::Left => {
                 self.keybindings.left = value.to_vec();
             }
@@ -1646,6 +2526,12 @@ impl AppConfig {
             KeyBindingEnum::OpenConfigMenu => {
                 self.keybindings.open_config_menu = value.to_vec();
             }
+            KeyBindingEnum::OpenBulkActions => {
+                self.keybindings.open_bulk_actions = value.to_vec();
+            }
+            KeyBindingEnum::OpenUndoHistory => {
+                self.keybindings.open_undo_history = value.to_vec();
+            }
             KeyBindingEnum::PrvFocus => {
                 self.keybindings.prv_focus = value.to_vec();
             }
@@ -1664,12 +2550,21 @@ impl AppConfig {
             KeyBindingEnum::SaveState => {
                 self.keybindings.save_state = value.to_vec();
             }
+            KeyBindingEnum::SelectAllCardsInBoard => {
+                self.keybindings.select_all_cards_in_board = value.to_vec();
+            }
+            KeyBindingEnum::SelectCardRange => {
+                self.keybindings.select_card_range = value.to_vec();
+            }
             KeyBindingEnum::StopUserInput => {
                 self.keybindings.stop_user_input = value.to_vec();
             }
             KeyBindingEnum::TakeUserInput => {
                 self.keybindings.take_user_input = value.to_vec();
             }
+            KeyBindingEnum::ToggleCardSelection => {
+                self.keybindings.toggle_card_selection = value.to_vec();
+            }
             KeyBindingEnum::ToggleCommandPalette => {
                 self.keybindings.toggle_command_palette = value.to_vec();
             }
@@ -1746,6 +2641,25 @@ impl AppConfig {
         );
     }
 
+    fn json_config_tag_colors_checker(serde_json_object: &Value) -> BTreeMap<String, String> {
+        let mut tag_colors = BTreeMap::new();
+        if let Some(tag_colors_object) =
+            serde_json_object[ConfigEnum::TagColors.to_json_key()].as_object()
+        {
+            for (tag, color) in tag_colors_object {
+                match color.as_str().map(Color::from_str) {
+                    Some(Ok(parsed_color)) => {
+                        tag_colors.insert(tag.to_lowercase(), parsed_color.to_string());
+                    }
+                    _ => {
+                        error!("Invalid colour for tag: {}, Ignoring it", tag);
+                    }
+                }
+            }
+        }
+        tag_colors
+    }
+
     fn json_config_keybindings_checker(serde_json_object: &Value) -> KeyBindings {
         if let Some(keybindings) = serde_json_object["keybindings"].as_object() {
             let mut default_keybindings = KeyBindings::default();
@@ -1830,6 +2744,7 @@ impl AppConfig {
             }
         };
         let keybindings = AppConfig::json_config_keybindings_checker(&serde_json_object);
+        let tag_colors = AppConfig::json_config_tag_colors_checker(&serde_json_object);
         let always_load_last_save = AppConfig::get_bool_or_default(
             &serde_json_object,
             ConfigEnum::AlwaysLoadLastSave,
@@ -1879,6 +2794,34 @@ impl AppConfig {
             Some(MIN_TICKRATE),
             Some(MAX_TICKRATE),
         );
+        let saves_to_keep_per_day = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::SavesToKeepPerDay,
+            default_config.saves_to_keep_per_day,
+            None,
+            Some(MAX_SAVES_TO_KEEP_PER_DAY),
+        );
+        let days_to_keep_saves = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::DaysToKeepSaves,
+            default_config.days_to_keep_saves,
+            None,
+            Some(MAX_DAYS_TO_KEEP_SAVES),
+        );
+        let weekly_saves_to_keep = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::WeeklySavesToKeep,
+            default_config.weekly_saves_to_keep,
+            None,
+            Some(MAX_WEEKLY_SAVES_TO_KEEP),
+        );
+        let auto_sync_interval = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::AutoSyncInterval,
+            default_config.auto_sync_interval,
+            None,
+            Some(MAX_AUTO_SYNC_INTERVAL),
+        );
         let no_of_cards_to_show = AppConfig::get_u16_or_default(
             &serde_json_object,
             ConfigEnum::NoOfCardsToShow,
@@ -1893,6 +2836,23 @@ impl AppConfig {
             Some(MIN_NO_BOARDS_PER_PAGE),
             Some(MAX_NO_BOARDS_PER_PAGE),
         );
+        let save_action_history = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::SaveActionHistory,
+            default_config.save_action_history,
+        );
+        let encrypt_local_saves = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::EncryptLocalSaves,
+            default_config.encrypt_local_saves,
+        );
+        let action_history_limit = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::ActionHistoryLimit,
+            default_config.action_history_limit,
+            Some(MIN_ACTION_HISTORY_LIMIT),
+            Some(MAX_ACTION_HISTORY_LIMIT),
+        );
         let default_theme = match serde_json_object[ConfigEnum::DefaultTheme.to_json_key()].as_str()
         {
             Some(default_theme) => default_theme.to_string(),
@@ -1901,6 +2861,79 @@ impl AppConfig {
                 default_config.default_theme
             }
         };
+        let sync_backend_url =
+            match serde_json_object[ConfigEnum::SyncBackendUrl.to_json_key()].as_str() {
+                Some(url) if ConfigEnum::SyncBackendUrl.validate_value(url).is_ok() => {
+                    url.trim_end_matches('/').to_string()
+                }
+                _ => {
+                    error!("Invalid Sync Backend URL, Resetting to default sync backend");
+                    default_config.sync_backend_url
+                }
+            };
+        let git_commit_on_sync = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::GitCommitOnSync,
+            default_config.git_commit_on_sync,
+        );
+        let sync_on_exit = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::SyncOnExit,
+            default_config.sync_on_exit,
+        );
+        let sync_backend_type =
+            match serde_json_object[ConfigEnum::SyncBackendType.to_json_key()].as_str() {
+                Some(backend_type) => match SyncBackendType::from_str(backend_type) {
+                    Ok(backend_type) => backend_type,
+                    Err(backend_type_parse_error) => {
+                        error!(
+                            "Invalid sync backend: {}, Resetting to default sync backend",
+                            backend_type
+                        );
+                        debug!("Error: {}", backend_type_parse_error);
+                        default_config.sync_backend_type
+                    }
+                },
+                None => {
+                    error!("Sync Backend is not a string, Resetting to default sync backend");
+                    default_config.sync_backend_type
+                }
+            };
+        let sync_directory =
+            match serde_json_object[ConfigEnum::SyncDirectory.to_json_key()].as_str() {
+                Some(path) => {
+                    let path = PathBuf::from(path);
+                    if path.as_os_str().is_empty() || path.is_dir() {
+                        path
+                    } else {
+                        error!(
+                            "Invalid path: {}, Resetting sync directory",
+                            path.to_string_lossy()
+                        );
+                        default_config.sync_directory
+                    }
+                }
+                None => {
+                    error!("Sync Directory is not a string, Resetting sync directory");
+                    default_config.sync_directory
+                }
+            };
+        let reminder_command =
+            match serde_json_object[ConfigEnum::ReminderCommand.to_json_key()].as_str() {
+                Some(command) if split_command(command).is_ok() => command.trim().to_string(),
+                _ => {
+                    error!("Invalid Reminder Command, Resetting to no reminder command");
+                    default_config.reminder_command
+                }
+            };
+        let sync_backend_anon_key =
+            match serde_json_object[ConfigEnum::SyncBackendAnonKey.to_json_key()].as_str() {
+                Some(anon_key) if !anon_key.trim().is_empty() => anon_key.trim().to_string(),
+                _ => {
+                    error!("Invalid Sync Backend Anon Key, Resetting to default anon key");
+                    default_config.sync_backend_anon_key
+                }
+            };
         let date_format = match serde_json_object[ConfigEnum::DateFormat.to_json_key()].as_str() {
             Some(date_format) => match DateTimeFormat::from_str(date_format) {
                 Ok(date_format) => date_format,
@@ -1954,51 +2987,96 @@ impl AppConfig {
             date_time_format: date_format,
             show_line_numbers,
             disable_animations,
+            save_action_history,
+            action_history_limit,
+            encrypt_local_saves,
+            sync_backend_type,
+            sync_backend_url,
+            sync_backend_anon_key,
+            sync_directory,
+            git_commit_on_sync,
+            auto_sync_interval,
+            sync_on_exit,
+            tag_colors,
+            saves_to_keep_per_day,
+            days_to_keep_saves,
+            weekly_saves_to_keep,
+            reminder_command,
         })
     }
 }
 
 #[derive(PartialEq, Copy, Clone, EnumIter)]
 pub enum ConfigEnum {
+    ActionHistoryLimit,
     AlwaysLoadLastSave,
     AutoLogin,
+    AutoSyncInterval,
     DateFormat,
+    DaysToKeepSaves,
     DefaultTheme,
     DefaultView,
     DisableAnimations,
     DisableScrollBar,
     EnableMouseSupport,
+    EncryptLocalSaves,
+    GitCommitOnSync,
     Keybindings,
     NoOfBoardsToShow,
     NoOfCardsToShow,
     DatePickerCalenderFormat,
+    ReminderCommand,
+    SaveActionHistory,
     SaveDirectory,
     SaveOnExit,
+    SavesToKeepPerDay,
     ShowLineNumbers,
+    SyncBackendAnonKey,
+    SyncBackendType,
+    SyncBackendUrl,
+    SyncDirectory,
+    SyncOnExit,
+    TagColors,
     Tickrate,
     WarningDelta,
+    WeeklySavesToKeep,
 }
 
 impl fmt::Display for ConfigEnum {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
+            ConfigEnum::ActionHistoryLimit => write!(f, "Number of Actions to Keep in History"),
             ConfigEnum::AlwaysLoadLastSave => write!(f, "Auto Load Last Save"),
             ConfigEnum::AutoLogin => write!(f, "Auto Login"),
+            ConfigEnum::AutoSyncInterval => write!(f, "Auto Sync Interval in Minutes"),
             ConfigEnum::DateFormat => write!(f, "Date Format"),
+            ConfigEnum::DaysToKeepSaves => write!(f, "Number of Days to Keep Saves"),
             ConfigEnum::DefaultTheme => write!(f, "Default Theme"),
             ConfigEnum::DefaultView => write!(f, "Select Default View"),
             ConfigEnum::DisableAnimations => write!(f, "Disable Animations"),
             ConfigEnum::DisableScrollBar => write!(f, "Disable Scroll Bar"),
             ConfigEnum::EnableMouseSupport => write!(f, "Enable Mouse Support"),
+            ConfigEnum::EncryptLocalSaves => write!(f, "Encrypt Local Saves"),
+            ConfigEnum::GitCommitOnSync => write!(f, "Git Commit on Sync"),
             ConfigEnum::Keybindings => write!(f, "Edit Keybindings"),
             ConfigEnum::NoOfBoardsToShow => write!(f, "Number of Boards to Show"),
             ConfigEnum::NoOfCardsToShow => write!(f, "Number of Cards to Show"),
             ConfigEnum::DatePickerCalenderFormat => write!(f, "Date Picker Calender Format"),
+            ConfigEnum::ReminderCommand => write!(f, "Reminder Command"),
+            ConfigEnum::SaveActionHistory => write!(f, "Save Undo History"),
             ConfigEnum::SaveDirectory => write!(f, "Save Directory"),
             ConfigEnum::SaveOnExit => write!(f, "Auto Save on Exit"),
+            ConfigEnum::SavesToKeepPerDay => write!(f, "Number of Saves to Keep per Day"),
             ConfigEnum::ShowLineNumbers => write!(f, "Show Line Numbers"),
+            ConfigEnum::SyncBackendAnonKey => write!(f, "Sync Backend Anon Key"),
+            ConfigEnum::SyncBackendType => write!(f, "Sync Backend"),
+            ConfigEnum::SyncBackendUrl => write!(f, "Sync Backend URL"),
+            ConfigEnum::SyncDirectory => write!(f, "Sync Directory"),
+            ConfigEnum::SyncOnExit => write!(f, "Auto Sync on Exit"),
+            ConfigEnum::TagColors => write!(f, "Manage Tags"),
             ConfigEnum::Tickrate => write!(f, "Tickrate"),
             ConfigEnum::WarningDelta => write!(f, "Number of Days to Warn Before Due Date"),
+            ConfigEnum::WeeklySavesToKeep => write!(f, "Number of Weekly Saves to Keep"),
         }
     }
 }
@@ -2010,19 +3088,34 @@ impl FromStr for ConfigEnum {
             "Auto Load Last Save" => Ok(ConfigEnum::AlwaysLoadLastSave),
             "Auto Login" => Ok(ConfigEnum::AutoLogin),
             "Auto Save on Exit" => Ok(ConfigEnum::SaveOnExit),
+            "Auto Sync Interval in Minutes" => Ok(ConfigEnum::AutoSyncInterval),
+            "Auto Sync on Exit" => Ok(ConfigEnum::SyncOnExit),
             "Date Format" => Ok(ConfigEnum::DateFormat),
             "Default Theme" => Ok(ConfigEnum::DefaultTheme),
             "Disable Animations" => Ok(ConfigEnum::DisableAnimations),
             "Disable Scroll Bar" => Ok(ConfigEnum::DisableScrollBar),
             "Edit Keybindings" => Ok(ConfigEnum::Keybindings),
             "Enable Mouse Support" => Ok(ConfigEnum::EnableMouseSupport),
+            "Encrypt Local Saves" => Ok(ConfigEnum::EncryptLocalSaves),
+            "Git Commit on Sync" => Ok(ConfigEnum::GitCommitOnSync),
             "Number of Boards to Show" => Ok(ConfigEnum::NoOfBoardsToShow),
             "Number of Cards to Show" => Ok(ConfigEnum::NoOfCardsToShow),
             "Date Picker Calender Format" => Ok(ConfigEnum::DatePickerCalenderFormat),
             "Number of Days to Warn Before Due Date" => Ok(ConfigEnum::WarningDelta),
+            "Number of Actions to Keep in History" => Ok(ConfigEnum::ActionHistoryLimit),
+            "Number of Days to Keep Saves" => Ok(ConfigEnum::DaysToKeepSaves),
+            "Number of Saves to Keep per Day" => Ok(ConfigEnum::SavesToKeepPerDay),
+            "Number of Weekly Saves to Keep" => Ok(ConfigEnum::WeeklySavesToKeep),
+            "Reminder Command" => Ok(ConfigEnum::ReminderCommand),
+            "Save Undo History" => Ok(ConfigEnum::SaveActionHistory),
             "Save Directory" => Ok(ConfigEnum::SaveDirectory),
             "Select Default View" => Ok(ConfigEnum::DefaultView),
             "Show Line Numbers" => Ok(ConfigEnum::ShowLineNumbers),
+            "Sync Backend Anon Key" => Ok(ConfigEnum::SyncBackendAnonKey),
+            "Sync Backend" => Ok(ConfigEnum::SyncBackendType),
+            "Sync Backend URL" => Ok(ConfigEnum::SyncBackendUrl),
+            "Sync Directory" => Ok(ConfigEnum::SyncDirectory),
+            "Manage Tags" => Ok(ConfigEnum::TagColors),
             "Tickrate" => Ok(ConfigEnum::Tickrate),
             _ => Err(format!("Invalid ConfigEnum: {}", s)),
         }
@@ -2032,29 +3125,44 @@ impl FromStr for ConfigEnum {
 impl ConfigEnum {
     pub fn to_json_key(&self) -> &str {
         match self {
+            ConfigEnum::ActionHistoryLimit => "action_history_limit",
             ConfigEnum::AlwaysLoadLastSave => "always_load_last_save",
             ConfigEnum::AutoLogin => "auto_login",
+            ConfigEnum::AutoSyncInterval => "auto_sync_interval",
             ConfigEnum::DateFormat => "date_format",
+            ConfigEnum::DaysToKeepSaves => "days_to_keep_saves",
             ConfigEnum::DefaultTheme => "default_theme",
             ConfigEnum::DefaultView => "default_view",
             ConfigEnum::DisableAnimations => "disable_animations",
             ConfigEnum::DisableScrollBar => "disable_scroll_bar",
             ConfigEnum::EnableMouseSupport => "enable_mouse_support",
+            ConfigEnum::EncryptLocalSaves => "encrypt_local_saves",
+            ConfigEnum::GitCommitOnSync => "git_commit_on_sync",
             ConfigEnum::Keybindings => "keybindings",
             ConfigEnum::NoOfBoardsToShow => "no_of_boards_to_show",
             ConfigEnum::NoOfCardsToShow => "no_of_cards_to_show",
             ConfigEnum::DatePickerCalenderFormat => "date_picker_calender_format",
+            ConfigEnum::ReminderCommand => "reminder_command",
+            ConfigEnum::SaveActionHistory => "save_action_history",
             ConfigEnum::SaveDirectory => "save_directory",
             ConfigEnum::SaveOnExit => "save_on_exit",
+            ConfigEnum::SavesToKeepPerDay => "saves_to_keep_per_day",
             ConfigEnum::ShowLineNumbers => "show_line_numbers",
+            ConfigEnum::SyncBackendAnonKey => "sync_backend_anon_key",
+            ConfigEnum::SyncBackendType => "sync_backend_type",
+            ConfigEnum::SyncBackendUrl => "sync_backend_url",
+            ConfigEnum::SyncDirectory => "sync_directory",
+            ConfigEnum::SyncOnExit => "sync_on_exit",
+            ConfigEnum::TagColors => "tag_colors",
             ConfigEnum::Tickrate => "tickrate",
             ConfigEnum::WarningDelta => "warning_delta",
+            ConfigEnum::WeeklySavesToKeep => "weekly_saves_to_keep",
         }
     }
 
     pub fn validate_value(&self, value: &str) -> Result<(), String> {
         match self {
-            ConfigEnum::SaveDirectory => {
+            ConfigEnum::SaveDirectory | ConfigEnum::SyncDirectory => {
                 let path = PathBuf::from(value);
                 if path.try_exists().is_ok() && path.try_exists().unwrap() && path.is_dir() {
                     Ok(())
@@ -2075,8 +3183,12 @@ impl ConfigEnum {
             | ConfigEnum::DisableAnimations
             | ConfigEnum::DisableScrollBar
             | ConfigEnum::EnableMouseSupport
+            | ConfigEnum::EncryptLocalSaves
+            | ConfigEnum::GitCommitOnSync
+            | ConfigEnum::SaveActionHistory
             | ConfigEnum::SaveOnExit
-            | ConfigEnum::ShowLineNumbers => {
+            | ConfigEnum::ShowLineNumbers
+            | ConfigEnum::SyncOnExit => {
                 let check = value.parse::<bool>();
                 if check.is_ok() {
                     Ok(())
@@ -2084,11 +3196,43 @@ impl ConfigEnum {
                     Err(format!("Invalid boolean: {}", value))
                 }
             }
-            ConfigEnum::NoOfBoardsToShow
+            ConfigEnum::SyncBackendType => {
+                let backend_type = SyncBackendType::from_str(value);
+                if backend_type.is_ok() {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid Sync Backend: {}", value))
+                }
+            }
+            ConfigEnum::SyncBackendUrl => {
+                if value.starts_with("http://") || value.starts_with("https://") {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "Invalid URL: {}, It must start with http:// or https://",
+                        value
+                    ))
+                }
+            }
+            ConfigEnum::SyncBackendAnonKey => {
+                if value.is_empty() {
+                    Err("Anon key cannot be empty".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+            ConfigEnum::ReminderCommand => split_command(value).map(|_| ()),
+            ConfigEnum::ActionHistoryLimit
+            | ConfigEnum::AutoSyncInterval
+            | ConfigEnum::DaysToKeepSaves
+            | ConfigEnum::NoOfBoardsToShow
             | ConfigEnum::NoOfCardsToShow
+            | ConfigEnum::SavesToKeepPerDay
             | ConfigEnum::Tickrate
-            | ConfigEnum::WarningDelta => {
+            | ConfigEnum::WarningDelta
+            | ConfigEnum::WeeklySavesToKeep => {
                 let min_value = match self {
+                    ConfigEnum::ActionHistoryLimit => MIN_ACTION_HISTORY_LIMIT,
                     ConfigEnum::WarningDelta => MIN_WARNING_DUE_DATE_DAYS,
                     ConfigEnum::Tickrate => MIN_TICKRATE,
                     ConfigEnum::NoOfCardsToShow => MIN_NO_CARDS_PER_BOARD,
@@ -2096,6 +3240,11 @@ impl ConfigEnum {
                     _ => 0,
                 };
                 let max_value = match self {
+                    ConfigEnum::ActionHistoryLimit => MAX_ACTION_HISTORY_LIMIT,
+                    ConfigEnum::AutoSyncInterval => MAX_AUTO_SYNC_INTERVAL,
+                    ConfigEnum::DaysToKeepSaves => MAX_DAYS_TO_KEEP_SAVES,
+                    ConfigEnum::SavesToKeepPerDay => MAX_SAVES_TO_KEEP_PER_DAY,
+                    ConfigEnum::WeeklySavesToKeep => MAX_WEEKLY_SAVES_TO_KEEP,
                     ConfigEnum::WarningDelta => MAX_WARNING_DUE_DATE_DAYS,
                     ConfigEnum::Tickrate => MAX_TICKRATE,
                     ConfigEnum::NoOfCardsToShow => MAX_NO_CARDS_PER_BOARD,
@@ -2142,6 +3291,11 @@ impl ConfigEnum {
                 // Keybindings are handled separately
                 Ok(())
             }
+            ConfigEnum::TagColors => {
+                debug!("TagColors should not be called from validate_value");
+                // Tag colours are edited from the tag manager
+                Ok(())
+            }
         }
     }
 
@@ -2162,6 +3316,12 @@ impl ConfigEnum {
             ConfigEnum::SaveOnExit => {
                 config.save_on_exit = value.parse::<bool>().unwrap();
             }
+            ConfigEnum::SaveActionHistory => {
+                config.save_action_history = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::ActionHistoryLimit => {
+                config.action_history_limit = value.parse::<u16>().unwrap();
+            }
             ConfigEnum::DisableScrollBar => {
                 config.disable_scroll_bar = value.parse::<bool>().unwrap();
             }
@@ -2171,12 +3331,48 @@ impl ConfigEnum {
             ConfigEnum::ShowLineNumbers => {
                 config.show_line_numbers = value.parse::<bool>().unwrap();
             }
+            ConfigEnum::SyncBackendAnonKey => {
+                config.sync_backend_anon_key = value.to_string();
+            }
+            ConfigEnum::ReminderCommand => {
+                config.reminder_command = value.to_string();
+            }
+            ConfigEnum::SyncBackendType => {
+                config.sync_backend_type = SyncBackendType::from_str(value).unwrap();
+            }
+            ConfigEnum::SyncBackendUrl => {
+                config.sync_backend_url = value.trim_end_matches('/').to_string();
+            }
+            ConfigEnum::SyncDirectory => {
+                config.sync_directory = PathBuf::from(value);
+            }
+            ConfigEnum::SyncOnExit => {
+                config.sync_on_exit = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::AutoSyncInterval => {
+                config.auto_sync_interval = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::SavesToKeepPerDay => {
+                config.saves_to_keep_per_day = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::DaysToKeepSaves => {
+                config.days_to_keep_saves = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::WeeklySavesToKeep => {
+                config.weekly_saves_to_keep = value.parse::<u16>().unwrap();
+            }
             ConfigEnum::DisableAnimations => {
                 config.disable_animations = value.parse::<bool>().unwrap();
             }
             ConfigEnum::EnableMouseSupport => {
                 config.enable_mouse_support = value.parse::<bool>().unwrap();
             }
+            ConfigEnum::EncryptLocalSaves => {
+                config.encrypt_local_saves = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::GitCommitOnSync => {
+                config.git_commit_on_sync = value.parse::<bool>().unwrap();
+            }
             ConfigEnum::WarningDelta => {
                 config.warning_delta = value.parse::<u16>().unwrap();
             }
@@ -2203,14 +3399,29 @@ impl ConfigEnum {
                 debug!("Keybindings should not be called from edit_config");
                 // Keybindings are handled separately
             }
+            ConfigEnum::TagColors => {
+                debug!("TagColors should not be called from edit_config");
+                // Tag colours are edited from the tag manager
+            }
         }
         Ok(())
     }
 }
 
 pub async fn handle_exit(app: &mut App<'_>) -> AppReturn {
-    if app.config.save_on_exit {
+    // Asked before exiting as there is no one to answer the popup afterwards
+    if app.config.save_on_exit && !app.state.read_only && detect_external_save_change(app) {
+        return AppReturn::Continue;
+    }
+    if app.config.save_on_exit && !app.state.read_only {
         app.dispatch(IoEvent::AutoSave).await;
     }
+    if app.config.sync_on_exit
+        && app.main_menu.logged_in
+        && app.state.pending_merge.is_none()
+        && app.get_sync_status() != SyncStatus::Synced
+    {
+        app.dispatch(IoEvent::SyncLocalData).await;
+    }
     AppReturn::Exit
 }
@@ -0,0 +1,351 @@
+use crate::{
+    app::DateTimeFormat, ui::widgets::date_time_picker::CalenderType, util::parse_date_time,
+};
+use chrono::{
+    DateTime, Datelike, Duration, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Weekday,
+};
+
+const MINUTES_IN_HOUR: u64 = 60;
+const MINUTES_IN_DAY: u64 = 24 * MINUTES_IN_HOUR;
+const MINUTES_IN_WEEK: u64 = 7 * MINUTES_IN_DAY;
+
+/// Units shared by due dates and reminders. A bare "m" is refused as it could mean
+/// minutes or months, "min" and "mo" are used instead
+pub enum OffsetUnit {
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl OffsetUnit {
+    pub fn parse(unit: &str) -> Option<Self> {
+        match unit {
+            "min" | "mins" | "minute" | "minutes" => Some(OffsetUnit::Minutes),
+            "h" | "hr" | "hrs" | "hour" | "hours" => Some(OffsetUnit::Hours),
+            "d" | "day" | "days" => Some(OffsetUnit::Days),
+            "w" | "wk" | "wks" | "week" | "weeks" => Some(OffsetUnit::Weeks),
+            "mo" | "mos" | "month" | "months" => Some(OffsetUnit::Months),
+            "y" | "yr" | "yrs" | "year" | "years" => Some(OffsetUnit::Years),
+            _ => None,
+        }
+    }
+
+    /// Length of the unit in minutes, None for months and years as their length varies
+    pub fn minutes(&self) -> Option<u64> {
+        match self {
+            OffsetUnit::Minutes => Some(1),
+            OffsetUnit::Hours => Some(MINUTES_IN_HOUR),
+            OffsetUnit::Days => Some(MINUTES_IN_DAY),
+            OffsetUnit::Weeks => Some(MINUTES_IN_WEEK),
+            OffsetUnit::Months | OffsetUnit::Years => None,
+        }
+    }
+}
+
+/// Parses a due date typed by the user, either in one of the known date formats or as a
+/// relative expression like "tomorrow 5pm", "next fri", "+3d" or "end of month"
+pub fn parse_due_date(
+    input: &str,
+    preferred_format: DateTimeFormat,
+    calender_type: &CalenderType,
+) -> Result<DateTime<Local>, String> {
+    parse_date_time(input, preferred_format).or_else(|_| {
+        parse_natural_date(input, Local::now(), calender_type)
+            .ok_or_else(|| format!("Could not understand date '{}'", input.trim()))
+    })
+}
+
+/// Resolves a relative date expression against `now`. Expressions that only name a day resolve
+/// to midnight unless a time like "5pm", "17:30" or "noon" is given after them
+pub fn parse_natural_date(
+    input: &str,
+    now: DateTime<Local>,
+    calender_type: &CalenderType,
+) -> Option<DateTime<Local>> {
+    let input = input.trim().to_lowercase();
+    let mut words: Vec<&str> = input.split_whitespace().collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let time = take_trailing_time(&mut words);
+    if words.last() == Some(&"at") {
+        words.pop();
+    }
+    let today = now.date_naive();
+    let week_start = match calender_type {
+        CalenderType::SundayFirst => Weekday::Sun,
+        CalenderType::MondayFirst => Weekday::Mon,
+    };
+
+    let resolved = match words.as_slice() {
+        [] => time.map(|time| today.and_time(time)),
+        ["now"] => Some(now.naive_local()),
+        ["today"] => Some(today.and_time(NaiveTime::MIN)),
+        ["tomorrow" | "tmrw" | "tmr"] => today.succ_opt().map(|date| date.and_time(NaiveTime::MIN)),
+        ["yesterday"] => today.pred_opt().map(|date| date.and_time(NaiveTime::MIN)),
+        ["end", "of", period] => end_of_period(today, period, week_start),
+        ["next", period] if parse_weekday(period).is_none() => {
+            start_of_next_period(today, period, week_start)
+        }
+        ["next", weekday] => parse_weekday(weekday).map(|weekday| {
+            let next_week_start = start_of_week(today, week_start) + Duration::days(7);
+            (next_week_start + Duration::days(days_from(week_start, weekday)))
+                .and_time(NaiveTime::MIN)
+        }),
+        ["this" | "on", weekday] | [weekday] if parse_weekday(weekday).is_some() => {
+            parse_weekday(weekday).map(|weekday| {
+                (today + Duration::days(days_from(today.weekday(), weekday)))
+                    .and_time(NaiveTime::MIN)
+            })
+        }
+        ["in", amount, unit] => apply_offset(now, amount, unit, 1),
+        ["in", amount_and_unit] => split_amount_and_unit(amount_and_unit)
+            .and_then(|(amount, unit)| apply_offset(now, amount, unit, 1)),
+        [signed_amount, unit] => split_sign(signed_amount)
+            .and_then(|(sign, amount)| apply_offset(now, amount, unit, sign)),
+        [signed_amount_and_unit] => split_sign(signed_amount_and_unit).and_then(|(sign, rest)| {
+            split_amount_and_unit(rest)
+                .and_then(|(amount, unit)| apply_offset(now, amount, unit, sign))
+        }),
+        _ => None,
+    }?;
+
+    let resolved = match time {
+        Some(time) => resolved.date().and_time(time),
+        None => resolved,
+    };
+    Local.from_local_datetime(&resolved).earliest()
+}
+
+fn take_trailing_time(words: &mut Vec<&str>) -> Option<NaiveTime> {
+    let last = *words.last()?;
+    if let Some(time) = parse_time(last) {
+        words.pop();
+        return Some(time);
+    }
+    if words.len() >= 2 && (last == "am" || last == "pm") {
+        let time = parse_time(&format!("{}{}", words[words.len() - 2], last))?;
+        words.truncate(words.len() - 2);
+        return Some(time);
+    }
+    None
+}
+
+fn parse_time(word: &str) -> Option<NaiveTime> {
+    match word {
+        "noon" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return Some(NaiveTime::MIN),
+        _ => {}
+    }
+    let (clock, is_pm) = if let Some(clock) = word.strip_suffix("am") {
+        (clock, Some(false))
+    } else if let Some(clock) = word.strip_suffix("pm") {
+        (clock, Some(true))
+    } else {
+        (word, None)
+    };
+    match is_pm {
+        Some(is_pm) => {
+            let (hour, minute) = match clock.split_once(':') {
+                Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+                None => (clock.parse::<u32>().ok()?, 0),
+            };
+            if !(1..=12).contains(&hour) {
+                return None;
+            }
+            let hour = match (hour, is_pm) {
+                (12, false) => 0,
+                (12, true) => 12,
+                (hour, true) => hour + 12,
+                (hour, false) => hour,
+            };
+            NaiveTime::from_hms_opt(hour, minute, 0)
+        }
+        None => NaiveTime::parse_from_str(clock, "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(clock, "%H:%M:%S"))
+            .ok(),
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Number of days to go forward from `from` to reach `to`, zero if they are the same day
+fn days_from(from: Weekday, to: Weekday) -> i64 {
+    (7 + to.num_days_from_monday() as i64 - from.num_days_from_monday() as i64) % 7
+}
+
+fn start_of_week(date: NaiveDate, week_start: Weekday) -> NaiveDate {
+    date - Duration::days(days_from(week_start, date.weekday()))
+}
+
+fn end_of_period(today: NaiveDate, period: &str, week_start: Weekday) -> Option<NaiveDateTime> {
+    let last_day = match period {
+        "day" | "today" => Some(today),
+        "week" => Some(start_of_week(today, week_start) + Duration::days(6)),
+        "month" => NaiveDate::from_ymd_opt(today.year(), today.month(), 1)
+            .and_then(|first_day| first_day.checked_add_months(Months::new(1)))
+            .and_then(|first_day_of_next_month| first_day_of_next_month.pred_opt()),
+        "year" => NaiveDate::from_ymd_opt(today.year(), 12, 31),
+        _ => None,
+    }?;
+    last_day.and_hms_opt(23, 59, 59)
+}
+
+fn start_of_next_period(
+    today: NaiveDate,
+    period: &str,
+    week_start: Weekday,
+) -> Option<NaiveDateTime> {
+    let first_day = match period {
+        "week" => Some(start_of_week(today, week_start) + Duration::days(7)),
+        "month" => NaiveDate::from_ymd_opt(today.year(), today.month(), 1)
+            .and_then(|first_day| first_day.checked_add_months(Months::new(1))),
+        "year" => NaiveDate::from_ymd_opt(today.year() + 1, 1, 1),
+        _ => None,
+    }?;
+    Some(first_day.and_time(NaiveTime::MIN))
+}
+
+fn split_sign(word: &str) -> Option<(i64, &str)> {
+    if let Some(rest) = word.strip_prefix('+') {
+        Some((1, rest))
+    } else {
+        word.strip_prefix('-').map(|rest| (-1, rest))
+    }
+}
+
+fn split_amount_and_unit(word: &str) -> Option<(&str, &str)> {
+    let unit_start = word.find(|c: char| !c.is_ascii_digit())?;
+    Some(word.split_at(unit_start))
+}
+
+/// Day based offsets resolve to midnight, hour and minute offsets keep the current time
+fn apply_offset(
+    now: DateTime<Local>,
+    amount: &str,
+    unit: &str,
+    sign: i64,
+) -> Option<NaiveDateTime> {
+    let amount = amount.parse::<u32>().ok()?;
+    let now = now.naive_local();
+    let today = now.date();
+    let signed_amount = sign * amount as i64;
+    let add_months = |months: u32| {
+        if sign < 0 {
+            today.checked_sub_months(Months::new(months))
+        } else {
+            today.checked_add_months(Months::new(months))
+        }
+    };
+    let resolved = match OffsetUnit::parse(unit)? {
+        OffsetUnit::Minutes => return now.checked_add_signed(Duration::minutes(signed_amount)),
+        OffsetUnit::Hours => return now.checked_add_signed(Duration::hours(signed_amount)),
+        OffsetUnit::Days => today.checked_add_signed(Duration::days(signed_amount)),
+        OffsetUnit::Weeks => today.checked_add_signed(Duration::weeks(signed_amount)),
+        OffsetUnit::Months => add_months(amount),
+        OffsetUnit::Years => add_months(amount.checked_mul(12)?),
+    }?;
+    Some(resolved.and_time(NaiveTime::MIN))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Wednesday
+    fn now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 5, 15, 10, 0, 0).unwrap()
+    }
+
+    fn parse(input: &str) -> Option<DateTime<Local>> {
+        parse_natural_date(input, now(), &CalenderType::MondayFirst)
+    }
+
+    fn at(month: u32, day: u32, hour: u32, minute: u32) -> Option<DateTime<Local>> {
+        Local
+            .with_ymd_and_hms(2024, month, day, hour, minute, 0)
+            .single()
+    }
+
+    #[test]
+    fn relative_offsets() {
+        assert_eq!(parse("+3d"), at(5, 18, 0, 0));
+        assert_eq!(parse("in 2 weeks"), at(5, 29, 0, 0));
+        assert_eq!(parse("+1mo"), at(6, 15, 0, 0));
+        assert_eq!(parse("in 2 months"), at(7, 15, 0, 0));
+        assert_eq!(parse("-2h"), at(5, 15, 8, 0));
+        assert_eq!(parse("in 30min"), at(5, 15, 10, 30));
+        assert_eq!(
+            parse("+1y"),
+            Local.with_ymd_and_hms(2025, 5, 15, 0, 0, 0).single()
+        );
+    }
+
+    #[test]
+    fn weekday_names() {
+        assert_eq!(parse("fri"), at(5, 17, 0, 0));
+        assert_eq!(parse("wednesday"), at(5, 15, 0, 0));
+        assert_eq!(parse("this mon"), at(5, 20, 0, 0));
+        assert_eq!(parse("next fri"), at(5, 24, 0, 0));
+        assert_eq!(parse("on tue at 9am"), at(5, 21, 9, 0));
+    }
+
+    #[test]
+    fn named_days_periods_and_times() {
+        assert_eq!(parse("tomorrow 5pm"), at(5, 16, 17, 0));
+        assert_eq!(parse("today noon"), at(5, 15, 12, 0));
+        assert_eq!(parse("17:30"), at(5, 15, 17, 30));
+        assert_eq!(
+            parse("end of month"),
+            Local.with_ymd_and_hms(2024, 5, 31, 23, 59, 59).single()
+        );
+        assert_eq!(parse("next week"), at(5, 20, 0, 0));
+        assert_eq!(
+            parse_natural_date("next week", now(), &CalenderType::SundayFirst),
+            at(5, 19, 0, 0)
+        );
+    }
+
+    #[test]
+    fn invalid_input_is_refused() {
+        for input in [
+            "",
+            "blah",
+            "+3m",
+            "in 3m",
+            "in x days",
+            "+3",
+            "next blah",
+            "25:00",
+            "13pm",
+        ] {
+            assert_eq!(parse(input), None, "'{}' should not parse", input);
+        }
+    }
+
+    #[test]
+    fn offset_units_do_not_overlap() {
+        assert!(OffsetUnit::parse("m").is_none());
+        assert_eq!(
+            OffsetUnit::parse("min").and_then(|unit| unit.minutes()),
+            Some(1)
+        );
+        assert!(matches!(OffsetUnit::parse("mo"), Some(OffsetUnit::Months)));
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    app::{
+        kanban::{Boards, CardStatus},
+        natural_date::OffsetUnit,
+        DateTimeFormat,
+    },
+    constants::MAX_REMINDER_OFFSET,
+};
+use chrono::{DateTime, Local, TimeDelta};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DueReminder {
+    pub board_name: String,
+    pub card_name: String,
+    pub due_date: DateTime<Local>,
+    /// Minutes before the due date
+    pub offset: u64,
+}
+
+impl DueReminder {
+    pub fn message(&self, date_time_format: DateTimeFormat) -> String {
+        format!(
+            "'{}' in '{}' is due {}",
+            self.card_name,
+            self.board_name,
+            date_time_format.format_timestamp(Some(self.due_date))
+        )
+    }
+}
+
+/// Reminders of unfinished cards that come due after `after` and no later than `until`
+pub fn get_due_reminders(
+    boards: &Boards,
+    after: DateTime<Local>,
+    until: DateTime<Local>,
+) -> Vec<DueReminder> {
+    let mut due_reminders = vec![];
+    for board in boards.get_boards() {
+        for card in board.cards.get_all_cards() {
+            if card.card_status == CardStatus::Complete {
+                continue;
+            }
+            let due_date = if let Some(due_date) = card.due_date {
+                due_date
+            } else {
+                continue;
+            };
+            for offset in &card.reminders {
+                // Offsets come from the save file too, so they are not trusted to be in range
+                let remind_at = i64::try_from(*offset)
+                    .ok()
+                    .and_then(TimeDelta::try_minutes)
+                    .and_then(|offset| due_date.checked_sub_signed(offset));
+                let remind_at = if let Some(remind_at) = remind_at {
+                    remind_at
+                } else {
+                    continue;
+                };
+                if remind_at > after && remind_at <= until {
+                    due_reminders.push(DueReminder {
+                        board_name: board.name.clone(),
+                        card_name: card.name.clone(),
+                        due_date,
+                        offset: *offset,
+                    });
+                }
+            }
+        }
+    }
+    due_reminders
+}
+
+/// Parses a comma or space separated list of offsets like "1w, 2d 3h, 30min", a bare number is
+/// taken as minutes
+pub fn parse_reminder_offsets(input: &str) -> Result<Vec<u64>, String> {
+    let mut offsets = vec![];
+    for offset in input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|offset| !offset.is_empty())
+    {
+        let offset = offset.to_lowercase();
+        let unit_start = offset
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(offset.len());
+        let (amount, unit) = offset.split_at(unit_start);
+        let amount = amount
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid reminder '{}'", offset))?;
+        let multiplier = if unit.is_empty() {
+            Some(1)
+        } else {
+            OffsetUnit::parse(unit).and_then(|unit| unit.minutes())
+        };
+        let multiplier = multiplier.ok_or_else(|| format!("Invalid reminder '{}'", offset))?;
+        let minutes = amount
+            .checked_mul(multiplier)
+            .filter(|minutes| *minutes <= MAX_REMINDER_OFFSET)
+            .ok_or_else(|| {
+                format!(
+                    "Reminder '{}' is too far out, reminders can be at most {} before due",
+                    offset,
+                    format_reminder_offset(MAX_REMINDER_OFFSET)
+                )
+            })?;
+        if !offsets.contains(&minutes) {
+            offsets.push(minutes);
+        }
+    }
+    offsets.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(offsets)
+}
+
+pub fn format_reminder_offset(offset: u64) -> String {
+    if offset == 0 {
+        return "0min".to_string();
+    }
+    for (unit, suffix) in [
+        (OffsetUnit::Weeks, "w"),
+        (OffsetUnit::Days, "d"),
+        (OffsetUnit::Hours, "h"),
+    ] {
+        let unit_minutes = unit.minutes().unwrap_or(1);
+        let (amount, remainder) = (offset / unit_minutes, offset % unit_minutes);
+        if remainder == 0 {
+            return format!("{}{}", amount, suffix);
+        }
+    }
+    format!("{}min", offset)
+}
+
+pub fn format_reminder_offsets(offsets: &[u64]) -> String {
+    offsets
+        .iter()
+        .map(|offset| format_reminder_offset(*offset))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Splits the reminder command into a program and its arguments and fills in the {card},
+/// {board}, {due} and {offset} placeholders. The command is not run through a shell so card
+/// names can not inject extra commands
+pub fn build_reminder_command(
+    command_template: &str,
+    reminder: &DueReminder,
+    date_time_format: DateTimeFormat,
+) -> Result<(String, Vec<String>), String> {
+    let due = date_time_format.format_timestamp(Some(reminder.due_date));
+    let offset = format_reminder_offset(reminder.offset);
+    let mut words = split_command(command_template)?
+        .into_iter()
+        .map(|word| {
+            word.replace("{card}", &reminder.card_name)
+                .replace("{board}", &reminder.board_name)
+                .replace("{due}", &due)
+                .replace("{offset}", &offset)
+        })
+        .collect::<Vec<String>>();
+    if words.is_empty() {
+        return Err("Reminder command is empty".to_string());
+    }
+    let program = words.remove(0);
+    Ok((program, words))
+}
+
+/// Splits a command line into words, honouring single and double quotes and backslash escapes
+pub fn split_command(command: &str) -> Result<Vec<String>, String> {
+    let mut words = vec![];
+    let mut current_word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(open_quote), c) if c == open_quote => quote = None,
+            (Some('"') | None, '\\') => {
+                if let Some(escaped) = chars.next() {
+                    current_word.push(escaped);
+                    in_word = true;
+                }
+            }
+            (Some(_), c) => current_word.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current_word));
+                    in_word = false;
+                }
+            }
+            (None, c) => {
+                current_word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if quote.is_some() {
+        return Err("Reminder command has an unclosed quote".to_string());
+    }
+    if in_word {
+        words.push(current_word);
+    }
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::{Board, Card, CardPriority};
+
+    #[test]
+    fn parses_offsets_longest_first() {
+        assert_eq!(
+            parse_reminder_offsets("30min, 1w 2d,3h 15"),
+            Ok(vec![7 * 24 * 60, 2 * 24 * 60, 3 * 60, 30, 15])
+        );
+        assert_eq!(parse_reminder_offsets("1d, 24h"), Ok(vec![24 * 60]));
+        assert_eq!(parse_reminder_offsets(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn refuses_ambiguous_and_variable_units() {
+        for input in ["30m", "1mo", "1y", "x", "1d, 2q"] {
+            assert!(parse_reminder_offsets(input).is_err(), "'{}'", input);
+        }
+    }
+
+    #[test]
+    fn refuses_offsets_beyond_the_maximum() {
+        assert_eq!(
+            parse_reminder_offsets("366d"),
+            Ok(vec![MAX_REMINDER_OFFSET])
+        );
+        assert!(parse_reminder_offsets("367d").is_err());
+        assert!(parse_reminder_offsets("1000000000w").is_err());
+        assert!(parse_reminder_offsets("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn huge_saved_offsets_are_skipped() {
+        let due_date = Local::now();
+        let mut card = Card::new("Card", "", None, CardPriority::Low, vec![], vec![]);
+        card.due_date = Some(due_date);
+        card.reminders = vec![u64::MAX, i64::MAX as u64, 1_000_000_000 * 7 * 24 * 60, 30];
+        let mut board = Board::new("Board", "");
+        board.cards.add_card(card);
+        let boards = Boards::from(vec![board]);
+
+        let due_reminders = get_due_reminders(&boards, due_date - TimeDelta::minutes(60), due_date);
+        assert_eq!(due_reminders.len(), 1);
+        assert_eq!(due_reminders[0].offset, 30);
+    }
+
+    #[test]
+    fn formatted_offsets_parse_back() {
+        let offsets = vec![2 * 7 * 24 * 60, 3 * 24 * 60, 5 * 60, 90, 0];
+        let formatted = format_reminder_offsets(&offsets);
+        assert_eq!(formatted, "2w, 3d, 5h, 90min, 0min");
+        assert_eq!(parse_reminder_offsets(&formatted), Ok(offsets));
+    }
+}
@@ -1,5 +1,11 @@
 use crate::{
-    app::{actions::Action, kanban::Card, VisibleBoardsAndCards},
+    app::{
+        actions::Action,
+        card_template::CardTemplate,
+        kanban::{Boards, Card},
+        merge::PendingMerge,
+        BulkCardAction, TagManagerAction, VisibleBoardsAndCards,
+    },
     constants::{DEFAULT_VIEW, MOUSE_OUT_OF_BOUNDS_COORDINATES},
     inputs::{key::Key, mouse::Mouse},
     io::io_handler::CloudData,
@@ -11,9 +17,12 @@ use log::debug;
 use ratatui::widgets::{ListState, TableState};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashSet,
+    fs::File,
     ops::{Deref, DerefMut},
     str::FromStr,
-    time::Instant,
+    sync::Arc,
+    time::{Instant, SystemTime},
     vec,
 };
 use strum::{Display, EnumString, IntoEnumIterator};
@@ -25,9 +34,14 @@ pub struct AppState<'a> {
     pub app_list_states: AppListStates,
     pub app_status: AppStatus,
     pub app_table_states: AppTableStates,
+    /// Template picked in the new card form, its priority and tags are applied on submit
+    pub applied_card_template: Option<CardTemplate>,
+    pub bulk_edit_action: Option<BulkCardAction>,
     pub card_being_edited: Option<((u64, u64), Card)>, // (board_id, card)
     pub card_drag_mode: bool,
+    pub card_selection_anchor: Option<(u64, u64)>,
     pub cloud_data: Option<Vec<CloudData>>,
+    pub compact_layout: bool,
     pub current_board_id: Option<(u64, u64)>,
     pub current_card_id: Option<(u64, u64)>,
     pub current_mouse_coordinates: (u16, u16),
@@ -35,18 +49,36 @@ pub struct AppState<'a> {
     pub default_theme_mode: bool,
     pub edited_keybinding: Option<Vec<Key>>,
     pub encryption_key_from_arguments: Option<String>,
+    pub encryption_passphrase: Option<String>,
+    pub external_save_change: Option<ExternalSaveChange>,
+    pub passphrase_derived_encryption_key: Option<String>,
     pub filter_tags: Option<Vec<String>>,
     pub focus: Focus,
     pub hovered_board: Option<(u64, u64)>,
     pub hovered_card_dimensions: Option<(u16, u16)>,
     pub hovered_card: Option<((u64, u64), (u64, u64))>,
+    pub ignore_save_directory_lock: bool,
+    pub known_save_file: Option<KnownSaveFile>,
     pub last_mouse_action: Option<Mouse>,
+    pub last_auto_sync_time: Instant,
     pub last_reset_password_link_sent_time: Option<Instant>,
+    pub last_save_directory_check: Instant,
+    pub last_sync_error: Option<String>,
+    pub last_synced_boards: Option<Boards>,
+    pub last_synced_save_id: Option<usize>,
     pub mouse_focus: Option<Focus>,
     pub mouse_list_index: Option<u16>,
+    pub pending_merge: Option<PendingMerge>,
     pub z_stack: ZStack,
+    pub selected_cards: HashSet<(u64, u64)>,
+    /// Tag picked in the tag manager, the target of tag_manager_action
+    pub tag_being_managed: Option<String>,
+    pub tag_manager_action: Option<TagManagerAction>,
     pub prev_focus: Option<Focus>,
     pub prev_view: Option<View>,
+    pub queued_sync: Option<QueuedSync>,
+    pub read_only: bool,
+    pub save_directory_lock: Option<Arc<File>>,
     pub preview_file_name: Option<String>,
     pub preview_visible_boards_and_cards: VisibleBoardsAndCards,
     pub previous_mouse_coordinates: (u16, u16),
@@ -71,6 +103,13 @@ impl AppState<'_> {
     pub fn get_theme_being_edited(&self) -> Theme {
         self.theme_being_edited.clone()
     }
+    /// Base64 encryption key to use instead of the key file, either passed
+    /// as an argument or derived from the encryption passphrase
+    pub fn get_encryption_key_override(&self) -> Option<String> {
+        self.encryption_key_from_arguments
+            .clone()
+            .or(self.passphrase_derived_encryption_key.clone())
+    }
 }
 
 impl Default for AppState<'_> {
@@ -80,9 +119,13 @@ impl Default for AppState<'_> {
             app_list_states: AppListStates::default(),
             app_status: AppStatus::default(),
             app_table_states: AppTableStates::default(),
+            applied_card_template: None,
+            bulk_edit_action: None,
             card_being_edited: None,
             card_drag_mode: false,
+            card_selection_anchor: None,
             cloud_data: None,
+            compact_layout: false,
             current_board_id: None,
             current_card_id: None,
             current_mouse_coordinates: MOUSE_OUT_OF_BOUNDS_COORDINATES, // make sure it's out of bounds when mouse mode is disabled
@@ -90,18 +133,35 @@ impl Default for AppState<'_> {
             default_theme_mode: false,
             edited_keybinding: None,
             encryption_key_from_arguments: None,
+            encryption_passphrase: None,
+            external_save_change: None,
+            passphrase_derived_encryption_key: None,
             filter_tags: None,
             focus: Focus::NoFocus,
             hovered_board: None,
             hovered_card_dimensions: None,
             hovered_card: None,
+            ignore_save_directory_lock: false,
+            known_save_file: None,
             last_mouse_action: None,
+            last_auto_sync_time: Instant::now(),
             last_reset_password_link_sent_time: None,
+            last_save_directory_check: Instant::now(),
+            last_sync_error: None,
+            last_synced_boards: None,
+            last_synced_save_id: None,
             mouse_focus: None,
             mouse_list_index: None,
+            pending_merge: None,
             z_stack: ZStack::default(),
+            selected_cards: HashSet::new(),
+            tag_being_managed: None,
+            tag_manager_action: None,
             prev_focus: None,
             prev_view: None,
+            queued_sync: None,
+            read_only: false,
+            save_directory_lock: None,
             preview_file_name: None,
             preview_visible_boards_and_cards: LinkedHashMap::new(),
             previous_mouse_coordinates: MOUSE_OUT_OF_BOUNDS_COORDINATES,
@@ -168,11 +228,16 @@ impl DerefMut for ZStack {
 
 #[derive(Debug, Clone, Default)]
 pub struct AppListStates {
+    pub board_template_picker: ListState,
+    pub bulk_actions: ListState,
+    pub undo_history: ListState,
     pub card_priority_selector: ListState,
     pub card_status_selector: ListState,
+    pub card_template_picker: ListState,
     pub card_view_comment_list: ListState,
     pub card_view_list: ListState,
     pub card_view_tag_list: ListState,
+    pub tag_manager_actions: ListState,
     pub tag_picker: ListState,
     pub command_palette_board_search: ListState,
     pub command_palette_card_search: ListState,
@@ -181,8 +246,11 @@ pub struct AppListStates {
     pub default_view: ListState,
     pub edit_specific_style: [ListState; 3],
     pub filter_by_tag_list: ListState,
+    pub go_to_board: ListState,
     pub load_save: ListState,
     pub logs: ListState,
+    pub merge_conflicts: ListState,
+    pub external_save_change_actions: ListState,
     pub main_menu: ListState,
     pub theme_selector: ListState,
 }
@@ -192,6 +260,7 @@ pub struct AppTableStates {
     pub config: TableState,
     pub edit_keybindings: TableState,
     pub help: TableState,
+    pub tag_manager: TableState,
     pub theme_editor: TableState,
 }
 
@@ -199,16 +268,24 @@ pub struct AppTableStates {
 pub struct TextBuffers<'a> {
     pub board_name: TextBox<'a>,
     pub board_description: TextBox<'a>,
+    pub board_template_name: TextBox<'a>,
+    pub bulk_edit: TextBox<'a>,
     pub card_name: TextBox<'a>,
     pub card_description: TextBox<'a>,
     pub card_tags: Vec<TextBox<'a>>,
     pub card_comments: Vec<TextBox<'a>>,
+    pub card_template_name: TextBox<'a>,
+    pub due_date: TextBox<'a>,
     pub email_id: TextBox<'a>,
+    pub encryption_passphrase: TextBox<'a>,
     pub password: TextBox<'a>,
     pub confirm_password: TextBox<'a>,
     pub reset_password_link: TextBox<'a>,
     pub general_config: TextBox<'a>,
     pub command_palette: TextBox<'a>,
+    pub go_to_board: TextBox<'a>,
+    pub import_file_path: TextBox<'a>,
+    pub tag_manager: TextBox<'a>,
     pub theme_editor_fg_hex: TextBox<'a>,
     pub theme_editor_bg_hex: TextBox<'a>,
 }
@@ -218,16 +295,24 @@ impl Default for TextBuffers<'_> {
         TextBuffers {
             board_name: TextBox::new(vec!["".to_string()], true),
             board_description: TextBox::new(vec!["".to_string()], false),
+            board_template_name: TextBox::new(vec!["".to_string()], true),
+            bulk_edit: TextBox::new(vec!["".to_string()], true),
             card_name: TextBox::new(vec!["".to_string()], true),
             card_description: TextBox::new(vec!["".to_string()], false),
             card_tags: Vec::new(),
             card_comments: Vec::new(),
+            card_template_name: TextBox::new(vec!["".to_string()], true),
+            due_date: TextBox::new(vec!["".to_string()], true),
             email_id: TextBox::new(vec!["".to_string()], true),
+            encryption_passphrase: TextBox::new(vec!["".to_string()], true),
             password: TextBox::new(vec!["".to_string()], true),
             confirm_password: TextBox::new(vec!["".to_string()], true),
             reset_password_link: TextBox::new(vec!["".to_string()], true),
             general_config: TextBox::new(vec!["".to_string()], true),
             command_palette: TextBox::new(vec!["".to_string()], true),
+            go_to_board: TextBox::new(vec!["".to_string()], true),
+            import_file_path: TextBox::new(vec!["".to_string()], true),
+            tag_manager: TextBox::new(vec!["".to_string()], true),
             theme_editor_fg_hex: TextBox::new(vec!["".to_string()], true),
             theme_editor_bg_hex: TextBox::new(vec!["".to_string()], true),
         }
@@ -266,6 +351,54 @@ pub struct UserLoginData {
     pub user_id: Option<String>,
 }
 
+/// Shown in the title bar so unsynced changes are not left behind on this device
+#[derive(Clone, Copy, PartialEq, Debug, Display)]
+pub enum SyncStatus {
+    Synced,
+    Pending,
+    Error,
+}
+
+/// A sync that failed because the sync backend could not be reached, it is retried
+/// with an exponential backoff and persisted so it survives a restart
+#[derive(Clone, Debug)]
+pub struct QueuedSync {
+    pub attempts: u32,
+    pub next_retry: Instant,
+}
+
+/// The newest save file as last seen by this instance and the boards it was based on,
+/// a newer save file showing up means another instance or a manual edit changed the boards
+#[derive(Clone, Debug)]
+pub struct KnownSaveFile {
+    pub file_name: String,
+    pub modified: Option<SystemTime>,
+    pub boards: Boards,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExternalSaveChange {
+    pub file_name: String,
+    pub base: Boards,
+    pub boards: Boards,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, Display)]
+pub enum ExternalSaveChangeAction {
+    #[strum(to_string = "Reload, discarding my changes")]
+    Reload,
+    #[strum(to_string = "Merge with my changes")]
+    Merge,
+    #[strum(to_string = "Keep my version")]
+    KeepMine,
+}
+
+impl ExternalSaveChangeAction {
+    pub fn all() -> Vec<ExternalSaveChangeAction> {
+        ExternalSaveChangeAction::iter().collect()
+    }
+}
+
 #[derive(Clone, PartialEq, Debug, Default)]
 pub enum AppStatus {
     #[default]
@@ -318,6 +451,7 @@ pub enum Focus {
     StyleEditorFG,
     StyleEditorModifier,
     SubmitButton,
+    TagManagerTable,
     TextInput,
     ThemeEditor,
     ThemeSelector,
@@ -343,10 +477,14 @@ pub struct KeyBindings {
     pub clear_all_toasts: Vec<Key>,
     pub delete_board: Vec<Key>,
     pub delete_card: Vec<Key>,
+    pub duplicate_board: Vec<Key>,
+    pub duplicate_card: Vec<Key>,
     pub down: Vec<Key>,
+    pub go_to_board: Vec<Key>,
     pub go_to_main_menu: Vec<Key>,
     pub go_to_previous_view_or_cancel: Vec<Key>,
     pub hide_ui_element: Vec<Key>,
+    pub jump_to_board: Vec<Key>,
     pub left: Vec<Key>,
     pub move_card_down: Vec<Key>,
     pub move_card_left: Vec<Key>,
@@ -356,14 +494,19 @@ pub struct KeyBindings {
     pub new_card: Vec<Key>,
     pub next_focus: Vec<Key>,
     pub open_config_menu: Vec<Key>,
+    pub open_bulk_actions: Vec<Key>,
+    pub open_undo_history: Vec<Key>,
     pub prv_focus: Vec<Key>,
     pub quit: Vec<Key>,
     pub redo: Vec<Key>,
     pub reset_ui: Vec<Key>,
     pub right: Vec<Key>,
     pub save_state: Vec<Key>,
+    pub select_all_cards_in_board: Vec<Key>,
+    pub select_card_range: Vec<Key>,
     pub stop_user_input: Vec<Key>,
     pub take_user_input: Vec<Key>,
+    pub toggle_card_selection: Vec<Key>,
     pub toggle_command_palette: Vec<Key>,
     pub undo: Vec<Key>,
     pub up: Vec<Key>,
@@ -382,9 +525,13 @@ pub enum KeyBindingEnum {
     DeleteBoard,
     DeleteCard,
     Down,
+    DuplicateBoard,
+    DuplicateCard,
+    GoToBoard,
     GoToMainMenu,
     GoToPreviousViewOrCancel,
     HideUiElement,
+    JumpToBoard,
     Left,
     MoveCardDown,
     MoveCardLeft,
@@ -394,14 +541,19 @@ pub enum KeyBindingEnum {
     NewCard,
     NextFocus,
     OpenConfigMenu,
+    OpenBulkActions,
+    OpenUndoHistory,
     PrvFocus,
     Quit,
     Redo,
     ResetUI,
     Right,
     SaveState,
+    SelectAllCardsInBoard,
+    SelectCardRange,
     StopUserInput,
     TakeUserInput,
+    ToggleCardSelection,
     ToggleCommandPalette,
     Undo,
     Up,
@@ -458,10 +610,14 @@ impl KeyBindings {
                 KeyBindingEnum::ClearAllToasts => &self.clear_all_toasts,
                 KeyBindingEnum::DeleteBoard => &self.delete_board,
                 KeyBindingEnum::DeleteCard => &self.delete_card,
+                KeyBindingEnum::DuplicateBoard => &self.duplicate_board,
+                KeyBindingEnum::DuplicateCard => &self.duplicate_card,
                 KeyBindingEnum::Down => &self.down,
+                KeyBindingEnum::GoToBoard => &self.go_to_board,
                 KeyBindingEnum::GoToMainMenu => &self.go_to_main_menu,
                 KeyBindingEnum::GoToPreviousViewOrCancel => &self.go_to_previous_view_or_cancel,
                 KeyBindingEnum::HideUiElement => &self.hide_ui_element,
+                KeyBindingEnum::JumpToBoard => &self.jump_to_board,
                 KeyBindingEnum::Left => &self.left,
                 KeyBindingEnum::MoveCardDown => &self.move_card_down,
                 KeyBindingEnum::MoveCardLeft => &self.move_card_left,
@@ -471,14 +627,19 @@ impl KeyBindings {
                 KeyBindingEnum::NewCard => &self.new_card,
                 KeyBindingEnum::NextFocus => &self.next_focus,
                 KeyBindingEnum::OpenConfigMenu => &self.open_config_menu,
+                KeyBindingEnum::OpenBulkActions => &self.open_bulk_actions,
+                KeyBindingEnum::OpenUndoHistory => &self.open_undo_history,
                 KeyBindingEnum::PrvFocus => &self.prv_focus,
                 KeyBindingEnum::Quit => &self.quit,
                 KeyBindingEnum::Redo => &self.redo,
                 KeyBindingEnum::ResetUI => &self.reset_ui,
                 KeyBindingEnum::Right => &self.right,
                 KeyBindingEnum::SaveState => &self.save_state,
+                KeyBindingEnum::SelectAllCardsInBoard => &self.select_all_cards_in_board,
+                KeyBindingEnum::SelectCardRange => &self.select_card_range,
                 KeyBindingEnum::StopUserInput => &self.stop_user_input,
                 KeyBindingEnum::TakeUserInput => &self.take_user_input,
+                KeyBindingEnum::ToggleCardSelection => &self.toggle_card_selection,
                 KeyBindingEnum::ToggleCommandPalette => &self.toggle_command_palette,
                 KeyBindingEnum::Undo => &self.undo,
                 KeyBindingEnum::Up => &self.up,
@@ -507,10 +668,14 @@ impl KeyBindings {
             KeyBindingEnum::ClearAllToasts => Action::ClearAllToasts,
             KeyBindingEnum::DeleteBoard => Action::DeleteBoard,
             KeyBindingEnum::DeleteCard => Action::Delete,
+            KeyBindingEnum::DuplicateBoard => Action::DuplicateBoard,
+            KeyBindingEnum::DuplicateCard => Action::DuplicateCard,
             KeyBindingEnum::Down => Action::Down,
+            KeyBindingEnum::GoToBoard => Action::GoToBoard,
             KeyBindingEnum::GoToMainMenu => Action::GoToMainMenu,
             KeyBindingEnum::GoToPreviousViewOrCancel => Action::GoToPreviousViewOrCancel,
             KeyBindingEnum::HideUiElement => Action::HideUiElement,
+            KeyBindingEnum::JumpToBoard => Action::JumpToBoard,
             KeyBindingEnum::Left => Action::Left,
             KeyBindingEnum::MoveCardDown => Action::MoveCardDown,
             KeyBindingEnum::MoveCardLeft => Action::MoveCardLeft,
@@ -520,14 +685,19 @@ impl KeyBindings {
             KeyBindingEnum::NewCard => Action::NewCard,
             KeyBindingEnum::NextFocus => Action::NextFocus,
             KeyBindingEnum::OpenConfigMenu => Action::OpenConfigMenu,
+            KeyBindingEnum::OpenBulkActions => Action::OpenBulkActions,
+            KeyBindingEnum::OpenUndoHistory => Action::OpenUndoHistory,
             KeyBindingEnum::PrvFocus => Action::PrvFocus,
             KeyBindingEnum::Quit => Action::Quit,
             KeyBindingEnum::Redo => Action::Redo,
             KeyBindingEnum::ResetUI => Action::ResetUI,
             KeyBindingEnum::Right => Action::Right,
             KeyBindingEnum::SaveState => Action::SaveState,
+            KeyBindingEnum::SelectAllCardsInBoard => Action::SelectAllCardsInBoard,
+            KeyBindingEnum::SelectCardRange => Action::SelectCardRange,
             KeyBindingEnum::StopUserInput => Action::StopUserInput,
             KeyBindingEnum::TakeUserInput => Action::TakeUserInput,
+            KeyBindingEnum::ToggleCardSelection => Action::ToggleCardSelection,
             KeyBindingEnum::ToggleCommandPalette => Action::ToggleCommandPalette,
             KeyBindingEnum::Undo => Action::Undo,
             KeyBindingEnum::Up => Action::Up,
@@ -562,12 +732,16 @@ impl KeyBindings {
                 KeyBindingEnum::ClearAllToasts => self.clear_all_toasts = keybinding,
                 KeyBindingEnum::DeleteBoard => self.delete_board = keybinding,
                 KeyBindingEnum::DeleteCard => self.delete_card = keybinding,
+                KeyBindingEnum::DuplicateBoard => self.duplicate_board = keybinding,
+                KeyBindingEnum::DuplicateCard => self.duplicate_card = keybinding,
                 KeyBindingEnum::Down => self.down = keybinding,
+                KeyBindingEnum::GoToBoard => self.go_to_board = keybinding,
                 KeyBindingEnum::GoToMainMenu => self.go_to_main_menu = keybinding,
                 KeyBindingEnum::GoToPreviousViewOrCancel => {
                     self.go_to_previous_view_or_cancel = keybinding
                 }
                 KeyBindingEnum::HideUiElement => self.hide_ui_element = keybinding,
+                KeyBindingEnum::JumpToBoard => self.jump_to_board = keybinding,
                 KeyBindingEnum::Left => self.left = keybinding,
                 KeyBindingEnum::MoveCardDown => self.move_card_down = keybinding,
                 KeyBindingEnum::MoveCardLeft => self.move_card_left = keybinding,
@@ -577,14 +751,21 @@ impl KeyBindings {
                 KeyBindingEnum::NewCard => self.new_card = keybinding,
                 KeyBindingEnum::NextFocus => self.next_focus = keybinding,
                 KeyBindingEnum::OpenConfigMenu => self.open_config_menu = keybinding,
+                KeyBindingEnum::OpenBulkActions => self.open_bulk_actions = keybinding,
+                KeyBindingEnum::OpenUndoHistory => self.open_undo_history = keybinding,
                 KeyBindingEnum::PrvFocus => self.prv_focus = keybinding,
                 KeyBindingEnum::Quit => self.quit = keybinding,
                 KeyBindingEnum::Redo => self.redo = keybinding,
                 KeyBindingEnum::ResetUI => self.reset_ui = keybinding,
                 KeyBindingEnum::Right => self.right = keybinding,
                 KeyBindingEnum::SaveState => self.save_state = keybinding,
+                KeyBindingEnum::SelectAllCardsInBoard => {
+                    self.select_all_cards_in_board = keybinding
+                }
+                KeyBindingEnum::SelectCardRange => self.select_card_range = keybinding,
                 KeyBindingEnum::StopUserInput => self.stop_user_input = keybinding,
                 KeyBindingEnum::TakeUserInput => self.take_user_input = keybinding,
+                KeyBindingEnum::ToggleCardSelection => self.toggle_card_selection = keybinding,
                 KeyBindingEnum::ToggleCommandPalette => self.toggle_command_palette = keybinding,
                 KeyBindingEnum::Undo => self.undo = keybinding,
                 KeyBindingEnum::Up => self.up = keybinding,
@@ -619,12 +800,16 @@ impl KeyBindings {
             KeyBindingEnum::ClearAllToasts => Some(self.clear_all_toasts.clone()),
             KeyBindingEnum::DeleteBoard => Some(self.delete_board.clone()),
             KeyBindingEnum::DeleteCard => Some(self.delete_card.clone()),
+            KeyBindingEnum::DuplicateBoard => Some(self.duplicate_board.clone()),
+            KeyBindingEnum::DuplicateCard => Some(self.duplicate_card.clone()),
             KeyBindingEnum::Down => Some(self.down.clone()),
+            KeyBindingEnum::GoToBoard => Some(self.go_to_board.clone()),
             KeyBindingEnum::GoToMainMenu => Some(self.go_to_main_menu.clone()),
             KeyBindingEnum::GoToPreviousViewOrCancel => {
                 Some(self.go_to_previous_view_or_cancel.clone())
             }
             KeyBindingEnum::HideUiElement => Some(self.hide_ui_element.clone()),
+            KeyBindingEnum::JumpToBoard => Some(self.jump_to_board.clone()),
             KeyBindingEnum::Left => Some(self.left.clone()),
             KeyBindingEnum::MoveCardDown => Some(self.move_card_down.clone()),
             KeyBindingEnum::MoveCardLeft => Some(self.move_card_left.clone()),
@@ -634,14 +819,19 @@ impl KeyBindings {
             KeyBindingEnum::NewCard => Some(self.new_card.clone()),
             KeyBindingEnum::NextFocus => Some(self.next_focus.clone()),
             KeyBindingEnum::OpenConfigMenu => Some(self.open_config_menu.clone()),
+            KeyBindingEnum::OpenBulkActions => Some(self.open_bulk_actions.clone()),
+            KeyBindingEnum::OpenUndoHistory => Some(self.open_undo_history.clone()),
             KeyBindingEnum::PrvFocus => Some(self.prv_focus.clone()),
             KeyBindingEnum::Quit => Some(self.quit.clone()),
             KeyBindingEnum::Redo => Some(self.redo.clone()),
             KeyBindingEnum::ResetUI => Some(self.reset_ui.clone()),
             KeyBindingEnum::Right => Some(self.right.clone()),
             KeyBindingEnum::SaveState => Some(self.save_state.clone()),
+            KeyBindingEnum::SelectAllCardsInBoard => Some(self.select_all_cards_in_board.clone()),
+            KeyBindingEnum::SelectCardRange => Some(self.select_card_range.clone()),
             KeyBindingEnum::StopUserInput => Some(self.stop_user_input.clone()),
             KeyBindingEnum::TakeUserInput => Some(self.take_user_input.clone()),
+            KeyBindingEnum::ToggleCardSelection => Some(self.toggle_card_selection.clone()),
             KeyBindingEnum::ToggleCommandPalette => Some(self.toggle_command_palette.clone()),
             KeyBindingEnum::Undo => Some(self.undo.clone()),
             KeyBindingEnum::Up => Some(self.up.clone()),
@@ -662,10 +852,24 @@ impl Default for KeyBindings {
             clear_all_toasts: vec![Key::Char('t')],
             delete_board: vec![Key::Char('D')],
             delete_card: vec![Key::Char('d'), Key::Delete],
+            duplicate_board: vec![Key::Char('Y')],
+            duplicate_card: vec![Key::Char('y')],
             down: vec![Key::Down],
+            go_to_board: vec![Key::Char('g')],
             go_to_main_menu: vec![Key::Char('m')],
             go_to_previous_view_or_cancel: vec![Key::Esc],
             hide_ui_element: vec![Key::Char('h')],
+            jump_to_board: vec![
+                Key::Alt('1'),
+                Key::Alt('2'),
+                Key::Alt('3'),
+                Key::Alt('4'),
+                Key::Alt('5'),
+                Key::Alt('6'),
+                Key::Alt('7'),
+                Key::Alt('8'),
+                Key::Alt('9'),
+            ],
             left: vec![Key::Left],
             move_card_down: vec![Key::ShiftDown],
             move_card_left: vec![Key::ShiftLeft],
@@ -675,14 +879,19 @@ impl Default for KeyBindings {
             new_card: vec![Key::Char('n')],
             next_focus: vec![Key::Tab],
             open_config_menu: vec![Key::Char('c')],
+            open_bulk_actions: vec![Key::Char('B')],
+            open_undo_history: vec![Key::Char('H')],
             prv_focus: vec![Key::BackTab],
             quit: vec![Key::Ctrl('c'), Key::Char('q')],
             redo: vec![Key::Ctrl('y')],
             reset_ui: vec![Key::Char('r')],
             right: vec![Key::Right],
             save_state: vec![Key::Ctrl('s')],
+            select_all_cards_in_board: vec![Key::Ctrl('a')],
+            select_card_range: vec![Key::Char('X')],
             stop_user_input: vec![Key::Ins],
             take_user_input: vec![Key::Char('i')],
+            toggle_card_selection: vec![Key::Char('x')],
             toggle_command_palette: vec![Key::Ctrl('p')],
             undo: vec![Key::Ctrl('z')],
             up: vec![Key::Up],
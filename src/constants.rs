@@ -1,27 +1,56 @@
 use crate::ui::View;
+pub const ACTION_HISTORY_FILE_NAME: &str = "kanban_action_history.json";
 pub const APP_TITLE: &str = "Rust 🦀 Kanban";
+pub const BOARD_TEMPLATE_DIR_NAME: &str = "board_templates";
+pub const BOARD_TEMPLATE_FILE_NAME: &str = "kanban_board_template";
+pub const CARD_TEMPLATE_DIR_NAME: &str = "card_templates";
+pub const CARD_TEMPLATE_FILE_NAME: &str = "kanban_card_template";
 pub const CONFIG_DIR_NAME: &str = "rust_kanban";
 pub const CONFIG_FILE_NAME: &str = "config.json";
+pub const DEFAULT_ACTION_HISTORY_LIMIT: u16 = 100;
+pub const DEFAULT_AUTO_SYNC_INTERVAL: u16 = 0; // minutes, 0 disables auto sync
 pub const DEFAULT_BOARD_TITLE_LENGTH: u16 = 20;
 pub const DEFAULT_CARD_TITLE_LENGTH: u16 = 20;
 pub const DEFAULT_CARD_WARNING_DUE_DATE_DAYS: u16 = 3;
+pub const DEFAULT_DAYS_TO_KEEP_SAVES: u16 = 0; // 0 keeps every save, pruning is opt in
+pub const DEFAULT_REMINDER_WINDOW: u32 = 15; // minutes
 pub const DEFAULT_TICKRATE: u16 = 50;
 pub const DEFAULT_TOAST_DURATION: u64 = 2;
 pub const DEFAULT_VIEW: View = View::TitleBodyHelpLog;
+pub const ENCRYPTED_SAVE_FILE_EXTENSION: &str = ".enc";
 pub const ENCRYPTION_KEY_FILE_NAME: &str = "kanban_encryption_key";
+pub const ENCRYPTION_KEY_SALT_FILE_NAME: &str = "kanban_encryption_key_salt";
+pub const ENCRYPTION_KEY_SALT_LENGTH: usize = 16;
+pub const ENCRYPTION_KEY_SALT_METADATA_KEY: &str = "encryption_key_salt";
+pub const ENCRYPTION_PASSPHRASE_ENV_VAR: &str = "RUST_KANBAN_ENCRYPTION_PASSPHRASE";
+pub const EXPORT_FILE_NAME: &str = "kanban_export";
 pub const FIELD_NA: &str = "N/A";
 pub const FIELD_NOT_SET: &str = "Not Set";
+pub const FILESYSTEM_SYNC_METADATA_FILE_NAME: &str = "kanban_sync_metadata.json";
+pub const FILESYSTEM_SYNC_SAVE_FILE_PREFIX: &str = "kanban_cloud_save_";
+pub const FILESYSTEM_SYNC_USER_ID: &str = "local";
 // TODO: Use textbox masking instead and deprecate this constant
 pub const HIDDEN_PASSWORD_SYMBOL: char = '*';
 pub const IO_EVENT_WAIT_TIME: u64 = 5; // ms
+pub const LAST_SYNCED_SAVE_FILE_NAME: &str = "kanban_last_synced_save";
 pub const LIST_SELECTED_SYMBOL: &str = ">> ";
+pub const MAX_ACTION_HISTORY_LIMIT: u16 = 1000;
+pub const MAX_AUTO_SYNC_INTERVAL: u16 = 1440; // minutes
+pub const MAX_BOARDS_IN_MINI_MAP: usize = 20;
+pub const MAX_DAYS_TO_KEEP_SAVES: u16 = 3650;
+pub const MAX_EXIT_WAIT_TIME: u64 = 10; // seconds
 pub const MAX_NO_BOARDS_PER_PAGE: u16 = 5;
 pub const MAX_NO_CARDS_PER_BOARD: u16 = 4;
+pub const MAX_SAVES_TO_KEEP_PER_DAY: u16 = 1000;
+pub const MAX_SYNC_RETRY_DELAY: u64 = 1800; // seconds
 pub const MAX_TICKRATE: u16 = 1000;
 pub const MAX_TOASTS_TO_DISPLAY: usize = 5;
 pub const MAX_WARNING_DUE_DATE_DAYS: u16 = 30;
+pub const MAX_WEEKLY_SAVES_TO_KEEP: u16 = 520;
+pub const MIN_ACTION_HISTORY_LIMIT: u16 = 1;
 pub const MIN_NO_BOARDS_PER_PAGE: u16 = 1;
 pub const MIN_NO_CARDS_PER_BOARD: u16 = 1;
+pub const MIN_COMPACT_TERM_WIDTH: u16 = 50;
 pub const MIN_TERM_HEIGHT: u16 = 30;
 pub const MIN_TERM_WIDTH: u16 = 110;
 pub const MIN_TICKRATE: u16 = 10;
@@ -29,18 +58,29 @@ pub const MIN_WARNING_DUE_DATE_DAYS: u16 = 1;
 pub const MOUSE_OUT_OF_BOUNDS_COORDINATES: (u16, u16) = (9999, 9999);
 pub const DEFAULT_NO_OF_BOARDS_PER_PAGE: u16 = 3;
 pub const DEFAULT_NO_OF_CARDS_PER_BOARD: u16 = 2;
+pub const DEFAULT_SAVES_TO_KEEP_PER_DAY: u16 = 5;
+pub const DEFAULT_WEEKLY_SAVES_TO_KEEP: u16 = 4;
 pub const PATTERN_CHANGE_INTERVAL: u64 = 1000; // ms
 pub const RANDOM_SEARCH_TERM: &str = "iibnigivirneiivure";
 pub const REFRESH_TOKEN_FILE_NAME: &str = "kanban_token";
 pub const REFRESH_TOKEN_SEPARATOR: &str = "<<>>";
+pub const REMINDER_CHECK_INTERVAL: u64 = 10; // seconds
+pub const REMINDER_LAST_RUN_FILE_NAME: &str = "kanban_reminder_last_run";
+pub const MAX_REMINDER_OFFSET: u64 = 366 * 24 * 60; // minutes
+pub const REMINDER_TOAST_DURATION: u64 = 10; // seconds
 pub const SAMPLE_TEXT: &str = "Sample Text";
 pub const SAVE_DIR_NAME: &str = "kanban_saves";
+pub const SAVE_DIRECTORY_CHECK_INTERVAL: u64 = 2; // seconds
+pub const SAVE_DIRECTORY_LOCK_FILE_NAME: &str = "kanban.lock";
 pub const SAVE_FILE_NAME: &str = "kanban";
-pub const SAVE_FILE_REGEX: &str = r"^kanban_\d{2}-\d{2}-\d{4}_v\d+.json";
+pub const SAVE_FILE_SCHEMA_VERSION: u32 = 3;
+pub const SAVE_FILE_REGEX: &str = r"^kanban_\d{2}-\d{2}-\d{4}_v\d+.json(\.enc)?";
 pub const SCREEN_TO_TOAST_WIDTH_RATIO: u16 = 3; // 1/3rd of the screen width
 pub const SCROLLBAR_BEGIN_SYMBOL: Option<&str> = Some("▲");
 pub const SCROLLBAR_END_SYMBOL: Option<&str> = Some("▼");
 pub const SCROLLBAR_TRACK_SYMBOL: Option<&str> = Some("|");
+pub const SYNC_QUEUE_FILE_NAME: &str = "kanban_sync_queue";
+pub const SYNC_RETRY_BASE_DELAY: u64 = 15; // seconds
 pub const THEME_DIR_NAME: &str = "themes";
 pub const THEME_FILE_NAME: &str = "kanban_theme";
 pub const TOAST_FADE_IN_TIME: u64 = 200;
@@ -31,6 +31,10 @@ impl Events {
                         if let Err(err) = event_tx.send(InputEvent::KeyBoardInput(key)).await {
                             error!("Oops!, {}", err);
                         }
+                    } else if let crossterm::event::Event::Resize(width, height) = event {
+                        if let Err(err) = event_tx.send(InputEvent::Resize(width, height)).await {
+                            error!("Oops!, {}", err);
+                        }
                     }
                 }
                 if let Err(err) = event_tx.send(InputEvent::Tick).await {
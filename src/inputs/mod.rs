@@ -8,5 +8,6 @@ pub mod mouse;
 pub enum InputEvent {
     KeyBoardInput(Key),
     MouseAction(Mouse),
+    Resize(u16, u16),
     Tick,
 }
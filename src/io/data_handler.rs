@@ -1,20 +1,37 @@
 use crate::{
     app::{
+        board_template::BoardSetTemplate,
+        card_template::CardTemplate,
         kanban::{Board, Boards},
-        AppConfig,
+        ActionHistoryEntry, ActionHistoryManager, AppConfig, DateTimeFormat,
     },
     constants::{
-        CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME, SAVE_FILE_REGEX,
-        THEME_DIR_NAME, THEME_FILE_NAME,
+        ACTION_HISTORY_FILE_NAME, BOARD_TEMPLATE_DIR_NAME, BOARD_TEMPLATE_FILE_NAME,
+        CARD_TEMPLATE_DIR_NAME, CARD_TEMPLATE_FILE_NAME, CONFIG_DIR_NAME, CONFIG_FILE_NAME,
+        ENCRYPTED_SAVE_FILE_EXTENSION, SAVE_DIR_NAME, SAVE_FILE_NAME, SAVE_FILE_REGEX,
+        SAVE_FILE_SCHEMA_VERSION, THEME_DIR_NAME, THEME_FILE_NAME,
     },
     inputs::key::Key,
-    io::io_handler::{get_config_dir, make_file_system_safe_name, prepare_config_dir},
+    io::{
+        io_handler::{
+            decrypt_data, encrypt_data, get_config_dir, make_file_system_safe_name,
+            prepare_config_dir,
+        },
+        migration::{get_schema_version, migrate_save, MigrationReport},
+    },
     ui::theme::Theme,
 };
-use log::{debug, error, info};
+use chrono::{Datelike, NaiveDate};
+use log::{debug, error, info, warn};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::{cmp::Ordering, collections::HashMap, env, fs, path::PathBuf};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BTreeMap, HashMap, HashSet},
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 pub fn get_config(ignore_overlapped_keybindings: bool) -> Result<AppConfig, String> {
     let config_dir_status = get_config_dir();
@@ -109,7 +126,11 @@ pub fn reset_config() {
     }
 }
 
-pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Result<(), String> {
+pub fn save_kanban_state_locally(
+    boards: Vec<Board>,
+    config: &AppConfig,
+    encryption_key: Option<&[u8]>,
+) -> Result<(), String> {
     let files = fs::read_dir(&config.save_directory);
     if files.is_err() {
         return Err("Error reading save directory".to_string());
@@ -128,6 +149,7 @@ pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Resu
             let file_version = file_name.split('_').last();
             if let Some(file_version) = file_version {
                 let file_version = file_version.replace('v', "");
+                let file_version = file_version.replace(ENCRYPTED_SAVE_FILE_EXTENSION, "");
                 let file_version = file_version.replace(".json", "");
                 let file_version = file_version.parse::<u32>();
                 if let Ok(file_version) = file_version {
@@ -151,39 +173,132 @@ pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Resu
             }
         }
     }
-    let file_name = format!(
+    let mut file_name = format!(
         "{}_{}_v{}.json",
         SAVE_FILE_NAME,
         chrono::Local::now().format("%d-%m-%Y"),
         version
     );
-    match export_kanban_to_json(&boards, config, file_name) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(e),
+    if encryption_key.is_some() {
+        file_name.push_str(ENCRYPTED_SAVE_FILE_EXTENSION);
     }
+    export_kanban_to_json(&boards, config, file_name, encryption_key)?;
+    if let Err(e) = prune_local_save_files(config) {
+        warn!("Could not prune old save files: {}", e);
+    }
+    Ok(())
+}
+
+/// Deletes the save files not covered by the retention settings, returns how many were deleted
+pub fn prune_local_save_files(config: &AppConfig) -> Result<usize, String> {
+    let save_files = get_available_local_save_files(config);
+    if save_files.is_none() {
+        return Err("Error reading save directory".to_string());
+    }
+    let save_files_to_prune = get_save_files_to_prune(&save_files.unwrap(), config);
+    for file_name in &save_files_to_prune {
+        info!("Pruning old save file: {}", file_name);
+        if let Err(e) = fs::remove_file(config.save_directory.join(file_name)) {
+            debug!("Error removing save file {}: {}", file_name, e);
+            return Err(format!("Error removing save file {}", file_name));
+        }
+    }
+    Ok(save_files_to_prune.len())
+}
+
+/// Keeps the latest saves_to_keep_per_day saves of each of the latest days_to_keep_saves days
+/// that have saves, and the latest save of each of the weekly_saves_to_keep weeks before them.
+/// A days_to_keep_saves or saves_to_keep_per_day of 0 keeps everything in that range
+fn get_save_files_to_prune(save_files: &[String], config: &AppConfig) -> Vec<String> {
+    if config.days_to_keep_saves == 0 {
+        return vec![];
+    }
+    let mut saves_by_date: BTreeMap<NaiveDate, Vec<(u32, &String)>> = BTreeMap::new();
+    for file_name in save_files {
+        if let Some((date, version)) = parse_save_file_name(file_name) {
+            saves_by_date
+                .entry(date)
+                .or_default()
+                .push((version, file_name));
+        }
+    }
+    let mut files_to_keep: HashSet<&String> = HashSet::new();
+    let mut covered_weeks = HashSet::new();
+    let mut weekly_saves_kept = 0;
+    for (day_index, (date, saves)) in saves_by_date.iter_mut().rev().enumerate() {
+        saves.sort_by_key(|(version, _)| Reverse(*version));
+        let week = (date.iso_week().year(), date.iso_week().week());
+        if day_index < config.days_to_keep_saves as usize {
+            let saves_to_keep = if config.saves_to_keep_per_day == 0 {
+                saves.len()
+            } else {
+                config.saves_to_keep_per_day as usize
+            };
+            files_to_keep.extend(saves.iter().take(saves_to_keep).map(|(_, file)| *file));
+            covered_weeks.insert(week);
+        } else if !covered_weeks.contains(&week) && weekly_saves_kept < config.weekly_saves_to_keep
+        {
+            files_to_keep.insert(saves[0].1);
+            covered_weeks.insert(week);
+            weekly_saves_kept += 1;
+        }
+    }
+    save_files
+        .iter()
+        .filter(|file_name| {
+            parse_save_file_name(file_name).is_some() && !files_to_keep.contains(file_name)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Date and version of a kanban_<date>_v<version>.json(.enc) save file
+fn parse_save_file_name(file_name: &str) -> Option<(NaiveDate, u32)> {
+    let mut parts = file_name.split('_');
+    let date = NaiveDate::parse_from_str(parts.nth(1)?, "%d-%m-%Y").ok()?;
+    let version = parts
+        .next()?
+        .trim_start_matches('v')
+        .replace(ENCRYPTED_SAVE_FILE_EXTENSION, "")
+        .replace(".json", "")
+        .parse::<u32>()
+        .ok()?;
+    Some((date, version))
 }
 
 pub fn get_local_kanban_state(
     file_name: String,
     preview_mode: bool,
     config: &AppConfig,
+    encryption_key: Option<&[u8]>,
 ) -> Result<Boards, String> {
     let file_path = config.save_directory.join(file_name);
     if !preview_mode {
         info!("Loading local save file: {:?}", file_path);
     }
-    let file = fs::File::open(file_path);
-    if file.is_err() {
-        debug!("Error opening save file: {}", file.err().unwrap());
-        return Err("Error opening save file".to_string());
+    let file_content = read_local_file(&file_path, encryption_key);
+    if let Err(e) = file_content {
+        debug!("Error opening save file: {}", e);
+        return Err(e);
     }
-    let file = file.unwrap();
-    let serde_object = serde_json::from_reader(file);
+    let serde_object = serde_json::from_str(&file_content.unwrap());
     if serde_object.is_err() {
         debug!("Error parsing save file: {}", serde_object.err().unwrap());
         return Err("Error parsing save file".to_string());
     }
-    let serde_object: serde_json::Value = serde_object.unwrap();
+    let mut serde_object: serde_json::Value = serde_object.unwrap();
+    let migration_report = migrate_save(&mut serde_object, config.date_time_format);
+    if let Err(e) = migration_report {
+        debug!("Error migrating save file: {}", e);
+        return Err(e);
+    }
+    let schema_version = migration_report.unwrap().from_schema_version;
+    if schema_version != SAVE_FILE_SCHEMA_VERSION && !preview_mode {
+        info!(
+            "Migrated save file from schema version {} to {}",
+            schema_version, SAVE_FILE_SCHEMA_VERSION
+        );
+    }
     let boards = serde_object.get("boards");
     if boards.is_none() {
         debug!("Error parsing save file, no boards found");
@@ -204,6 +319,117 @@ pub fn get_local_kanban_state(
     Ok(Boards::from(parsed_boards))
 }
 
+/// Upgrades a save file to the current schema version in place, the original is copied
+/// next to it first. Returns the backup path and what was dropped while migrating, None if
+/// the file was already up to date
+pub fn migrate_save_file(
+    file_path: &Path,
+    encryption_key: Option<&[u8]>,
+    date_time_format: DateTimeFormat,
+) -> Result<Option<(PathBuf, MigrationReport)>, String> {
+    // A plaintext save has to stay plaintext, read_local_file only decrypts .enc files
+    let encryption_key = encryption_key.filter(|_| is_encrypted_file(file_path));
+    let file_content = read_local_file(file_path, encryption_key)?;
+    let mut save: serde_json::Value = serde_json::from_str(&file_content)
+        .map_err(|e| format!("Error parsing save file: {}", e))?;
+    let schema_version = get_schema_version(&save)?;
+    if schema_version == SAVE_FILE_SCHEMA_VERSION {
+        return Ok(None);
+    }
+    let migration_report = migrate_save(&mut save, date_time_format)?;
+    // Prefixed so the backup does not match SAVE_FILE_REGEX and show up as a save
+    let file_name = file_path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let backup_file_path =
+        file_path.with_file_name(format!("schema_v{}_backup_{}", schema_version, file_name));
+    fs::copy(file_path, &backup_file_path)
+        .map_err(|e| format!("Error backing up save file: {}", e))?;
+    write_local_file(
+        file_path,
+        serde_json::to_string_pretty(&save).unwrap(),
+        encryption_key,
+    )?;
+    Ok(Some((backup_file_path, migration_report)))
+}
+
+/// Writes the undo history next to the save files, tagged with the save file it belongs to
+pub fn save_action_history_locally(
+    action_history_manager: &ActionHistoryManager,
+    save_file_name: String,
+    config: &AppConfig,
+    encryption_key: Option<&[u8]>,
+) -> Result<(), String> {
+    let action_history_struct = ActionHistoryStruct {
+        save_file_name,
+        history_index: action_history_manager.history_index,
+        history: action_history_manager.history.clone(),
+    };
+    let file_path = get_action_history_file_path(config, encryption_key.is_some());
+    // Only keep the variant matching the current encryption setting
+    let stale_file_path = get_action_history_file_path(config, encryption_key.is_none());
+    if stale_file_path.exists() {
+        if let Err(e) = fs::remove_file(&stale_file_path) {
+            debug!("Error removing stale action history file: {}", e);
+        }
+    }
+    let write_status = write_local_file(
+        &file_path,
+        serde_json::to_string(&action_history_struct).unwrap(),
+        encryption_key,
+    );
+    match write_status {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            debug!("Error writing action history file: {}", e);
+            Err("Error writing action history file".to_string())
+        }
+    }
+}
+
+/// Returns the saved undo history and history index if it was written for the given save file
+pub fn get_local_action_history(
+    save_file_name: &str,
+    config: &AppConfig,
+    encryption_key: Option<&[u8]>,
+) -> Result<(Vec<ActionHistoryEntry>, usize), String> {
+    let mut file_path = get_action_history_file_path(config, encryption_key.is_some());
+    if !file_path.exists() {
+        file_path = get_action_history_file_path(config, false);
+    }
+    if !file_path.exists() {
+        return Err("No action history file found".to_string());
+    }
+    let file_content = read_local_file(&file_path, encryption_key);
+    if file_content.is_err() {
+        debug!(
+            "Error reading action history file: {}",
+            file_content.err().unwrap()
+        );
+        return Err("Error reading action history file".to_string());
+    }
+    let action_history_struct = serde_json::from_str::<ActionHistoryStruct>(&file_content.unwrap());
+    if action_history_struct.is_err() {
+        debug!(
+            "Error parsing action history file: {}",
+            action_history_struct.err().unwrap()
+        );
+        return Err("Error parsing action history file".to_string());
+    }
+    let action_history_struct = action_history_struct.unwrap();
+    if action_history_struct.save_file_name != save_file_name {
+        return Err(format!(
+            "Action history belongs to {}, not {}",
+            action_history_struct.save_file_name, save_file_name
+        ));
+    }
+    Ok((
+        action_history_struct.history,
+        action_history_struct.history_index,
+    ))
+}
+
 pub fn get_available_local_save_files(config: &AppConfig) -> Option<Vec<String>> {
     let read_dir_status = fs::read_dir(&config.save_directory);
     match read_dir_status {
@@ -228,6 +454,7 @@ pub fn get_available_local_save_files(config: &AppConfig) -> Option<Vec<String>>
                     .split('v')
                     .nth(1)
                     .unwrap()
+                    .replace(ENCRYPTED_SAVE_FILE_EXTENSION, "")
                     .replace(".json", "")
                     .parse::<u32>()
                     .unwrap();
@@ -235,6 +462,7 @@ pub fn get_available_local_save_files(config: &AppConfig) -> Option<Vec<String>>
                     .split('v')
                     .nth(1)
                     .unwrap()
+                    .replace(ENCRYPTED_SAVE_FILE_EXTENSION, "")
                     .replace(".json", "")
                     .parse::<u32>()
                     .unwrap();
@@ -275,6 +503,7 @@ pub fn export_kanban_to_json(
     boards: &[Board],
     config: &AppConfig,
     file_name: String,
+    encryption_key: Option<&[u8]>,
 ) -> Result<String, String> {
     let version = env!("CARGO_PKG_VERSION");
     let date = format!(
@@ -286,18 +515,110 @@ pub fn export_kanban_to_json(
         boards: boards.to_vec(),
         export_date: date,
         kanban_version: version.to_string(),
+        schema_version: SAVE_FILE_SCHEMA_VERSION,
     };
     let file_path = config.save_directory.join(file_name);
-    let write_status = fs::write(
-        file_path.clone(),
+    let write_status = write_local_file(
+        &file_path,
         serde_json::to_string_pretty(&export_struct).unwrap(),
+        encryption_key,
     );
     match write_status {
         Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
-        Err(e) => Err(e.to_string()),
+        Err(e) => Err(e),
     }
 }
 
+fn get_action_history_file_path(config: &AppConfig, encrypted: bool) -> PathBuf {
+    if encrypted {
+        config.save_directory.join(format!(
+            "{}{}",
+            ACTION_HISTORY_FILE_NAME, ENCRYPTED_SAVE_FILE_EXTENSION
+        ))
+    } else {
+        config.save_directory.join(ACTION_HISTORY_FILE_NAME)
+    }
+}
+
+/// Writes the content as is, or wrapped in an EncryptedFileStruct when a key is given.
+/// Also used for the filesystem sync backend, whose files are copied to other devices
+pub fn write_local_file(
+    file_path: &Path,
+    content: String,
+    encryption_key: Option<&[u8]>,
+) -> Result<(), String> {
+    let content = if let Some(encryption_key) = encryption_key {
+        let (encrypted_data, nonce) = encrypt_data(content.as_bytes(), encryption_key)?;
+        let encrypted_file_struct = EncryptedFileStruct {
+            kanban_version: env!("CARGO_PKG_VERSION").to_string(),
+            nonce,
+            encrypted_data,
+        };
+        serde_json::to_string_pretty(&encrypted_file_struct).unwrap()
+    } else {
+        content
+    };
+    // Written to a temporary file and renamed over the target so a crash mid write
+    // never leaves a truncated file behind
+    let file_name = file_path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let temp_file_path = file_path.with_file_name(format!(".{}.tmp", file_name));
+    let write_result = fs::File::create(&temp_file_path).and_then(|mut temp_file| {
+        temp_file.write_all(content.as_bytes())?;
+        temp_file.sync_all()
+    });
+    if let Err(e) = write_result {
+        _ = fs::remove_file(&temp_file_path);
+        return Err(e.to_string());
+    }
+    fs::rename(&temp_file_path, file_path).map_err(|e| {
+        _ = fs::remove_file(&temp_file_path);
+        e.to_string()
+    })
+}
+
+/// Reads a local file, files with the encrypted extension are decrypted with the given key
+pub fn is_encrypted_file(file_path: &Path) -> bool {
+    file_path
+        .to_str()
+        .is_some_and(|path| path.ends_with(ENCRYPTED_SAVE_FILE_EXTENSION))
+}
+
+fn read_local_file(file_path: &Path, encryption_key: Option<&[u8]>) -> Result<String, String> {
+    let file_content = fs::read_to_string(file_path);
+    if let Err(e) = file_content {
+        debug!("Error reading file {:?}: {}", file_path, e);
+        return Err("Error opening file".to_string());
+    }
+    let file_content = file_content.unwrap();
+    if !is_encrypted_file(file_path) {
+        return Ok(file_content);
+    }
+    let encryption_key = if let Some(encryption_key) = encryption_key {
+        encryption_key
+    } else {
+        return Err("File is encrypted and no encryption key was found".to_string());
+    };
+    let encrypted_file_struct = serde_json::from_str::<EncryptedFileStruct>(&file_content);
+    if encrypted_file_struct.is_err() {
+        debug!(
+            "Error parsing encrypted file: {}",
+            encrypted_file_struct.err().unwrap()
+        );
+        return Err("Error parsing encrypted file".to_string());
+    }
+    let encrypted_file_struct = encrypted_file_struct.unwrap();
+    let decrypted_data = decrypt_data(
+        &encrypted_file_struct.encrypted_data,
+        encryption_key,
+        &encrypted_file_struct.nonce,
+    )?;
+    String::from_utf8(decrypted_data)
+        .map_err(|_| "Error converting decrypted file to string".to_string())
+}
+
 pub fn get_default_save_directory() -> PathBuf {
     let mut default_save_path = env::temp_dir();
     default_save_path.push(SAVE_DIR_NAME);
@@ -376,6 +697,98 @@ pub fn save_theme(theme: Theme) -> Result<String, String> {
     Ok(theme_path.to_str().unwrap().to_string())
 }
 
+fn get_card_template_dir() -> Result<PathBuf, String> {
+    Ok(get_config_dir()?.join(CARD_TEMPLATE_DIR_NAME))
+}
+
+/// Templates that can not be read or parsed are skipped
+pub fn get_saved_card_templates() -> Option<Vec<CardTemplate>> {
+    let card_template_dir = get_card_template_dir().ok()?;
+    let files = fs::read_dir(&card_template_dir).ok()?;
+    let regex_str = format!("^{}_.*\\.json$", CARD_TEMPLATE_FILE_NAME);
+    let re = Regex::new(&regex_str).unwrap();
+    let mut card_templates = Vec::new();
+    for file in files.flatten() {
+        let file_name = file.file_name().to_string_lossy().to_string();
+        if !re.is_match(&file_name) {
+            continue;
+        }
+        let card_template = fs::read_to_string(card_template_dir.join(&file_name))
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                serde_json::from_str::<CardTemplate>(&content).map_err(|e| e.to_string())
+            });
+        match card_template {
+            Ok(card_template) => card_templates.push(card_template),
+            Err(e) => warn!("Skipping card template {}: {}", file_name, e),
+        }
+    }
+    card_templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Some(card_templates)
+}
+
+pub fn save_card_template(card_template: &CardTemplate) -> Result<String, String> {
+    let card_template_dir = get_card_template_dir()?;
+    fs::create_dir_all(&card_template_dir).map_err(|e| e.to_string())?;
+    let card_template_path = card_template_dir.join(format!(
+        "{}_{}.json",
+        CARD_TEMPLATE_FILE_NAME,
+        make_file_system_safe_name(&card_template.name)
+    ));
+    fs::write(
+        &card_template_path,
+        serde_json::to_string_pretty(card_template).unwrap(),
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(card_template_path.to_string_lossy().to_string())
+}
+
+fn get_board_template_dir() -> Result<PathBuf, String> {
+    Ok(get_config_dir()?.join(BOARD_TEMPLATE_DIR_NAME))
+}
+
+/// Templates that can not be read or parsed are skipped
+pub fn get_saved_board_templates() -> Option<Vec<BoardSetTemplate>> {
+    let board_template_dir = get_board_template_dir().ok()?;
+    let files = fs::read_dir(&board_template_dir).ok()?;
+    let regex_str = format!("^{}_.*\\.json$", BOARD_TEMPLATE_FILE_NAME);
+    let re = Regex::new(&regex_str).unwrap();
+    let mut board_templates = Vec::new();
+    for file in files.flatten() {
+        let file_name = file.file_name().to_string_lossy().to_string();
+        if !re.is_match(&file_name) {
+            continue;
+        }
+        let board_template = fs::read_to_string(board_template_dir.join(&file_name))
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                serde_json::from_str::<BoardSetTemplate>(&content).map_err(|e| e.to_string())
+            });
+        match board_template {
+            Ok(board_template) => board_templates.push(board_template),
+            Err(e) => warn!("Skipping board template {}: {}", file_name, e),
+        }
+    }
+    board_templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Some(board_templates)
+}
+
+pub fn save_board_template(board_template: &BoardSetTemplate) -> Result<String, String> {
+    let board_template_dir = get_board_template_dir()?;
+    fs::create_dir_all(&board_template_dir).map_err(|e| e.to_string())?;
+    let board_template_path = board_template_dir.join(format!(
+        "{}_{}.json",
+        BOARD_TEMPLATE_FILE_NAME,
+        make_file_system_safe_name(&board_template.name)
+    ));
+    fs::write(
+        &board_template_path,
+        serde_json::to_string_pretty(board_template).unwrap(),
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(board_template_path.to_string_lossy().to_string())
+}
+
 fn write_default_config() {
     let config = AppConfig::default();
     let write_config_status = write_config(&config);
@@ -384,9 +797,225 @@ fn write_default_config() {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ActionHistoryStruct {
+    pub save_file_name: String,
+    pub history_index: usize,
+    pub history: Vec<ActionHistoryEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EncryptedFileStruct {
+    pub kanban_version: String,
+    pub nonce: String,
+    pub encrypted_data: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ExportStruct {
     pub boards: Vec<Board>,
     pub export_date: String,
     pub kanban_version: String,
+    pub schema_version: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::io_handler::generate_new_encryption_key;
+    use serde_json::json;
+
+    fn retention_config(days: u16, per_day: u16, weekly: u16) -> AppConfig {
+        AppConfig {
+            days_to_keep_saves: days,
+            saves_to_keep_per_day: per_day,
+            weekly_saves_to_keep: weekly,
+            ..AppConfig::default()
+        }
+    }
+
+    fn file_names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn pruned(save_files: &[String], config: &AppConfig) -> Vec<String> {
+        let mut pruned_files = get_save_files_to_prune(save_files, config);
+        pruned_files.sort();
+        pruned_files
+    }
+
+    #[test]
+    fn pruning_is_disabled_by_default() {
+        let save_files = file_names(&[
+            "kanban_01-01-2024_v1.json",
+            "kanban_01-01-2024_v2.json",
+            "kanban_01-02-2024_v1.json",
+        ]);
+        assert!(get_save_files_to_prune(&save_files, &AppConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn keeps_latest_saves_of_recent_days() {
+        let save_files = file_names(&[
+            "kanban_10-06-2024_v1.json",
+            "kanban_10-06-2024_v2.json",
+            "kanban_11-06-2024_v1.json",
+            "kanban_11-06-2024_v2.json",
+            "kanban_11-06-2024_v10.json",
+            "kanban_12-06-2024_v1.json",
+            "kanban_12-06-2024_v2.json.enc",
+            "kanban_12-06-2024_v3.json",
+        ]);
+        assert_eq!(
+            pruned(&save_files, &retention_config(2, 2, 0)),
+            file_names(&[
+                "kanban_10-06-2024_v1.json",
+                "kanban_10-06-2024_v2.json",
+                "kanban_11-06-2024_v1.json",
+                "kanban_12-06-2024_v1.json",
+            ])
+        );
+    }
+
+    #[test]
+    fn zero_saves_per_day_keeps_every_save_of_recent_days() {
+        let save_files = file_names(&[
+            "kanban_11-06-2024_v1.json",
+            "kanban_12-06-2024_v1.json",
+            "kanban_12-06-2024_v2.json",
+        ]);
+        assert_eq!(
+            pruned(&save_files, &retention_config(1, 0, 0)),
+            file_names(&["kanban_11-06-2024_v1.json"])
+        );
+    }
+
+    #[test]
+    fn keeps_one_save_per_week_before_recent_days() {
+        let save_files = file_names(&[
+            "kanban_28-05-2024_v1.json",
+            "kanban_04-06-2024_v1.json",
+            "kanban_11-06-2024_v2.json",
+            "kanban_12-06-2024_v1.json",
+            "kanban_20-06-2024_v1.json",
+            "kanban_20-06-2024_v2.json",
+        ]);
+        assert_eq!(
+            pruned(&save_files, &retention_config(1, 1, 2)),
+            file_names(&[
+                "kanban_11-06-2024_v2.json",
+                "kanban_20-06-2024_v1.json",
+                "kanban_28-05-2024_v1.json",
+            ])
+        );
+    }
+
+    #[test]
+    fn leaves_other_files_alone() {
+        let save_files =
+            file_names(&["notes.json", "kanban_backup.json", "kanban_01-01-2024.json"]);
+        assert!(get_save_files_to_prune(&save_files, &retention_config(1, 1, 0)).is_empty());
+    }
+
+    /// A save from before schema versions, cards as a plain list and dates as strings
+    fn unversioned_save() -> String {
+        json!({
+            "boards": [{
+                "id": [1, 2],
+                "name": "Todo",
+                "description": "Things to do",
+                "cards": [{
+                    "id": [3, 4],
+                    "name": "Write tests",
+                    "description": "Not Set",
+                    "card_status": "Active",
+                    "priority": "High",
+                    "tags": ["work"],
+                    "comments": [],
+                    "date_created": "01/01/2024-10:00:00",
+                    "date_modified": "01/01/2024-10:00:00",
+                    "date_completed": "N/A",
+                    "due_date": "02/01/2024-12:30:00"
+                }]
+            }],
+            "export_date": "01/01/2024-10:00:00",
+            "kanban_version": "0.9.0"
+        })
+        .to_string()
+    }
+
+    fn temp_save_directory() -> PathBuf {
+        let save_directory =
+            env::temp_dir().join(format!("rust_kanban_test_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&save_directory).unwrap();
+        save_directory
+    }
+
+    fn migrate_and_reload(file_name: &str, encrypt_file: bool) {
+        let save_directory = temp_save_directory();
+        let config = AppConfig {
+            save_directory: save_directory.clone(),
+            ..AppConfig::default()
+        };
+        let key = generate_new_encryption_key();
+        let file_path = save_directory.join(file_name);
+        let file_key = if encrypt_file {
+            Some(key.as_slice())
+        } else {
+            None
+        };
+        write_local_file(&file_path, unversioned_save(), file_key).unwrap();
+
+        // The key is always passed, as the CLI does when one is configured
+        let migrated_save_file =
+            migrate_save_file(&file_path, Some(&key), DateTimeFormat::DayMonthYearTime).unwrap();
+        assert!(
+            migrated_save_file.is_some_and(|(backup_file_path, migration_report)| {
+                backup_file_path.exists() && migration_report.dropped_dates.is_empty()
+            })
+        );
+        let migrated_save: serde_json::Value =
+            serde_json::from_str(&read_local_file(&file_path, Some(&key)).unwrap()).unwrap();
+        assert_eq!(migrated_save["schema_version"], SAVE_FILE_SCHEMA_VERSION);
+
+        let boards =
+            get_local_kanban_state(file_name.to_string(), false, &config, Some(&key)).unwrap();
+        let card = boards
+            .get_board_with_index(0)
+            .unwrap()
+            .cards
+            .get_card_with_index(0)
+            .unwrap();
+        assert_eq!(card.name, "Write tests");
+        assert!(card.due_date.is_some());
+        assert!(card.date_completed.is_none());
+        assert_eq!(
+            migrate_save_file(&file_path, Some(&key), DateTimeFormat::DayMonthYearTime),
+            Ok(None)
+        );
+        fs::remove_dir_all(save_directory).unwrap();
+    }
+
+    #[test]
+    fn migrated_plaintext_save_stays_readable() {
+        migrate_and_reload("kanban_01-01-2024_v1.json", false);
+        let save_directory = temp_save_directory();
+        let file_path = save_directory.join("kanban_01-01-2024_v1.json");
+        write_local_file(&file_path, unversioned_save(), None).unwrap();
+        migrate_save_file(
+            &file_path,
+            Some(&generate_new_encryption_key()),
+            DateTimeFormat::DayMonthYearTime,
+        )
+        .unwrap();
+        // Still plain json without a key, not an encrypted file under a .json name
+        let migrated_save = read_local_file(&file_path, None).unwrap();
+        assert!(serde_json::from_str::<EncryptedFileStruct>(&migrated_save).is_err());
+        fs::remove_dir_all(save_directory).unwrap();
+    }
+
+    #[test]
+    fn migrated_encrypted_save_stays_encrypted() {
+        migrate_and_reload("kanban_01-01-2024_v1.json.enc", true);
+    }
 }
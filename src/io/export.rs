@@ -0,0 +1,69 @@
+use crate::{
+    app::{kanban::Boards, AppConfig},
+    constants::EXPORT_FILE_NAME,
+    io::{html::boards_to_html, ical::boards_to_ics, todo_txt::boards_to_todo_txt},
+    ui::theme::Theme,
+};
+use clap::ValueEnum;
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+    #[value(name = "ics")]
+    ICalendar,
+    #[value(name = "todo-txt")]
+    TodoTxt,
+    #[value(name = "html")]
+    Html,
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExportFormat::ICalendar => write!(f, "iCalendar"),
+            ExportFormat::TodoTxt => write!(f, "todo.txt"),
+            ExportFormat::Html => write!(f, "HTML"),
+        }
+    }
+}
+
+impl ExportFormat {
+    pub fn file_extension(&self) -> &str {
+        match self {
+            ExportFormat::ICalendar => "ics",
+            ExportFormat::TodoTxt => "txt",
+            ExportFormat::Html => "html",
+        }
+    }
+
+    /// The theme and config are only used by the html export, for colours and date formatting
+    pub fn export(&self, boards: &Boards, theme: &Theme, config: &AppConfig) -> String {
+        match self {
+            ExportFormat::ICalendar => boards_to_ics(boards),
+            ExportFormat::TodoTxt => boards_to_todo_txt(boards),
+            ExportFormat::Html => {
+                boards_to_html(boards, theme, config.date_time_format, config.warning_delta)
+            }
+        }
+    }
+}
+
+/// Exports go next to the saves, an earlier export in the same format is overwritten
+pub fn get_default_export_path(config: &AppConfig, format: ExportFormat) -> PathBuf {
+    config
+        .save_directory
+        .join(format!("{}.{}", EXPORT_FILE_NAME, format.file_extension()))
+}
+
+pub fn export_boards(
+    boards: &Boards,
+    format: ExportFormat,
+    theme: &Theme,
+    config: &AppConfig,
+    export_path: &Path,
+) -> Result<(), String> {
+    fs::write(export_path, format.export(boards, theme, config)).map_err(|err| err.to_string())
+}
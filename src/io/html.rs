@@ -0,0 +1,316 @@
+use crate::{
+    app::{
+        kanban::{Boards, Card, CardPriority, CardStatus},
+        DateTimeFormat,
+    },
+    constants::{APP_TITLE, FIELD_NOT_SET},
+    ui::{theme::Theme, TextColorOptions},
+    util::get_term_bg_color,
+};
+use chrono::Local;
+use ratatui::style::{Color, Modifier, Style};
+
+/// Renders the boards as a single html file with boards as columns and cards as boxes, colours
+/// are taken from the theme and descriptions and comments are collapsed into details elements
+pub fn boards_to_html(
+    boards: &Boards,
+    theme: &Theme,
+    date_time_format: DateTimeFormat,
+    warning_delta: u16,
+) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str(&format!("<title>{}</title>\n", escape_html(APP_TITLE)));
+    html.push_str(&format!("<style>\n{}</style>\n", get_stylesheet(theme)));
+    html.push_str("</head>\n<body>\n<header>\n");
+    html.push_str(&format!("<h1>{}</h1>\n", escape_html(APP_TITLE)));
+    html.push_str(&format!(
+        "<p class=\"exported\">Exported {}</p>\n",
+        escape_html(&date_time_format.format_timestamp(Some(Local::now())))
+    ));
+    html.push_str("</header>\n<main class=\"boards\">\n");
+    for board in boards.get_boards() {
+        html.push_str("<section class=\"board\">\n");
+        html.push_str(&format!(
+            "<h2>{} <span class=\"count\">({})</span></h2>\n",
+            escape_html(&board.name),
+            board.cards.len()
+        ));
+        if !board.description.is_empty() && board.description != FIELD_NOT_SET {
+            html.push_str(&format!(
+                "<p class=\"board-description\">{}</p>\n",
+                escape_html(&board.description)
+            ));
+        }
+        for card in board.cards.get_all_cards() {
+            html.push_str(&card_to_html(card, date_time_format, warning_delta));
+        }
+        html.push_str("</section>\n");
+    }
+    html.push_str("</main>\n</body>\n</html>\n");
+    html
+}
+
+fn card_to_html(card: &Card, date_time_format: DateTimeFormat, warning_delta: u16) -> String {
+    let status_class = match card.card_status {
+        CardStatus::Active => "status-active",
+        CardStatus::Complete => "status-completed",
+        CardStatus::Stale => "status-stale",
+    };
+    let priority_class = match card.priority {
+        CardPriority::High => "priority-high",
+        CardPriority::Medium => "priority-medium",
+        CardPriority::Low => "priority-low",
+    };
+    let due_class = match card.get_days_until_due() {
+        Some(days_left) if days_left < 0 => "due-overdue",
+        Some(days_left) if days_left <= warning_delta as i64 => "due-warning",
+        _ => "due-default",
+    };
+
+    let mut html = format!("<article class=\"card {}\">\n", status_class);
+    html.push_str(&format!("<h3>{}</h3>\n", escape_html(&card.name)));
+    html.push_str("<p class=\"meta\">");
+    html.push_str(&format!(
+        "<span class=\"{}\">Priority: {}</span> | ",
+        priority_class, card.priority
+    ));
+    html.push_str(&format!(
+        "<span class=\"{}\">Status: {}</span>",
+        status_class, card.card_status
+    ));
+    if card.due_date.is_some() {
+        html.push_str(&format!(
+            " | <span class=\"{}\">Due: {}</span>",
+            due_class,
+            escape_html(&date_time_format.format_timestamp(card.due_date))
+        ));
+    }
+    html.push_str("</p>\n");
+    if !card.tags.is_empty() {
+        html.push_str("<p class=\"tags\">");
+        for tag in &card.tags {
+            html.push_str(&format!("<span class=\"tag\">{}</span>", escape_html(tag)));
+        }
+        html.push_str("</p>\n");
+    }
+    if !card.description.is_empty() && card.description != FIELD_NOT_SET {
+        html.push_str(&format!(
+            "<details>\n<summary>Description</summary>\n<p class=\"text\">{}</p>\n</details>\n",
+            escape_html(&card.description)
+        ));
+    }
+    if !card.comments.is_empty() {
+        html.push_str(&format!(
+            "<details>\n<summary>Comments ({})</summary>\n<ul>\n",
+            card.comments.len()
+        ));
+        for comment in &card.comments {
+            html.push_str(&format!(
+                "<li class=\"text\">{}</li>\n",
+                escape_html(comment)
+            ));
+        }
+        html.push_str("</ul>\n</details>\n");
+    }
+    html.push_str("</article>\n");
+    html
+}
+
+fn get_stylesheet(theme: &Theme) -> String {
+    let (bg_r, bg_g, bg_b) = get_term_bg_color();
+    let page_background = theme
+        .general_style
+        .bg
+        .and_then(css_color)
+        .unwrap_or(format!("#{:02x}{:02x}{:02x}", bg_r, bg_g, bg_b));
+    let page_foreground = theme
+        .general_style
+        .fg
+        .and_then(css_color)
+        .unwrap_or("#ffffff".to_string());
+    let border_color = theme
+        .inactive_text_style
+        .fg
+        .and_then(css_color)
+        .unwrap_or(page_foreground.clone());
+    let status_color = |style: &Style| {
+        style
+            .fg
+            .and_then(css_color)
+            .unwrap_or(page_foreground.clone())
+    };
+
+    let mut css = format!(
+        "body {{ margin: 0; padding: 1rem; font-family: monospace; background-color: {}; color: {}; }}\n",
+        page_background, page_foreground
+    );
+    css.push_str("header h1 { margin: 0; }\n");
+    css.push_str(".exported { margin-top: 0.25rem; opacity: 0.7; }\n");
+    css.push_str(
+        ".boards { display: flex; gap: 1rem; align-items: flex-start; overflow-x: auto; }\n",
+    );
+    css.push_str(&format!(
+        ".board {{ flex: 0 0 20rem; border: 1px solid {}; border-radius: 0.5rem; padding: 0.5rem; }}\n",
+        border_color
+    ));
+    css.push_str(".board h2 { margin: 0.25rem 0 0.5rem; font-size: 1.2rem; }\n");
+    css.push_str(".board-description, .count { opacity: 0.7; }\n");
+    css.push_str(&format!(
+        ".card {{ border: 1px solid {}; border-left-width: 0.4rem; border-radius: 0.4rem; padding: 0.5rem; margin-bottom: 0.5rem; }}\n",
+        border_color
+    ));
+    css.push_str(".card h3 { margin: 0 0 0.25rem; font-size: 1rem; }\n");
+    css.push_str(".meta, .tags { margin: 0.25rem 0; }\n");
+    css.push_str(&format!(
+        ".tag {{ display: inline-block; border: 1px solid {}; border-radius: 0.75rem; padding: 0 0.5rem; margin-right: 0.25rem; }}\n",
+        border_color
+    ));
+    css.push_str("summary { cursor: pointer; }\n");
+    css.push_str(".text { white-space: pre-wrap; }\n");
+    let status_styles = [
+        ("status-active", &theme.card_status_active_style),
+        ("status-completed", &theme.card_status_completed_style),
+        ("status-stale", &theme.card_status_stale_style),
+    ];
+    for (status_class, style) in status_styles {
+        css.push_str(&format!(
+            ".card.{} {{ border-left-color: {}; }}\n",
+            status_class,
+            status_color(style)
+        ));
+    }
+    for (class, style) in status_styles.into_iter().chain([
+        ("priority-high", &theme.card_priority_high_style),
+        ("priority-medium", &theme.card_priority_medium_style),
+        ("priority-low", &theme.card_priority_low_style),
+        ("due-default", &theme.card_due_default_style),
+        ("due-warning", &theme.card_due_warning_style),
+        ("due-overdue", &theme.card_due_overdue_style),
+        ("tag", &theme.help_key_style),
+    ]) {
+        css.push_str(&format!("span.{} {{ {} }}\n", class, style_to_css(style)));
+    }
+    css
+}
+
+fn style_to_css(style: &Style) -> String {
+    let mut declarations = vec![];
+    if let Some(color) = style.fg.and_then(css_color) {
+        declarations.push(format!("color: {};", color));
+    }
+    if let Some(color) = style.bg.and_then(css_color) {
+        declarations.push(format!("background-color: {};", color));
+    }
+    if style.add_modifier.contains(Modifier::BOLD) {
+        declarations.push("font-weight: bold;".to_string());
+    }
+    if style.add_modifier.contains(Modifier::ITALIC) {
+        declarations.push("font-style: italic;".to_string());
+    }
+    if style.add_modifier.contains(Modifier::UNDERLINED) {
+        declarations.push("text-decoration: underline;".to_string());
+    } else if style.add_modifier.contains(Modifier::CROSSED_OUT) {
+        declarations.push("text-decoration: line-through;".to_string());
+    }
+    declarations.join(" ")
+}
+
+/// None for the terminal default colour so the page colours show through
+fn css_color(color: Color) -> Option<String> {
+    match TextColorOptions::from(color) {
+        TextColorOptions::None => None,
+        color => {
+            let (r, g, b) = color.to_rgb();
+            Some(format!("#{:02x}{:02x}{:02x}", r, g, b))
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::Board;
+    use chrono::TimeZone;
+
+    #[test]
+    fn escapes_html_special_characters() {
+        assert_eq!(
+            escape_html(r#"<b>Tom & "Jerry"</b> it's"#),
+            "&lt;b&gt;Tom &amp; &quot;Jerry&quot;&lt;/b&gt; it&#39;s"
+        );
+        assert_eq!(escape_html("&amp;"), "&amp;amp;");
+        assert_eq!(escape_html("plain text"), "plain text");
+    }
+
+    #[test]
+    fn boards_and_cards_are_exported_escaped() {
+        let due_date = Local.with_ymd_and_hms(2030, 5, 15, 17, 30, 0).unwrap();
+        let mut card = Card::new(
+            "<script>alert(1)</script>",
+            "Fix A & B",
+            Some(due_date),
+            CardPriority::High,
+            vec!["<urgent>".to_string(), "work".to_string()],
+            vec!["Looks \"done\"".to_string()],
+        );
+        card.card_status = CardStatus::Complete;
+        let mut board = Board::new("Q&A <board>", "Questions 'n' answers");
+        board.cards.add_card(card);
+        let html = boards_to_html(
+            &Boards::from(vec![board]),
+            &Theme::default(),
+            DateTimeFormat::DayMonthYearTime,
+            3,
+        );
+
+        assert!(html.contains("<h2>Q&amp;A &lt;board&gt; <span class=\"count\">(1)</span></h2>"));
+        assert!(html.contains("<p class=\"board-description\">Questions &#39;n&#39; answers</p>"));
+        assert!(html.contains("<h3>&lt;script&gt;alert(1)&lt;/script&gt;</h3>"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("<article class=\"card status-completed\">"));
+        assert!(html.contains("<span class=\"priority-high\">Priority: High</span>"));
+        assert!(html.contains(&format!(
+            "<span class=\"due-default\">Due: {}</span>",
+            escape_html(&DateTimeFormat::DayMonthYearTime.format_timestamp(Some(due_date)))
+        )));
+        assert!(html
+            .contains("<span class=\"tag\">&lt;urgent&gt;</span><span class=\"tag\">work</span>"));
+        assert!(html.contains("<p class=\"text\">Fix A &amp; B</p>"));
+        assert!(html.contains("<summary>Comments (1)</summary>"));
+        assert!(html.contains("<li class=\"text\">Looks &quot;done&quot;</li>"));
+    }
+
+    #[test]
+    fn unset_card_fields_are_left_out() {
+        let mut board = Board::new("Board", "");
+        board.cards.add_card(Card::new(
+            "Card",
+            "",
+            None,
+            CardPriority::Low,
+            vec![],
+            vec![],
+        ));
+        let html = boards_to_html(
+            &Boards::from(vec![board]),
+            &Theme::default(),
+            DateTimeFormat::default(),
+            3,
+        );
+        assert!(html.contains("<h3>Card</h3>"));
+        assert!(!html.contains("Due:"));
+        assert!(!html.contains("class=\"tags\""));
+        assert!(!html.contains("<details>"));
+        assert!(!html.contains("<p class=\"board-description\">"));
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    app::kanban::{Boards, Card, CardPriority, CardStatus},
+    constants::FIELD_NOT_SET,
+};
+use chrono::{DateTime, Local, Utc};
+
+const ICAL_DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const ICAL_LINE_ENDING: &str = "\r\n";
+/// Content lines longer than this many octets have to be folded
+const ICAL_MAX_LINE_LENGTH: usize = 75;
+
+/// Builds an iCalendar document with one VTODO per card. The UID of every entry is derived from
+/// the card id so importing a newer export updates the existing entries instead of adding copies
+pub fn boards_to_ics(boards: &Boards) -> String {
+    let dtstamp = format_ical_date_time(Local::now());
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!(
+            "PRODID:-//rust-kanban//rust-kanban {}//EN",
+            env!("CARGO_PKG_VERSION")
+        ),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for board in boards.get_boards() {
+        for card in board.cards.get_all_cards() {
+            lines.extend(card_to_vtodo(card, &board.name, &dtstamp));
+        }
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut ics = String::new();
+    for line in lines {
+        ics.push_str(&fold_line(&line));
+        ics.push_str(ICAL_LINE_ENDING);
+    }
+    ics
+}
+
+fn card_to_vtodo(card: &Card, board_name: &str, dtstamp: &str) -> Vec<String> {
+    let mut lines = vec![
+        "BEGIN:VTODO".to_string(),
+        format!("UID:{}-{}@rust-kanban", card.id.0, card.id.1),
+        format!("DTSTAMP:{}", dtstamp),
+        format!("SUMMARY:{}", escape_text(&card.name)),
+    ];
+    if !card.description.is_empty() && card.description != FIELD_NOT_SET {
+        lines.push(format!("DESCRIPTION:{}", escape_text(&card.description)));
+    }
+    if let Some(due_date) = card.due_date {
+        lines.push(format!("DUE:{}", format_ical_date_time(due_date)));
+    }
+    lines.push(format!("PRIORITY:{}", ical_priority(&card.priority)));
+    match card.card_status {
+        CardStatus::Complete => {
+            lines.push("STATUS:COMPLETED".to_string());
+            lines.push("PERCENT-COMPLETE:100".to_string());
+            if let Some(date_completed) = card.date_completed {
+                lines.push(format!(
+                    "COMPLETED:{}",
+                    format_ical_date_time(date_completed)
+                ));
+            }
+        }
+        CardStatus::Active | CardStatus::Stale => lines.push("STATUS:NEEDS-ACTION".to_string()),
+    }
+    let categories = std::iter::once(board_name)
+        .chain(card.tags.iter().map(|tag| tag.as_str()))
+        .filter(|category| !category.is_empty())
+        .map(escape_text)
+        .collect::<Vec<String>>();
+    if !categories.is_empty() {
+        lines.push(format!("CATEGORIES:{}", categories.join(",")));
+    }
+    if let Some(date_created) = card.date_created {
+        lines.push(format!("CREATED:{}", format_ical_date_time(date_created)));
+    }
+    if let Some(date_modified) = card.date_modified {
+        lines.push(format!(
+            "LAST-MODIFIED:{}",
+            format_ical_date_time(date_modified)
+        ));
+    }
+    lines.push("END:VTODO".to_string());
+    lines
+}
+
+/// iCalendar priorities run from 1 (highest) to 9 (lowest)
+fn ical_priority(priority: &CardPriority) -> u8 {
+    match priority {
+        CardPriority::High => 1,
+        CardPriority::Medium => 5,
+        CardPriority::Low => 9,
+    }
+}
+
+fn format_ical_date_time(date_time: DateTime<Local>) -> String {
+    date_time
+        .with_timezone(&Utc)
+        .format(ICAL_DATE_TIME_FORMAT)
+        .to_string()
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+/// Splits a content line into chunks of at most 75 octets, continuation lines start with a space
+/// which counts towards their length. Multi byte characters are never split
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut current_line_length = 0;
+    for c in line.chars() {
+        if current_line_length + c.len_utf8() > ICAL_MAX_LINE_LENGTH {
+            folded.push_str(ICAL_LINE_ENDING);
+            folded.push(' ');
+            current_line_length = 1;
+        }
+        folded.push(c);
+        current_line_length += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::Board;
+    use chrono::{NaiveDateTime, TimeZone};
+
+    /// Joins folded lines back together, the way a calendar client reads them
+    fn unfold(ics: &str) -> Vec<String> {
+        let mut lines: Vec<String> = vec![];
+        for line in ics.split(ICAL_LINE_ENDING).filter(|line| !line.is_empty()) {
+            if let Some(continuation) = line.strip_prefix(' ') {
+                lines.last_mut().unwrap().push_str(continuation);
+            } else {
+                lines.push(line.to_string());
+            }
+        }
+        lines
+    }
+
+    fn unescape(text: &str) -> String {
+        let mut unescaped = String::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                unescaped.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => unescaped.push('\n'),
+                Some(escaped) => unescaped.push(escaped),
+                None => unescaped.push('\\'),
+            }
+        }
+        unescaped
+    }
+
+    fn property<'a>(vtodo: &'a [String], name: &str) -> Option<&'a str> {
+        vtodo
+            .iter()
+            .find_map(|line| line.strip_prefix(&format!("{}:", name)))
+    }
+
+    fn vtodos(ics: &str) -> Vec<Vec<String>> {
+        let mut vtodos = vec![];
+        let mut current = None;
+        for line in unfold(ics) {
+            match line.as_str() {
+                "BEGIN:VTODO" => current = Some(vec![]),
+                "END:VTODO" => vtodos.extend(current.take()),
+                _ => {
+                    if let Some(current) = current.as_mut() {
+                        current.push(line)
+                    }
+                }
+            }
+        }
+        vtodos
+    }
+
+    #[test]
+    fn card_fields_survive_the_export() {
+        let due_date = Local.with_ymd_and_hms(2024, 5, 15, 17, 30, 0).unwrap();
+        let mut card = Card::new(
+            "Call Bob; then, Alice \\ Eve",
+            "First line\nSecond line",
+            Some(due_date),
+            CardPriority::High,
+            vec!["work".to_string(), "phone".to_string()],
+            vec![],
+        );
+        card.card_status = CardStatus::Complete;
+        card.date_completed = Some(due_date);
+        let mut board = Board::new("Todo", "");
+        board.cards.add_card(card.clone());
+        let ics = boards_to_ics(&Boards::from(vec![board]));
+
+        let vtodos = vtodos(&ics);
+        assert_eq!(vtodos.len(), 1);
+        let vtodo = &vtodos[0];
+        assert_eq!(
+            property(vtodo, "UID"),
+            Some(format!("{}-{}@rust-kanban", card.id.0, card.id.1).as_str())
+        );
+        assert_eq!(property(vtodo, "SUMMARY").map(unescape), Some(card.name));
+        assert_eq!(
+            property(vtodo, "DESCRIPTION").map(unescape),
+            Some(card.description)
+        );
+        let due = property(vtodo, "DUE").unwrap();
+        assert_eq!(
+            NaiveDateTime::parse_from_str(due, ICAL_DATE_TIME_FORMAT)
+                .ok()
+                .map(|due| due.and_utc().with_timezone(&Local)),
+            Some(due_date)
+        );
+        assert_eq!(property(vtodo, "PRIORITY"), Some("1"));
+        assert_eq!(property(vtodo, "STATUS"), Some("COMPLETED"));
+        assert_eq!(property(vtodo, "COMPLETED"), Some(due.to_string().as_str()));
+        assert_eq!(property(vtodo, "CATEGORIES"), Some("Todo,work,phone"));
+    }
+
+    #[test]
+    fn unset_fields_are_left_out() {
+        let mut board = Board::new("Todo", "");
+        board.cards.add_card(Card::new(
+            "Card",
+            "",
+            None,
+            CardPriority::Low,
+            vec![],
+            vec![],
+        ));
+        let vtodos = vtodos(&boards_to_ics(&Boards::from(vec![board])));
+        let vtodo = &vtodos[0];
+        assert_eq!(property(vtodo, "DESCRIPTION"), None);
+        assert_eq!(property(vtodo, "DUE"), None);
+        assert_eq!(property(vtodo, "STATUS"), Some("NEEDS-ACTION"));
+        assert_eq!(property(vtodo, "PRIORITY"), Some("9"));
+    }
+
+    #[test]
+    fn long_lines_are_folded_without_splitting_characters() {
+        let name = "ünïcödé ".repeat(30);
+        let mut board = Board::new("Todo", "");
+        board.cards.add_card(Card::new(
+            &name,
+            "",
+            None,
+            CardPriority::Low,
+            vec![],
+            vec![],
+        ));
+        let ics = boards_to_ics(&Boards::from(vec![board]));
+
+        assert!(ics.ends_with(ICAL_LINE_ENDING));
+        for line in ics.split(ICAL_LINE_ENDING) {
+            assert!(line.len() <= ICAL_MAX_LINE_LENGTH, "'{}' is too long", line);
+        }
+        let vtodos = vtodos(&ics);
+        assert_eq!(property(&vtodos[0], "SUMMARY").map(unescape), Some(name));
+    }
+}
@@ -1,47 +1,64 @@
 use crate::{
     app::{
-        app_helper::handle_go_to_previous_view, kanban::Boards, state::UserLoginData, App,
-        AppConfig, VisibleBoardsAndCards,
+        app_helper::handle_go_to_previous_view,
+        kanban::Boards,
+        merge::{merge_boards, MergeSource, PendingMerge},
+        state::{ExternalSaveChange, KnownSaveFile, QueuedSync, UserLoginData},
+        App, AppConfig, DateTimeFormat, VisibleBoardsAndCards,
     },
     constants::{
-        CONFIG_DIR_NAME, CONFIG_FILE_NAME, EMAIL_REGEX, ENCRYPTION_KEY_FILE_NAME,
-        MAX_PASSWORD_LENGTH, MIN_PASSWORD_LENGTH, MIN_TIME_BETWEEN_SENDING_RESET_LINK,
-        REFRESH_TOKEN_FILE_NAME, REFRESH_TOKEN_SEPARATOR, SAVE_DIR_NAME, SUPABASE_ANON_KEY,
-        SUPABASE_URL,
+        CONFIG_DIR_NAME, CONFIG_FILE_NAME, EMAIL_REGEX, ENCRYPTED_SAVE_FILE_EXTENSION,
+        ENCRYPTION_KEY_FILE_NAME, ENCRYPTION_KEY_SALT_FILE_NAME, ENCRYPTION_KEY_SALT_LENGTH,
+        ENCRYPTION_KEY_SALT_METADATA_KEY, LAST_SYNCED_SAVE_FILE_NAME, MAX_PASSWORD_LENGTH,
+        MAX_SYNC_RETRY_DELAY, MIN_PASSWORD_LENGTH, MIN_TIME_BETWEEN_SENDING_RESET_LINK,
+        REFRESH_TOKEN_FILE_NAME, REFRESH_TOKEN_SEPARATOR, SAVE_DIRECTORY_LOCK_FILE_NAME,
+        SAVE_DIR_NAME, SAVE_FILE_SCHEMA_VERSION, SYNC_QUEUE_FILE_NAME, SYNC_RETRY_BASE_DELAY,
     },
     io::{
         data_handler::{
-            get_available_local_save_files, get_default_save_directory, get_local_kanban_state,
-            get_saved_themes, save_kanban_state_locally,
+            get_available_local_save_files, get_config, get_default_save_directory,
+            get_local_action_history, get_local_kanban_state, get_saved_board_templates,
+            get_saved_card_templates, get_saved_themes, prune_local_save_files,
+            save_action_history_locally, save_kanban_state_locally,
+        },
+        export::{export_boards, get_default_export_path, ExportFormat},
+        migration::migrate_save,
+        sync_backend::{
+            is_connection_error, ConfiguredSyncBackend, SupabaseBackend, SyncBackend,
+            SyncBackendType,
         },
         IoEvent,
     },
-    ui::{TextColorOptions, View},
+    ui::{PopUp, TextColorOptions, View},
     util::{
-        print_debug, print_error, print_info, send_error_toast, send_error_toast_with_duration,
-        send_info_toast, send_info_toast_with_duration, send_warning_toast,
-        send_warning_toast_with_duration, update_current_board_and_card,
-        update_current_visible_boards_and_cards,
+        derive_encryption_key_from_cached_salt, print_debug, print_error, print_info, print_warn,
+        send_error_toast, send_error_toast_with_duration, send_info_toast,
+        send_info_toast_with_duration, send_warning_toast, send_warning_toast_with_duration,
+        update_current_board_and_card, update_current_visible_boards_and_cards,
     },
 };
 use aes_gcm::{
-    aead::{generic_array::GenericArray, Aead, OsRng},
+    aead::{generic_array::GenericArray, rand_core::RngCore, Aead, OsRng},
     AeadCore, Aes256Gcm, Key, KeyInit,
 };
+use argon2::Argon2;
 use base64::Engine;
 use chrono::{NaiveDate, NaiveDateTime};
 use eyre::{anyhow, Result};
+use fs2::FileExt;
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info, warn};
 use ratatui::widgets::ListState;
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
 use std::{
+    collections::HashMap,
     env,
+    io::Write,
     path::{Path, PathBuf},
     sync::Arc,
-    time::{Duration, Instant},
+    time::{Duration, Instant, SystemTime},
 };
 
 pub struct IoAsyncHandler<'a> {
@@ -53,15 +70,24 @@ impl IoAsyncHandler<'_> {
         IoAsyncHandler { app }
     }
 
+    /// Built from the current config so edits to the backend settings apply right away
+    async fn get_sync_backend(&self) -> ConfiguredSyncBackend {
+        ConfiguredSyncBackend::from_config(&self.app.lock().await.config)
+    }
+
     pub async fn handle_io_event(&mut self, io_event: IoEvent) {
         let result = match io_event {
             IoEvent::Initialize => self.do_initialize().await,
             IoEvent::SaveLocalData => self.save_local_data().await,
             IoEvent::LoadSaveLocal => self.load_save_file_local().await,
             IoEvent::DeleteLocalSave => self.delete_local_save_file().await,
+            IoEvent::PruneLocalSaves => self.prune_local_save_files().await,
+            IoEvent::ExportBoards(export_format) => self.export_boards(export_format).await,
+            IoEvent::CheckSaveDirectory => self.check_save_directory().await,
             IoEvent::ResetVisibleBoardsandCards => self.refresh_visible_boards_and_cards().await,
             IoEvent::AutoSave => self.auto_save().await,
             IoEvent::LoadLocalPreview => self.load_local_preview().await,
+            IoEvent::UnlockLocalSaves(passphrase) => self.unlock_local_saves(passphrase).await,
             IoEvent::Login(email_id, password) => self.cloud_login(email_id, password).await,
             IoEvent::Logout => self.cloud_logout().await,
             IoEvent::SignUp(email_id, password, confirm_password) => {
@@ -113,12 +139,19 @@ impl IoAsyncHandler<'_> {
                 "Cannot create save directory",
             );
         }
+        acquire_save_directory_lock(&mut app);
         prepare_boards(&mut app);
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
         let saved_themes = get_saved_themes();
         if let Some(saved_themes) = saved_themes {
             app.all_themes.extend(saved_themes);
         }
+        if let Some(board_templates) = get_saved_board_templates() {
+            app.board_templates.extend(board_templates);
+        }
+        if let Some(card_templates) = get_saved_card_templates() {
+            app.card_templates = card_templates;
+        }
         let default_theme = app.config.default_theme.clone();
         for theme in &app.all_themes {
             if theme.name == default_theme {
@@ -142,11 +175,20 @@ impl IoAsyncHandler<'_> {
                 Duration::from_secs(10),
             );
         }
+        app.state.last_synced_save_id = get_last_synced_save_id();
+        app.state.queued_sync = get_queued_sync();
         send_info_toast(&mut app.widgets.toast_widget, "Application initialized");
-        if app.config.auto_login {
+        if app.config.sync_backend_type == SyncBackendType::Filesystem {
+            // No accounts with the filesystem backend, cloud saves are always available
+            app.main_menu.logged_in = true;
+            set_passphrase_derived_encryption_key(&mut app, "").await;
+        } else if app.config.auto_login {
             send_info_toast(&mut app.widgets.toast_widget, "Attempting to auto login");
-            let user_login_data =
-                test_refresh_token_on_disk(app.state.encryption_key_from_arguments.clone()).await;
+            let user_login_data = test_refresh_token_on_disk(
+                &SupabaseBackend::from_config(&app.config),
+                app.state.get_encryption_key_override(),
+            )
+            .await;
             if user_login_data.is_err() {
                 let refresh_token_file_path = get_config_dir();
                 if refresh_token_file_path.is_err() {
@@ -179,8 +221,10 @@ impl IoAsyncHandler<'_> {
                 }
             } else {
                 let user_login_data = user_login_data.unwrap();
+                let access_token = user_login_data.auth_token.clone().unwrap_or_default();
                 app.state.user_login_data = user_login_data;
                 app.main_menu.logged_in = true;
+                set_passphrase_derived_encryption_key(&mut app, &access_token).await;
                 send_info_toast(&mut app.widgets.toast_widget, "👍 Auto login successful");
             }
         }
@@ -190,11 +234,27 @@ impl IoAsyncHandler<'_> {
     async fn save_local_data(&mut self) -> Result<()> {
         info!("🚀 Saving local data");
         let mut app = self.app.lock().await;
+        if is_read_only(&mut app) || detect_external_save_change(&mut app) {
+            return Ok(());
+        }
         if save_required(&mut app) {
+            let encryption_key = match get_encryption_key_for_new_local_save(&mut app) {
+                Ok(encryption_key) => encryption_key,
+                Err(err) => {
+                    error!("{}", err);
+                    send_error_toast(&mut app.widgets.toast_widget, &err);
+                    return Ok(());
+                }
+            };
             let board_data = app.boards.get_boards();
-            let status = save_kanban_state_locally(board_data.to_vec(), &app.config);
+            let status = save_kanban_state_locally(
+                board_data.to_vec(),
+                &app.config,
+                encryption_key.as_deref(),
+            );
             match status {
                 Ok(_) => {
+                    record_known_save_file(&mut app);
                     info!("👍 Local data saved");
                     send_info_toast(&mut app.widgets.toast_widget, "👍 Local data saved");
                 }
@@ -203,8 +263,10 @@ impl IoAsyncHandler<'_> {
                     send_error_toast(&mut app.widgets.toast_widget, "Cannot save local data");
                 }
             }
+            save_action_history(&mut app);
             Ok(())
         } else {
+            save_action_history(&mut app);
             warn!("No changes to save");
             send_warning_toast(&mut app.widgets.toast_widget, "No changes to save");
             Ok(())
@@ -236,11 +298,21 @@ impl IoAsyncHandler<'_> {
         }
         let save_file_name = local_files[save_file_index].clone();
         info!("🚀 Loading save file: {}", save_file_name);
-        let board_data = get_local_kanban_state(save_file_name.clone(), false, &app.config);
+        let encryption_key = get_local_save_encryption_key(&mut app);
+        if prompt_for_passphrase_if_needed(&mut app, &save_file_name, &encryption_key) {
+            return Ok(());
+        }
+        let board_data = get_local_kanban_state(
+            save_file_name.clone(),
+            false,
+            &app.config,
+            encryption_key.as_deref(),
+        );
         match board_data {
             Ok(boards) => {
                 app.boards.set_boards(boards);
-                app.action_history_manager.reset();
+                record_known_save_file(&mut app);
+                restore_action_history(&mut app, &save_file_name);
                 info!("👍 Save file {:?} loaded", save_file_name);
                 send_info_toast(
                     &mut app.widgets.toast_widget,
@@ -248,8 +320,11 @@ impl IoAsyncHandler<'_> {
                 );
             }
             Err(err) => {
-                debug!("Cannot load save file: {:?}", err);
-                send_error_toast(&mut app.widgets.toast_widget, "Cannot load save file");
+                error!("Cannot load save file: {}", err);
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Cannot load save file: {}", err),
+                );
             }
         }
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
@@ -259,6 +334,9 @@ impl IoAsyncHandler<'_> {
 
     async fn delete_local_save_file(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
+        if is_read_only(&mut app) {
+            return Ok(());
+        }
         let file_list = get_available_local_save_files(&app.config);
         let file_list = if let Some(file_list) = file_list {
             file_list
@@ -329,6 +407,81 @@ impl IoAsyncHandler<'_> {
         Ok(())
     }
 
+    async fn prune_local_save_files(&mut self) -> Result<()> {
+        let mut app = self.app.lock().await;
+        if is_read_only(&mut app) {
+            return Ok(());
+        }
+        if app.config.days_to_keep_saves == 0 {
+            warn!("Save retention is disabled, not pruning save files");
+            send_warning_toast(
+                &mut app.widgets.toast_widget,
+                "Set 'Number of Days to Keep Saves' in the config to prune old save files",
+            );
+            return Ok(());
+        }
+        info!("🚀 Pruning old save files");
+        match prune_local_save_files(&app.config) {
+            Ok(0) => {
+                info!("No save files to prune");
+                send_info_toast(&mut app.widgets.toast_widget, "No save files to prune");
+            }
+            Ok(pruned_files) => {
+                info!("👍 Pruned {} old save files", pruned_files);
+                send_info_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("👍 Pruned {} old save files", pruned_files),
+                );
+                app.state.app_list_states.load_save = ListState::default();
+                app.preview_boards_and_cards = None;
+                app.state.preview_file_name = None;
+            }
+            Err(err) => {
+                error!("Cannot prune save files: {}", err);
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Cannot prune save files: {}", err),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn export_boards(&mut self, export_format: ExportFormat) -> Result<()> {
+        let mut app = self.app.lock().await;
+        info!("🚀 Exporting boards to {}", export_format);
+        let export_path = get_default_export_path(&app.config, export_format);
+        match export_boards(
+            &app.boards,
+            export_format,
+            &app.current_theme,
+            &app.config,
+            &export_path,
+        ) {
+            Ok(()) => {
+                info!("👍 Exported boards to {}", export_path.display());
+                send_info_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("👍 Exported boards to {}", export_path.display()),
+                );
+            }
+            Err(err) => {
+                error!("Cannot export boards to {}: {}", export_format, err);
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Cannot export boards to {}: {}", export_format, err),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn check_save_directory(&mut self) -> Result<()> {
+        let mut app = self.app.lock().await;
+        detect_external_save_change(&mut app);
+        Ok(())
+    }
+
     async fn refresh_visible_boards_and_cards(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
         refresh_visible_boards_and_cards(&mut app);
@@ -371,7 +524,16 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let save_file_name = local_files[save_file_index].clone();
-        let board_data = get_local_kanban_state(save_file_name.clone(), true, &app.config);
+        let encryption_key = get_local_save_encryption_key(&mut app);
+        if prompt_for_passphrase_if_needed(&mut app, &save_file_name, &encryption_key) {
+            return Ok(());
+        }
+        let board_data = get_local_kanban_state(
+            save_file_name.clone(),
+            true,
+            &app.config,
+            encryption_key.as_deref(),
+        );
         match board_data {
             Ok(boards) => {
                 app.preview_boards_and_cards = Some(boards);
@@ -384,7 +546,7 @@ impl IoAsyncHandler<'_> {
                     .iter()
                     .enumerate()
                 {
-                    if counter >= app.config.no_of_boards_to_show.into() {
+                    if counter >= app.get_no_of_boards_to_show().into() {
                         break;
                     }
                     let mut visible_cards: Vec<(u64, u64)> = Vec::new();
@@ -412,7 +574,10 @@ impl IoAsyncHandler<'_> {
             }
             Err(e) => {
                 error!("Error loading preview: {}", e);
-                send_error_toast(&mut app.widgets.toast_widget, "Error loading preview");
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    &format!("Error loading preview: {}", e),
+                );
             }
         }
         Ok(())
@@ -443,7 +608,9 @@ impl IoAsyncHandler<'_> {
             }
         }
 
-        let login_for_user_status = login_for_user(&email_id, &password, false).await;
+        let sync_backend = self.get_sync_backend().await;
+        let login_for_user_status =
+            login_for_user(&sync_backend, &email_id, &password, false).await;
         if let Err(err) = login_for_user_status {
             debug!("Error logging in: {:?}", err);
             let mut app = self.app.lock().await;
@@ -463,12 +630,13 @@ impl IoAsyncHandler<'_> {
         app.state.user_login_data.email_id = Some(email_id.to_string());
         app.state.user_login_data.user_id = Some(user_id.to_string());
         app.main_menu.logged_in = true;
+        set_passphrase_derived_encryption_key(&mut app, &access_token).await;
 
         if app.config.auto_login {
             save_refresh_token_to_disk(
                 &refresh_token,
                 &email_id,
-                app.state.encryption_key_from_arguments.clone(),
+                app.state.get_encryption_key_override(),
             )
             .await?;
         }
@@ -495,29 +663,18 @@ impl IoAsyncHandler<'_> {
                 send_info_toast(&mut app.widgets.toast_widget, "Logging out, please wait...");
             }
         }
-        let client = reqwest::Client::new();
-        let response = client
-            .post(format!("{}/auth/v1/logout", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .header(
-                "Authorization",
-                format!(
-                    "Bearer {}",
-                    self.app
-                        .lock()
-                        .await
-                        .state
-                        .user_login_data
-                        .auth_token
-                        .as_ref()
-                        .unwrap()
-                ),
-            )
-            .send()
-            .await?;
-
-        let status = response.status();
+        let auth_token = self
+            .app
+            .lock()
+            .await
+            .state
+            .user_login_data
+            .auth_token
+            .clone()
+            .unwrap();
+        let response = self.get_sync_backend().await.logout(&auth_token).await?;
+
+        let status = response.status;
         if status == StatusCode::NO_CONTENT {
             let mut app = self.app.lock().await;
             app.state.user_login_data = UserLoginData::default();
@@ -636,8 +793,10 @@ impl IoAsyncHandler<'_> {
                 }
             }
 
-            // check if encryption key is present
-            if get_user_encryption_key(app.state.encryption_key_from_arguments.clone()).is_ok() {
+            // check if encryption key is present, with a passphrase the key is derived after login
+            if app.state.encryption_passphrase.is_none()
+                && get_user_encryption_key(app.state.get_encryption_key_override()).is_ok()
+            {
                 warn!("Encryption key already exists, please delete it first or move it if you are trying to create a second account");
                 send_warning_toast_with_duration(
                     &mut app.widgets.toast_widget,
@@ -651,22 +810,13 @@ impl IoAsyncHandler<'_> {
             send_info_toast(&mut app.widgets.toast_widget, "Signing up, please wait...");
         }
 
-        let request_body = json!(
-            {
-                "email": email_id,
-                "password": password
-            }
-        );
-        let client = reqwest::Client::new();
-        let response = client
-            .post(format!("{}/auth/v1/signup", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .body(request_body.to_string())
-            .send()
+        let response = self
+            .get_sync_backend()
+            .await
+            .signup(&email_id, &password)
             .await?;
-        let status = response.status();
-        let body = response.json::<serde_json::Value>().await;
+        let status = response.status;
+        let body = response.json::<serde_json::Value>();
         if status == StatusCode::OK {
             match body {
                 Ok(body) => {
@@ -687,6 +837,17 @@ impl IoAsyncHandler<'_> {
                                 "👍 Confirmation email sent",
                                 Duration::from_secs(10),
                             );
+                            if app.state.encryption_passphrase.is_some() {
+                                info!("Encryption key will be derived from your passphrase when you login");
+                                send_info_toast_with_duration(
+                                    &mut app.widgets.toast_widget,
+                                    "Encryption key will be derived from your passphrase when you login",
+                                    Duration::from_secs(10),
+                                );
+                                let default_view = app.config.default_view;
+                                app.set_view(default_view);
+                                return Ok(());
+                            }
                             let key = generate_new_encryption_key();
                             let save_result = save_user_encryption_key(&key);
                             if save_result.is_err() {
@@ -776,18 +937,13 @@ impl IoAsyncHandler<'_> {
             }
         }
 
-        let request_body = json!({ "email": email_id });
-
-        let client = reqwest::Client::new();
-        let response = client
-            .post(format!("{}/auth/v1/recover", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .body(request_body.to_string())
-            .send()
+        let response = self
+            .get_sync_backend()
+            .await
+            .send_password_recovery(&email_id)
             .await?;
 
-        let status = response.status();
+        let status = response.status;
         if status == StatusCode::OK {
             info!("👍 Reset password email sent");
             let mut app = self.app.lock().await;
@@ -797,7 +953,7 @@ impl IoAsyncHandler<'_> {
                 "👍 Reset password email sent",
             );
         } else if status == StatusCode::TOO_MANY_REQUESTS {
-            let body = response.json::<serde_json::Value>().await;
+            let body = response.json::<serde_json::Value>();
             error!("Too many requests, please try again later. Due to the free nature of supabase i am limited to only 4 signup requests per hour. Sorry! 😢");
             debug!("status code {}, response body: {:?}", status, body);
             let mut app = self.app.lock().await;
@@ -910,100 +1066,53 @@ impl IoAsyncHandler<'_> {
             );
         }
 
-        let client = reqwest::Client::new();
-        let response = client.get(reset_link).send().await;
-        match response {
-            Ok(_) => {
+        let sync_backend = self.get_sync_backend().await;
+        let access_token = match sync_backend
+            .verify_password_recovery_link(&reset_link)
+            .await
+        {
+            Ok(access_token) => access_token,
+            Err(e) => {
                 error!("Error verifying reset password link");
+                debug!("Error verifying reset password link: {}", e);
                 let mut app = self.app.lock().await;
                 send_error_toast(
                     &mut app.widgets.toast_widget,
                     "Error verifying reset password link",
                 );
+                return Ok(());
             }
-            Err(e) => {
-                let mut app = self.app.lock().await;
-                let error_url = e.url();
-                if error_url.is_none() {
-                    error!("Error verifying reset password link");
-                    send_error_toast(
-                        &mut app.widgets.toast_widget,
-                        "Error verifying reset password link",
-                    );
-                    return Ok(());
-                }
-                let error_url = error_url.unwrap();
-                let error_url = error_url.to_string();
-                debug!("error url: {}", error_url);
-                let access_token = error_url.split("access_token=");
-                let access_token = access_token.last();
-                if access_token.is_none() {
-                    error!("Error verifying reset password link");
-                    send_error_toast(
-                        &mut app.widgets.toast_widget,
-                        "Error verifying reset password link",
-                    );
-                    return Ok(());
-                }
-                let mut access_token = access_token.unwrap().split("&expires_at");
-                let access_token = access_token.next();
-                debug!("access token: {:?}", access_token);
-                if access_token.is_none() {
-                    error!("Error verifying reset password link");
-                    send_error_toast(
-                        &mut app.widgets.toast_widget,
-                        "Error verifying reset password link",
-                    );
-                    return Ok(());
-                }
-                drop(app);
-                let access_token = access_token.unwrap();
-                let request_body = json!({ "password": new_password });
-                let reset_client = reqwest::Client::new();
-                let reset_response = reset_client
-                    .put(format!("{}/auth/v1/user", SUPABASE_URL))
-                    .header("apikey", SUPABASE_ANON_KEY)
-                    .header("Content-Type", "application/json")
-                    .header("Authorization", format!("Bearer {}", access_token))
-                    .body(request_body.to_string())
-                    .send()
-                    .await?;
-
-                let status = reset_response.status();
-                let mut app = self.app.lock().await;
-                match status {
-                    StatusCode::OK => {
-                        info!("👍 Password reset successful");
-                        if app.state.current_view == View::ResetPassword {
-                            handle_go_to_previous_view(&mut app).await;
-                        }
-                        send_info_toast(
-                            &mut app.widgets.toast_widget,
-                            "👍 Password reset successful",
-                        );
-                    }
-                    StatusCode::UNPROCESSABLE_ENTITY => {
-                        error!(
-                            "Error resetting password, new password cannot be same as old password"
-                        );
-                        debug!(
-                            "Error resetting password: {:?}",
-                            reset_response.text().await
-                        );
-                        send_error_toast(
-                            &mut app.widgets.toast_widget,
-                            "Error resetting password, new password cannot be same as old password",
-                        );
-                    }
-                    _ => {
-                        error!("Error resetting password");
-                        debug!(
-                            "Error resetting password: {:?}",
-                            reset_response.text().await
-                        );
-                        send_error_toast(&mut app.widgets.toast_widget, "Error resetting password");
-                    }
+        };
+        debug!("access token: {:?}", access_token);
+        let reset_response = sync_backend
+            .update_user(&access_token, json!({ "password": new_password }))
+            .await?;
+
+        let status = reset_response.status;
+        let mut app = self.app.lock().await;
+        match status {
+            StatusCode::OK => {
+                info!("👍 Password reset successful");
+                if app.state.current_view == View::ResetPassword {
+                    handle_go_to_previous_view(&mut app).await;
                 }
+                send_info_toast(
+                    &mut app.widgets.toast_widget,
+                    "👍 Password reset successful",
+                );
+            }
+            StatusCode::UNPROCESSABLE_ENTITY => {
+                error!("Error resetting password, new password cannot be same as old password");
+                debug!("Error resetting password: {:?}", reset_response.body);
+                send_error_toast(
+                    &mut app.widgets.toast_widget,
+                    "Error resetting password, new password cannot be same as old password",
+                );
+            }
+            _ => {
+                error!("Error resetting password");
+                debug!("Error resetting password: {:?}", reset_response.body);
+                send_error_toast(&mut app.widgets.toast_widget, "Error resetting password");
             }
         }
         Ok(())
@@ -1012,7 +1121,8 @@ impl IoAsyncHandler<'_> {
     async fn sync_local_data(&mut self) -> Result<()> {
         {
             let mut app = self.app.lock().await;
-            if app.state.user_login_data.auth_token.is_none() {
+            let requires_login = ConfiguredSyncBackend::from_config(&app.config).requires_login();
+            if requires_login && app.state.user_login_data.auth_token.is_none() {
                 error!("Not logged in");
                 send_error_toast(&mut app.widgets.toast_widget, "Not logged in");
                 return Ok(());
@@ -1025,16 +1135,26 @@ impl IoAsyncHandler<'_> {
             }
         }
 
-        let save_ids = self.get_save_ids_for_user().await?;
-        let max_save_id = save_ids.iter().max();
+        let cloud_saves = self.get_cloud_saves_for_user().await;
+        if let Err(err) = cloud_saves {
+            let mut app = self.app.lock().await;
+            handle_failed_sync(&mut app, &err);
+            return Ok(());
+        }
+        let cloud_saves = cloud_saves.unwrap();
+        let max_save_id = cloud_saves
+            .iter()
+            .map(|cloud_save| cloud_save.save_id)
+            .max();
         let max_save_id = if let Some(max_save_id) = max_save_id {
             max_save_id + 1
         } else {
             0
         };
 
+        self.derive_passphrase_encryption_key_if_missing().await;
         let mut app = self.app.lock().await;
-        let key = get_user_encryption_key(app.state.encryption_key_from_arguments.clone());
+        let key = get_user_encryption_key(app.state.get_encryption_key_override());
         if key.is_err() {
             error!("Error syncing local data, Could not get encryption key, If you have lost it please generate a new one using the -g flag");
             debug!(
@@ -1045,9 +1165,13 @@ impl IoAsyncHandler<'_> {
                 &mut app.widgets.toast_widget,
                 "Error syncing local data, Could not get encryption key, If you have lost it please generate a new one using the -g flag"
             );
+            set_sync_error(&mut app, "Could not get encryption key");
             return Ok(());
         }
         let key = key.unwrap();
+        if !merge_cloud_changes(&mut app, &cloud_saves, &key) {
+            return Ok(());
+        }
         let encrypt_result = encrypt_save(&app.boards, &key);
         if encrypt_result.is_err() {
             error!("Error syncing local data");
@@ -1056,19 +1180,28 @@ impl IoAsyncHandler<'_> {
                 encrypt_result.err()
             );
             send_error_toast(&mut app.widgets.toast_widget, "Error syncing local data");
+            set_sync_error(&mut app, "Could not encrypt local data");
             return Ok(());
         }
         let (encrypted_board_data, nonce) = encrypt_result.unwrap();
-        let auth_token = app.state.user_login_data.auth_token.clone().unwrap();
-        let user_id = app.state.user_login_data.user_id.clone().unwrap();
+        let auth_token = app
+            .state
+            .user_login_data
+            .auth_token
+            .clone()
+            .unwrap_or_default();
+        let user_id = app
+            .state
+            .user_login_data
+            .user_id
+            .clone()
+            .unwrap_or_default();
         drop(app);
-        let client = reqwest::Client::new();
-        let response = client
-            .post(format!("{}/rest/v1/user_data", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", auth_token))
-            .body(
+        let response = self
+            .get_sync_backend()
+            .await
+            .create_save(
+                &auth_token,
                 json!(
                     {
                         "user_id": user_id,
@@ -1076,71 +1209,127 @@ impl IoAsyncHandler<'_> {
                         "save_id": max_save_id,
                         "nonce": nonce
                     }
-                )
-                .to_string(),
+                ),
             )
-            .send()
-            .await?;
+            .await;
 
-        let status = response.status();
         let mut app = self.app.lock().await;
+        if let Err(e) = response {
+            handle_failed_sync(&mut app, &e);
+            return Ok(());
+        }
+        let response = response.unwrap();
+        let status = response.status;
         if status == StatusCode::CREATED {
             info!("👍 Local data synced to the cloud");
             send_info_toast(
                 &mut app.widgets.toast_widget,
                 "👍 Local data synced to the cloud",
             );
+            set_last_synced_save_id(&mut app, max_save_id);
+            set_synced(&mut app);
             if app.state.cloud_data.is_some() {
                 app.dispatch(IoEvent::GetCloudData).await;
             }
+        } else if status.is_server_error() {
+            debug!("Error syncing local data: {:?}", response.body);
+            queue_failed_sync(&mut app, &format!("server responded with {}", status));
         } else {
             error!("Error syncing local data");
-            debug!("Error syncing local data: {:?}", response.text().await);
+            debug!("Error syncing local data: {:?}", response.body);
             send_error_toast(&mut app.widgets.toast_widget, "Error syncing local data");
+            set_sync_error(&mut app, &format!("Sync backend responded with {}", status));
         }
         Ok(())
     }
 
-    async fn get_save_ids_for_user(&mut self) -> Result<Vec<usize>> {
+    /// Uses the passphrase entered in the prompt for local saves. The key is derived with the
+    /// cached salt, or with the salt of the logged in account if none is cached, and the
+    /// preview is reloaded once it is available
+    async fn unlock_local_saves(&mut self, passphrase: String) -> Result<()> {
         {
             let mut app = self.app.lock().await;
-            if app.state.user_login_data.auth_token.is_none() {
-                error!("Not logged in");
-                send_error_toast(&mut app.widgets.toast_widget, "Not logged in");
-                return Ok(vec![]);
-            }
+            app.state.encryption_passphrase = Some(passphrase.clone());
+            app.state.passphrase_derived_encryption_key = None;
+        }
+        if let Some(derived_key) = derive_encryption_key_from_cached_salt(&passphrase) {
+            let mut app = self.app.lock().await;
+            app.state.passphrase_derived_encryption_key = Some(derived_key);
+        } else {
+            self.derive_passphrase_encryption_key_if_missing().await;
+        }
+        let mut app = self.app.lock().await;
+        if app.state.passphrase_derived_encryption_key.is_none() {
+            return Ok(());
+        }
+        info!("Encryption key derived from the entered passphrase");
+        send_info_toast(&mut app.widgets.toast_widget, "Encrypted saves unlocked");
+        let reload_preview = app.state.current_view == View::LoadLocalSave;
+        drop(app);
+        if reload_preview {
+            self.load_local_preview().await?;
         }
+        Ok(())
+    }
+
+    /// The derived key is cleared when the sync backend changes, since every backend keeps its
+    /// own salt. The app is unlocked while the salt is fetched and the key derived
+    async fn derive_passphrase_encryption_key_if_missing(&mut self) {
+        let (sync_backend, access_token, passphrase) = {
+            let app = self.app.lock().await;
+            if app.state.passphrase_derived_encryption_key.is_some() {
+                return;
+            }
+            let passphrase = if let Some(passphrase) = app.state.encryption_passphrase.clone() {
+                passphrase
+            } else {
+                return;
+            };
+            let access_token = app
+                .state
+                .user_login_data
+                .auth_token
+                .clone()
+                .unwrap_or_default();
+            (
+                ConfiguredSyncBackend::from_config(&app.config),
+                access_token,
+                passphrase,
+            )
+        };
+        let derived_key =
+            derive_passphrase_encryption_key(&sync_backend, &access_token, &passphrase).await;
+        let mut app = self.app.lock().await;
+        apply_passphrase_derived_encryption_key(&mut app, derived_key);
+    }
 
+    async fn get_cloud_saves_for_user(&mut self) -> Result<Vec<CloudData>> {
         let (user_id, access_token) = {
             let app = self.app.lock().await;
-            let user_id = app.state.user_login_data.user_id.as_ref().unwrap().clone();
+            let user_id = app
+                .state
+                .user_login_data
+                .user_id
+                .clone()
+                .unwrap_or_default();
             let access_token = app
                 .state
                 .user_login_data
                 .auth_token
-                .as_ref()
-                .unwrap()
-                .clone();
+                .clone()
+                .unwrap_or_default();
             (user_id, access_token)
         };
 
-        let result = get_all_save_ids_for_user(user_id, &access_token).await;
-        if result.is_err() {
-            let error_string = format!("{:?}", result.err());
-            error!("{}", error_string);
-            let mut app = self.app.lock().await;
-            send_error_toast(&mut app.widgets.toast_widget, &error_string);
-            Ok(vec![])
-        } else {
-            let save_ids = result.unwrap();
-            Ok(save_ids)
-        }
+        let sync_backend = self.get_sync_backend().await;
+        get_all_cloud_saves_for_user(&sync_backend, &user_id, &access_token).await
     }
 
     async fn get_cloud_data(&mut self) -> Result<()> {
         {
             let mut app = self.app.lock().await;
-            if app.state.user_login_data.auth_token.is_none() {
+            let requires_login = ConfiguredSyncBackend::from_config(&app.config).requires_login();
+            if requires_login && app.state.user_login_data.auth_token.is_none() {
                 error!("Not logged in");
                 send_error_toast(&mut app.widgets.toast_widget, "Not logged in");
                 return Ok(());
@@ -1154,23 +1343,36 @@ impl IoAsyncHandler<'_> {
             }
         }
 
+        self.derive_passphrase_encryption_key_if_missing().await;
         let app = self.app.lock().await;
-        let auth_token = app.state.user_login_data.auth_token.clone().unwrap();
+        let auth_token = app
+            .state
+            .user_login_data
+            .auth_token
+            .clone()
+            .unwrap_or_default();
         drop(app);
-        let client = reqwest::Client::new();
-        let response = client
-            .get(format!("{}/rest/v1/user_data", SUPABASE_URL))
-            .header("apikey", SUPABASE_ANON_KEY)
-            .header("Content-Type", "application/json")
-            .header("Authorization", format!("Bearer {}", auth_token))
-            .send()
-            .await?;
+        let response = self
+            .get_sync_backend()
+            .await
+            .get_saves(&auth_token, None, None)
+            .await;
+        if let Err(e) = response {
+            error!("Error Refreshing cloud data: {}", e);
+            let mut app = self.app.lock().await;
+            send_error_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Error Refreshing cloud data: {}", e),
+            );
+            return Ok(());
+        }
+        let response = response.unwrap();
 
         let mut app = self.app.lock().await;
 
-        let status = response.status();
+        let status = response.status;
         if status == StatusCode::OK {
-            let body = response.json::<Vec<CloudData>>().await;
+            let body = response.json::<Vec<CloudData>>();
             match body {
                 Ok(cloud_data) => {
                     app.state.cloud_data = Some(cloud_data);
@@ -1212,7 +1414,7 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let save = cloud_data[selected_index].clone();
-        let key = get_user_encryption_key(app.state.encryption_key_from_arguments.clone());
+        let key = get_user_encryption_key(app.state.get_encryption_key_override());
         if key.is_err() {
             error!("Error loading save file, Could not get user Encryption key .If lost please generate a new one by using the -g flag");
             debug!("Error loading save file: {:?}", key.err());
@@ -1222,7 +1424,12 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let key = key.unwrap();
-        let decrypt_result = decrypt_save(save.board_data, key.as_slice(), &save.nonce);
+        let decrypt_result = decrypt_save(
+            save.board_data,
+            key.as_slice(),
+            &save.nonce,
+            app.config.date_time_format,
+        );
         if decrypt_result.is_err() {
             error!("Error loading save file, Could not decrypt save file. The save file must have been created with a different encryption key, either generate a new one with the -g flag or replace the current encryption key with the one used to create the save file");
             debug!("Error loading save file: {:?}", decrypt_result.err());
@@ -1243,7 +1450,7 @@ impl IoAsyncHandler<'_> {
             .iter()
             .enumerate()
         {
-            if counter >= app.config.no_of_boards_to_show.into() {
+            if counter >= app.get_no_of_boards_to_show().into() {
                 break;
             }
             let mut visible_cards: Vec<(u64, u64)> = Vec::new();
@@ -1313,7 +1520,7 @@ impl IoAsyncHandler<'_> {
         let save_file_number = local_files[save_file_index].save_id;
         info!("🚀 Loading save file: cloud_save_{}", save_file_number);
         let encrypted_board_data = &local_files[save_file_index].board_data;
-        let key = get_user_encryption_key(app.state.encryption_key_from_arguments.clone());
+        let key = get_user_encryption_key(app.state.get_encryption_key_override());
         if key.is_err() {
             error!("Error loading save file, Could not get user Encryption key. If lost please generate a new one by using the -g flag");
             debug!("Error loading save file: {:?}", key.err());
@@ -1327,6 +1534,7 @@ impl IoAsyncHandler<'_> {
             encrypted_board_data.to_string(),
             key.as_slice(),
             &local_files[save_file_index].nonce,
+            app.config.date_time_format,
         );
         if decrypt_result.is_err() {
             error!("Error loading save file, Could not decrypt save file. The save file must have been created with a different encryption key, either generate a new one with the -g flag or replace the current encryption key with the one used to create the save file");
@@ -1340,6 +1548,8 @@ impl IoAsyncHandler<'_> {
         }
         let decrypt_result = decrypt_result.unwrap();
         app.boards.set_boards(decrypt_result);
+        set_last_synced_save_id(&mut app, save_file_number);
+        set_synced(&mut app);
         info!("👍 Save file cloud_save_{} loaded", save_file_number);
         send_info_toast(
             &mut app.widgets.toast_widget,
@@ -1353,7 +1563,8 @@ impl IoAsyncHandler<'_> {
     async fn delete_cloud_save(&mut self) -> Result<()> {
         {
             let mut app = self.app.lock().await;
-            if app.state.user_login_data.auth_token.is_none() {
+            let requires_login = ConfiguredSyncBackend::from_config(&app.config).requires_login();
+            if requires_login && app.state.user_login_data.auth_token.is_none() {
                 error!("Not logged in");
                 send_error_toast(&mut app.widgets.toast_widget, "Not logged in");
                 return Ok(());
@@ -1368,7 +1579,12 @@ impl IoAsyncHandler<'_> {
 
         let mut app = self.app.lock().await;
         let save_file_index = app.state.app_list_states.load_save.selected().unwrap_or(0);
-        let user_access_token = app.state.user_login_data.auth_token.clone().unwrap();
+        let user_access_token = app
+            .state
+            .user_login_data
+            .auth_token
+            .clone()
+            .unwrap_or_default();
         let cloud_saves = app.state.cloud_data.clone();
         let cloud_saves = if let Some(cloud_saves) = cloud_saves {
             cloud_saves
@@ -1389,11 +1605,17 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         drop(app);
+        let sync_backend = self.get_sync_backend().await;
         let save_file_id = cloud_saves[save_file_index].id;
         let save_number = cloud_saves[save_file_index].save_id;
-        let delete_status =
-            delete_a_save_from_database(&user_access_token, false, save_file_id, Some(save_number))
-                .await;
+        let delete_status = delete_a_save_from_database(
+            &sync_backend,
+            &user_access_token,
+            false,
+            save_file_id,
+            Some(save_number),
+        )
+        .await;
         let mut app = self.app.lock().await;
         if delete_status.is_err() {
             send_error_toast(&mut app.widgets.toast_widget, "Error deleting cloud save");
@@ -1471,9 +1693,16 @@ fn prepare_boards(app: &mut App) {
     let boards = if app.config.always_load_last_save {
         let latest_save_file_info = get_latest_save_file(&app.config);
         if let Ok(latest_save_file) = latest_save_file_info {
-            let local_data = get_local_kanban_state(latest_save_file.clone(), false, &app.config);
+            let encryption_key = get_local_save_encryption_key(app);
+            let local_data = get_local_kanban_state(
+                latest_save_file.clone(),
+                false,
+                &app.config,
+                encryption_key.as_deref(),
+            );
             match local_data {
                 Ok(data) => {
+                    restore_action_history(app, &latest_save_file);
                     info!("👍 Local data loaded from {:?}", latest_save_file);
                     send_info_toast(
                         &mut app.widgets.toast_widget,
@@ -1496,9 +1725,10 @@ fn prepare_boards(app: &mut App) {
         Boards::default()
     };
     app.boards.set_boards(boards);
+    record_known_save_file(app);
 }
 
-fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
+pub(crate) fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
     let local_save_files = get_available_local_save_files(config);
     let local_save_files = if let Some(local_save_files) = local_save_files {
         local_save_files
@@ -1543,6 +1773,11 @@ fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
         latest_date.format("%d-%m-%Y"),
         latest_version
     );
+    // The latest save may be the encrypted variant of the file name
+    let latest_save_file = local_save_files
+        .into_iter()
+        .find(|file| file.starts_with(&latest_save_file))
+        .unwrap_or(latest_save_file);
     Ok(latest_save_file)
 }
 
@@ -1554,7 +1789,7 @@ pub fn refresh_visible_boards_and_cards(app: &mut App) {
         app.filtered_boards.get_boards()
     };
     for (i, board) in boards.iter().enumerate() {
-        if (i) as u16 == app.config.no_of_boards_to_show {
+        if (i) as u16 == app.get_no_of_boards_to_show() {
             break;
         }
         let mut visible_cards: Vec<(u64, u64)> = Vec::new();
@@ -1595,6 +1830,62 @@ pub fn refresh_visible_boards_and_cards(app: &mut App) {
     }
 }
 
+/// Recomputes the visible boards and cards so that the given board (and card if provided) stay on screen
+pub fn refresh_visible_boards_and_cards_around(
+    app: &mut App,
+    board_id: (u64, u64),
+    card_id: Option<(u64, u64)>,
+) {
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let board_index = match boards.get_board_index(board_id) {
+        Some(index) => index,
+        None => {
+            refresh_visible_boards_and_cards(app);
+            return;
+        }
+    };
+    let no_of_boards_to_show = app.get_no_of_boards_to_show().max(1) as usize;
+    let no_of_cards_to_show = app.config.no_of_cards_to_show.max(1) as usize;
+    let window_start = if boards.len() <= no_of_boards_to_show {
+        0
+    } else {
+        board_index
+            .saturating_sub(no_of_boards_to_show - 1)
+            .min(boards.len() - no_of_boards_to_show)
+    };
+    let mut visible_boards_and_cards: VisibleBoardsAndCards = LinkedHashMap::new();
+    let mut new_current_card_id = None;
+    for board in boards
+        .get_boards()
+        .iter()
+        .skip(window_start)
+        .take(no_of_boards_to_show)
+    {
+        let all_card_ids = board.cards.get_all_card_ids();
+        let mut card_window_start = 0;
+        if board.id == board_id {
+            if let Some(card_index) = card_id.and_then(|id| board.cards.get_card_index(id)) {
+                card_window_start = card_index.saturating_sub(no_of_cards_to_show - 1);
+                new_current_card_id = card_id;
+            } else {
+                new_current_card_id = all_card_ids.first().copied();
+            }
+        }
+        let visible_cards = all_card_ids
+            .into_iter()
+            .skip(card_window_start)
+            .take(no_of_cards_to_show)
+            .collect::<Vec<(u64, u64)>>();
+        visible_boards_and_cards.insert(board.id, visible_cards);
+    }
+    update_current_visible_boards_and_cards(app, visible_boards_and_cards);
+    update_current_board_and_card(&mut app.state, Some(board_id), new_current_card_id);
+}
+
 pub fn make_file_system_safe_name(name: &str) -> String {
     let mut safe_name = name.to_string();
     let unsafe_chars = vec!["/", "\\", ":", "*", "?", "\"", "<", ">", "|", " "];
@@ -1605,50 +1896,340 @@ pub fn make_file_system_safe_name(name: &str) -> String {
 }
 
 pub async fn auto_save(app: &mut App<'_>) -> Result<(), String> {
+    if app.state.read_only {
+        return Ok(());
+    }
+    // handle_exit asks about changes saved elsewhere, so one seen here was written while
+    // exiting. It is never merged without asking, the boards go to a new save file instead
+    let external_save_file =
+        get_external_save_change(app).map(|external_save_change| external_save_change.file_name);
     if save_required(app) {
-        save_kanban_state_locally(app.boards.get_boards().to_vec(), &app.config)
-    } else {
-        Ok(())
+        let encryption_key = get_encryption_key_for_new_local_save(app)?;
+        save_kanban_state_locally(
+            app.boards.get_boards().to_vec(),
+            &app.config,
+            encryption_key.as_deref(),
+        )?;
+        record_known_save_file(app);
+        if let Some(external_save_file) = external_save_file {
+            warn!(
+                "{} was changed while exiting, this instance was saved to {} instead of merging",
+                external_save_file,
+                app.state
+                    .known_save_file
+                    .as_ref()
+                    .map(|known_save_file| known_save_file.file_name.as_str())
+                    .unwrap_or("a new save file")
+            );
+        }
     }
+    save_action_history(app);
+    Ok(())
 }
 
-fn save_required(app: &mut App) -> bool {
-    let latest_save_file_info = get_latest_save_file(&app.config);
-    if let Ok(save_file_name) = latest_save_file_info {
-        let board_data = get_local_kanban_state(save_file_name, false, &app.config);
-        match board_data {
-            Ok(boards) => app.boards != boards,
-            Err(_) => true,
+/// Holds an advisory lock on the save directory while the app runs, a second instance
+/// opens read-only so it cannot bury the first one's saves under its own
+fn acquire_save_directory_lock(app: &mut App) {
+    let lock_file_path = app
+        .config
+        .save_directory
+        .join(SAVE_DIRECTORY_LOCK_FILE_NAME);
+    let lock_file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_file_path);
+    if let Err(e) = lock_file {
+        warn!(
+            "Could not open lock file {}: {}",
+            lock_file_path.display(),
+            e
+        );
+        return;
+    }
+    let mut lock_file = lock_file.unwrap();
+    match lock_file.try_lock_exclusive() {
+        Ok(_) => {
+            if lock_file.set_len(0).is_ok() {
+                _ = write!(lock_file, "{}", std::process::id());
+            }
+            app.state.save_directory_lock = Some(Arc::new(lock_file));
+        }
+        Err(e) if e.kind() == fs2::lock_contended_error().kind() => {
+            if app.state.ignore_save_directory_lock {
+                warn!("Another instance is using the save directory, ignoring its lock");
+                send_warning_toast(
+                    &mut app.widgets.toast_widget,
+                    "Another instance is using the save directory, ignoring its lock",
+                );
+            } else {
+                app.state.read_only = true;
+                warn!("Another instance is using the save directory, opened read-only");
+                send_warning_toast_with_duration(
+                    &mut app.widgets.toast_widget,
+                    "Another instance is using the save directory, opened read-only. Start with --ignore-lock to save anyway",
+                    Duration::from_secs(10),
+                );
+            }
+        }
+        Err(e) => {
+            warn!("Could not lock the save directory: {}", e);
         }
-    } else {
-        true
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CloudData {
-    pub id: u64,
-    pub created_at: String,
-    pub user_id: String,
-    pub board_data: String,
-    pub nonce: String,
-    pub save_id: usize,
+fn is_read_only(app: &mut App) -> bool {
+    if app.state.read_only {
+        warn!("Opened read-only, another instance is using the save directory");
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            "Opened read-only, another instance is using the save directory",
+        );
+    }
+    app.state.read_only
 }
 
-enum PasswordStatus {
-    Strong,
-    MissingUppercase,
-    MissingLowercase,
-    MissingNumber,
-    MissingSpecialChar,
-    TooShort,
-    TooLong,
+fn get_save_file_modified_time(config: &AppConfig, file_name: &str) -> Option<SystemTime> {
+    std::fs::metadata(config.save_directory.join(file_name))
+        .and_then(|metadata| metadata.modified())
+        .ok()
 }
 
-fn check_for_safe_password(check_password: &str) -> PasswordStatus {
-    let mut password_status = PasswordStatus::Strong;
-    if check_password.len() < MIN_PASSWORD_LENGTH {
-        password_status = PasswordStatus::TooShort;
+/// Remembers the newest save file with the current boards as the base for later merges
+fn record_known_save_file(app: &mut App) {
+    app.state.known_save_file =
+        get_latest_save_file(&app.config)
+            .ok()
+            .map(|file_name| KnownSaveFile {
+                modified: get_save_file_modified_time(&app.config, &file_name),
+                file_name,
+                boards: app.boards.clone(),
+            });
+}
+
+/// Returns the boards of a save file written since the last one this instance saw, if they
+/// differ from the current boards. The new save file becomes the known one either way
+fn get_external_save_change(app: &mut App) -> Option<ExternalSaveChange> {
+    let file_name = get_latest_save_file(&app.config).ok()?;
+    let modified = get_save_file_modified_time(&app.config, &file_name);
+    if let Some(known_save_file) = app.state.known_save_file.as_mut() {
+        if known_save_file.file_name == file_name && known_save_file.modified == modified {
+            return None;
+        }
+        // An older save became the newest one, e.g. after deleting a save file
+        if modified <= known_save_file.modified {
+            known_save_file.file_name = file_name;
+            known_save_file.modified = modified;
+            return None;
+        }
+    }
+    let base = app
+        .state
+        .known_save_file
+        .take()
+        .map(|known_save_file| known_save_file.boards)
+        .unwrap_or_default();
+    let encryption_key = get_local_save_encryption_key(app);
+    let boards = get_local_kanban_state(
+        file_name.clone(),
+        true,
+        &app.config,
+        encryption_key.as_deref(),
+    );
+    app.state.known_save_file = Some(KnownSaveFile {
+        file_name: file_name.clone(),
+        modified,
+        boards: boards.clone().unwrap_or(base.clone()),
+    });
+    match boards {
+        Ok(boards) if boards != app.boards => Some(ExternalSaveChange {
+            file_name,
+            base,
+            boards,
+        }),
+        Ok(_) => None,
+        Err(err) => {
+            warn!(
+                "Could not read {} written by another instance: {}",
+                file_name, err
+            );
+            send_warning_toast(
+                &mut app.widgets.toast_widget,
+                &format!("Could not read {} written by another instance", file_name),
+            );
+            None
+        }
+    }
+}
+
+/// Asks whether to reload or merge a save written by another instance or edited by hand,
+/// returns true when the popup was opened
+pub fn detect_external_save_change(app: &mut App) -> bool {
+    if let Some(external_save_change) = get_external_save_change(app) {
+        warn!(
+            "{} was changed outside of this instance",
+            external_save_change.file_name
+        );
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "{} was changed outside of this instance",
+                external_save_change.file_name
+            ),
+        );
+        app.state.external_save_change = Some(external_save_change);
+        app.set_popup(PopUp::ExternalSaveChanged);
+        true
+    } else {
+        false
+    }
+}
+
+/// Persists the undo history for the latest save file if enabled in the config
+fn save_action_history(app: &mut App) {
+    if !app.config.save_action_history {
+        return;
+    }
+    let encryption_key = match get_encryption_key_for_new_local_save(app) {
+        Ok(encryption_key) => encryption_key,
+        Err(err) => {
+            error!("Cannot save undo history: {}", err);
+            return;
+        }
+    };
+    let latest_save_file = get_latest_save_file(&app.config);
+    if let Ok(latest_save_file) = latest_save_file {
+        let save_status = save_action_history_locally(
+            &app.action_history_manager,
+            latest_save_file,
+            &app.config,
+            encryption_key.as_deref(),
+        );
+        if let Err(err) = save_status {
+            error!("Cannot save undo history: {}", err);
+            send_error_toast(&mut app.widgets.toast_widget, "Cannot save undo history");
+        }
+    } else {
+        debug!("Not saving undo history as there is no save file");
+    }
+}
+
+/// Replaces the undo history with the one saved for the given save file, if any
+fn restore_action_history(app: &mut App, save_file_name: &str) {
+    app.action_history_manager.reset();
+    if !app.config.save_action_history {
+        return;
+    }
+    let encryption_key = get_local_save_encryption_key(app);
+    match get_local_action_history(save_file_name, &app.config, encryption_key.as_deref()) {
+        Ok((history, history_index)) => {
+            info!(
+                "Restored {} undo history entries for {}",
+                history.len(),
+                save_file_name
+            );
+            app.action_history_manager.restore(history, history_index);
+        }
+        Err(err) => {
+            debug!("Not restoring undo history: {}", err);
+        }
+    }
+}
+
+fn save_required(app: &mut App) -> bool {
+    let latest_save_file_info = get_latest_save_file(&app.config);
+    if let Ok(save_file_name) = latest_save_file_info {
+        // A save in the other format is always outdated so that toggling encryption takes effect
+        if save_file_name.ends_with(ENCRYPTED_SAVE_FILE_EXTENSION) != app.config.encrypt_local_saves
+        {
+            return true;
+        }
+        let encryption_key = get_local_save_encryption_key(app);
+        let board_data = get_local_kanban_state(
+            save_file_name,
+            false,
+            &app.config,
+            encryption_key.as_deref(),
+        );
+        match board_data {
+            Ok(boards) => app.boards != boards,
+            Err(_) => true,
+        }
+    } else {
+        true
+    }
+}
+
+/// Key for encrypted local saves. A passphrase derived key is kept in the state and only
+/// derived here when it is missing, it is replaced when a login fetches the salt and cleared
+/// when the passphrase or sync backend changes, as every derivation runs argon2
+fn get_local_save_encryption_key(app: &mut App) -> Option<Vec<u8>> {
+    if app.state.encryption_key_from_arguments.is_none() {
+        if let Some(passphrase) = app.state.encryption_passphrase.clone() {
+            if app.state.passphrase_derived_encryption_key.is_none() {
+                app.state.passphrase_derived_encryption_key =
+                    derive_encryption_key_from_cached_salt(&passphrase);
+            }
+            // Do not fall back to the key file when a passphrase is in use
+            app.state.passphrase_derived_encryption_key.as_ref()?;
+        }
+    }
+    get_user_encryption_key(app.state.get_encryption_key_override()).ok()
+}
+
+/// Asks for the passphrase when an encrypted save is opened without a key, returns true if
+/// the prompt was shown
+fn prompt_for_passphrase_if_needed(
+    app: &mut App,
+    save_file_name: &str,
+    encryption_key: &Option<Vec<u8>>,
+) -> bool {
+    if encryption_key.is_some() || !save_file_name.ends_with(ENCRYPTED_SAVE_FILE_EXTENSION) {
+        return false;
+    }
+    info!(
+        "{} is encrypted and no key is available, asking for the passphrase",
+        save_file_name
+    );
+    app.set_popup(PopUp::EncryptionPassphrasePrompt);
+    true
+}
+
+/// Key to encrypt new local saves with, None if local encryption is turned off
+fn get_encryption_key_for_new_local_save(app: &mut App) -> Result<Option<Vec<u8>>, String> {
+    if !app.config.encrypt_local_saves {
+        return Ok(None);
+    }
+    match get_local_save_encryption_key(app) {
+        Some(encryption_key) => Ok(Some(encryption_key)),
+        None => Err("Cannot encrypt local save, encryption key not found. Please login, or use --encryption-key or --passphrase".to_string()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloudData {
+    pub id: u64,
+    pub created_at: String,
+    pub user_id: String,
+    pub board_data: String,
+    pub nonce: String,
+    pub save_id: usize,
+}
+
+enum PasswordStatus {
+    Strong,
+    MissingUppercase,
+    MissingLowercase,
+    MissingNumber,
+    MissingSpecialChar,
+    TooShort,
+    TooLong,
+}
+
+fn check_for_safe_password(check_password: &str) -> PasswordStatus {
+    let mut password_status = PasswordStatus::Strong;
+    if check_password.len() < MIN_PASSWORD_LENGTH {
+        password_status = PasswordStatus::TooShort;
     }
     if !check_password.chars().any(|c| c.is_uppercase()) {
         password_status = PasswordStatus::MissingUppercase;
@@ -1668,38 +2249,74 @@ fn check_for_safe_password(check_password: &str) -> PasswordStatus {
     password_status
 }
 
+/// Cloud saves carry the schema version next to the boards so they can be migrated like local saves
 fn encrypt_save(boards: &Boards, key: &[u8]) -> Result<(String, String), String> {
-    let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
-    let boards_json = serde_json::to_string(&boards);
+    let boards_json = serde_json::to_value(boards);
     if boards_json.is_err() {
         return Err("Error serializing boards".to_string());
     }
-    let boards_json = boards_json.unwrap();
+    let mut boards_json = boards_json.unwrap();
+    if let Some(boards_json) = boards_json.as_object_mut() {
+        boards_json.insert(
+            "schema_version".to_string(),
+            Value::from(SAVE_FILE_SCHEMA_VERSION),
+        );
+    }
+    encrypt_data(boards_json.to_string().as_bytes(), key)
+}
+
+fn decrypt_save(
+    encrypted_boards: String,
+    key: &[u8],
+    encoded_nonce: &str,
+    date_time_format: DateTimeFormat,
+) -> Result<Boards, String> {
+    let decrypted_board_data = decrypt_data(&encrypted_boards, key, encoded_nonce)?;
+    let decrypted_board_data = String::from_utf8(decrypted_board_data);
+    if decrypted_board_data.is_err() {
+        return Err("Error converting decrypted boards to string".to_string());
+    }
+    let decrypted_board_data = decrypted_board_data.unwrap();
+    let boards_json = serde_json::from_str(&decrypted_board_data);
+    if boards_json.is_err() {
+        return Err("Error deserializing boards".to_string());
+    }
+    let mut boards_json = boards_json.unwrap();
+    migrate_save(&mut boards_json, date_time_format)?;
+    let boards = serde_json::from_value(boards_json);
+    if boards.is_err() {
+        return Err("Error deserializing boards".to_string());
+    }
+    Ok(boards.unwrap())
+}
+
+/// Encrypts the data with AES-256-GCM, returns the base64 encoded data and nonce
+pub(crate) fn encrypt_data(data: &[u8], key: &[u8]) -> Result<(String, String), String> {
+    let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
     let key = Key::<Aes256Gcm>::from_slice(key);
     let cipher = Aes256Gcm::new(key);
     let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
     let nonce_vec = nonce.to_vec();
     let nonce_encoded = base64_engine.encode(nonce_vec);
-    let encrypted_boards = cipher.encrypt(&nonce, boards_json.as_bytes());
-    if encrypted_boards.is_err() {
-        return Err("Error encrypting boards".to_string());
+    let encrypted_data = cipher.encrypt(&nonce, data);
+    if encrypted_data.is_err() {
+        return Err("Error encrypting data".to_string());
     }
-    let encrypted_boards = encrypted_boards.unwrap();
-    let encoded_boards = base64_engine.encode(encrypted_boards);
-    Ok((encoded_boards, nonce_encoded))
+    let encoded_data = base64_engine.encode(encrypted_data.unwrap());
+    Ok((encoded_data, nonce_encoded))
 }
 
-fn decrypt_save(
-    encrypted_boards: String,
+pub(crate) fn decrypt_data(
+    encoded_data: &str,
     key: &[u8],
     encoded_nonce: &str,
-) -> Result<Boards, String> {
+) -> Result<Vec<u8>, String> {
     let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
-    let encrypted_boards = base64_engine.decode(encrypted_boards);
-    if encrypted_boards.is_err() {
-        return Err("Error decoding boards".to_string());
+    let encrypted_data = base64_engine.decode(encoded_data);
+    if encrypted_data.is_err() {
+        return Err("Error decoding data".to_string());
     }
-    let encrypted_boards = encrypted_boards.unwrap();
+    let encrypted_data = encrypted_data.unwrap();
     let key = Key::<Aes256Gcm>::from_slice(key);
     let cipher = Aes256Gcm::new(key);
     let nonce = base64_engine.decode(encoded_nonce);
@@ -1708,21 +2325,11 @@ fn decrypt_save(
     }
     let nonce = nonce.unwrap();
     let nonce = GenericArray::from_slice(&nonce);
-    let decrypted_board_data = cipher.decrypt(nonce, encrypted_boards.as_slice());
-    if decrypted_board_data.is_err() {
-        return Err("Error decrypting boards".to_string());
+    let decrypted_data = cipher.decrypt(nonce, encrypted_data.as_slice());
+    if decrypted_data.is_err() {
+        return Err("Error decrypting data".to_string());
     }
-    let decrypted_board_data = decrypted_board_data.unwrap();
-    let decrypted_board_data = String::from_utf8(decrypted_board_data);
-    if decrypted_board_data.is_err() {
-        return Err("Error converting decrypted boards to string".to_string());
-    }
-    let decrypted_board_data = decrypted_board_data.unwrap();
-    let boards = serde_json::from_str(&decrypted_board_data);
-    if boards.is_err() {
-        return Err("Error deserializing boards".to_string());
-    }
-    Ok(boards.unwrap())
+    Ok(decrypted_data.unwrap())
 }
 
 pub fn save_user_encryption_key(key: &[u8]) -> Result<String> {
@@ -1738,7 +2345,7 @@ pub fn save_user_encryption_key(key: &[u8]) -> Result<String> {
     }
 }
 
-fn get_user_encryption_key(encryption_key_from_arguments: Option<String>) -> Result<Vec<u8>> {
+pub fn get_user_encryption_key(encryption_key_from_arguments: Option<String>) -> Result<Vec<u8>> {
     let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
     if let Some(encryption_key_from_arguments) = encryption_key_from_arguments {
         let decoded_key = base64_engine.decode(encryption_key_from_arguments);
@@ -1772,27 +2379,403 @@ pub fn generate_new_encryption_key() -> Vec<u8> {
     Aes256Gcm::generate_key(&mut OsRng).to_vec()
 }
 
-pub async fn get_all_save_ids_for_user(user_id: String, access_token: &str) -> Result<Vec<usize>> {
-    let client = reqwest::Client::new();
-    let response = client
-        .get(format!(
-            "{}/rest/v1/user_data?user_id=eq.{}&select=save_id",
-            SUPABASE_URL, user_id
+/// Derives the AES-256-GCM key from a passphrase with Argon2id so the key file
+/// can be recreated from the passphrase and the per user salt
+pub fn derive_encryption_key_from_passphrase(passphrase: &str, salt: &[u8]) -> Result<Vec<u8>> {
+    let mut key = [0u8; 32];
+    let derive_status = Argon2::default().hash_password_into(passphrase.as_bytes(), salt, &mut key);
+    if let Err(e) = derive_status {
+        Err(anyhow!(
+            "Error deriving encryption key from passphrase: {}",
+            e
         ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .header("Range", "0-9")
-        .send()
+    } else {
+        Ok(key.to_vec())
+    }
+}
+
+/// The last cloud save this device synced or loaded is the common base for merging
+fn set_last_synced_save_id(app: &mut App, save_id: usize) {
+    app.state.last_synced_save_id = Some(save_id);
+    let last_synced_save_path = get_config_dir().map(|mut last_synced_save_path| {
+        last_synced_save_path.push(LAST_SYNCED_SAVE_FILE_NAME);
+        last_synced_save_path
+    });
+    match last_synced_save_path {
+        Ok(last_synced_save_path) => {
+            if let Err(e) = std::fs::write(last_synced_save_path, save_id.to_string()) {
+                warn!("Could not write last synced save id: {}", e);
+            }
+        }
+        Err(e) => warn!("Could not write last synced save id: {}", e),
+    }
+}
+
+fn get_last_synced_save_id() -> Option<usize> {
+    let mut last_synced_save_path = get_config_dir().ok()?;
+    last_synced_save_path.push(LAST_SYNCED_SAVE_FILE_NAME);
+    let save_id = std::fs::read_to_string(last_synced_save_path).ok()?;
+    save_id.trim().parse::<usize>().ok()
+}
+
+/// Syncs that could not reach the backend are queued and retried with backoff, anything
+/// else is reported as an error
+fn handle_failed_sync(app: &mut App, err: &eyre::Report) {
+    if is_connection_error(err) {
+        queue_failed_sync(app, &err.to_string());
+    } else {
+        error!("Error syncing local data: {}", err);
+        send_error_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Error syncing local data: {}", err),
+        );
+        set_sync_error(app, &err.to_string());
+    }
+}
+
+fn queue_failed_sync(app: &mut App, reason: &str) {
+    let attempts = app
+        .state
+        .queued_sync
+        .as_ref()
+        .map_or(1, |queued_sync| queued_sync.attempts.saturating_add(1));
+    let retry_delay = get_sync_retry_delay(attempts);
+    warn!(
+        "Could not reach the sync backend ({}), retrying in {} seconds",
+        reason,
+        retry_delay.as_secs()
+    );
+    send_warning_toast(
+        &mut app.widgets.toast_widget,
+        &format!(
+            "Could not reach the sync backend, retrying in {} seconds",
+            retry_delay.as_secs()
+        ),
+    );
+    app.state.queued_sync = Some(QueuedSync {
+        attempts,
+        next_retry: Instant::now() + retry_delay,
+    });
+    match get_config_dir() {
+        Ok(mut sync_queue_path) => {
+            sync_queue_path.push(SYNC_QUEUE_FILE_NAME);
+            if let Err(e) = std::fs::write(sync_queue_path, attempts.to_string()) {
+                warn!("Could not write sync queue: {}", e);
+            }
+        }
+        Err(e) => warn!("Could not write sync queue: {}", e),
+    }
+}
+
+fn get_sync_retry_delay(attempts: u32) -> Duration {
+    let backoff = 2_u64.saturating_pow(attempts.saturating_sub(1));
+    Duration::from_secs(
+        SYNC_RETRY_BASE_DELAY
+            .saturating_mul(backoff)
+            .min(MAX_SYNC_RETRY_DELAY),
+    )
+}
+
+/// A sync queued in a previous session is retried as soon as the app can sync again
+fn get_queued_sync() -> Option<QueuedSync> {
+    let mut sync_queue_path = get_config_dir().ok()?;
+    sync_queue_path.push(SYNC_QUEUE_FILE_NAME);
+    let attempts = std::fs::read_to_string(sync_queue_path).ok()?;
+    Some(QueuedSync {
+        attempts: attempts.trim().parse::<u32>().unwrap_or(1),
+        next_retry: Instant::now(),
+    })
+}
+
+fn clear_queued_sync(app: &mut App) {
+    if app.state.queued_sync.take().is_none() {
+        return;
+    }
+    if let Ok(mut sync_queue_path) = get_config_dir() {
+        sync_queue_path.push(SYNC_QUEUE_FILE_NAME);
+        if let Err(e) = std::fs::remove_file(sync_queue_path) {
+            warn!("Could not remove sync queue: {}", e);
+        }
+    }
+}
+
+fn set_sync_error(app: &mut App, error: &str) {
+    clear_queued_sync(app);
+    app.state.last_sync_error = Some(error.to_string());
+}
+
+fn set_synced(app: &mut App) {
+    clear_queued_sync(app);
+    app.state.last_sync_error = None;
+    app.state.last_synced_boards = Some(app.boards.clone());
+}
+
+/// Merges cloud saves made on other devices since the last sync into the local boards.
+/// Returns false when nothing should be uploaded, either because the local boards already
+/// match the latest cloud save or because the conflict popup was opened for the user
+fn merge_cloud_changes(app: &mut App<'_>, cloud_saves: &[CloudData], key: &[u8]) -> bool {
+    let latest_save = cloud_saves
+        .iter()
+        .max_by_key(|cloud_save| cloud_save.save_id);
+    let (base_save_id, latest_save) = match (app.state.last_synced_save_id, latest_save) {
+        (Some(base_save_id), Some(latest_save)) if latest_save.save_id > base_save_id => {
+            (base_save_id, latest_save)
+        }
+        _ => return true,
+    };
+    let base_save = cloud_saves
+        .iter()
+        .find(|cloud_save| cloud_save.save_id == base_save_id);
+    if base_save.is_none() {
+        warn!(
+            "cloud_save_{} no longer exists, syncing without merging cloud_save_{}",
+            base_save_id, latest_save.save_id
+        );
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "Could not merge cloud_save_{}, its base save was deleted",
+                latest_save.save_id
+            ),
+        );
+        return true;
+    }
+    let base_save = base_save.unwrap();
+    let date_time_format = app.config.date_time_format;
+    let base_boards = decrypt_save(
+        base_save.board_data.clone(),
+        key,
+        &base_save.nonce,
+        date_time_format,
+    );
+    let latest_boards = decrypt_save(
+        latest_save.board_data.clone(),
+        key,
+        &latest_save.nonce,
+        date_time_format,
+    );
+    if base_boards.is_err() || latest_boards.is_err() {
+        warn!(
+            "Could not decrypt cloud saves for merging, syncing without merging cloud_save_{}",
+            latest_save.save_id
+        );
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "Could not merge cloud_save_{}, it could not be decrypted",
+                latest_save.save_id
+            ),
+        );
+        return true;
+    }
+    let (base_boards, latest_boards) = (base_boards.unwrap(), latest_boards.unwrap());
+    let merge_result = merge_boards(&base_boards, &app.boards, &latest_boards, &HashMap::new());
+    if !merge_result.conflicts.is_empty() {
+        warn!(
+            "{} conflicts with cloud_save_{}",
+            merge_result.conflicts.len(),
+            latest_save.save_id
+        );
+        send_warning_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "{} conflicts with cloud_save_{}, resolve them to finish syncing",
+                merge_result.conflicts.len(),
+                latest_save.save_id
+            ),
+        );
+        app.state.pending_merge = Some(PendingMerge::new(
+            base_boards,
+            app.boards.clone(),
+            latest_boards,
+            MergeSource::CloudSave(latest_save.save_id),
+        ));
+        app.set_popup(PopUp::ResolveMergeConflicts);
+        return false;
+    }
+    if merge_result.boards != app.boards {
+        info!("Merged changes from cloud_save_{}", latest_save.save_id);
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!("Merged changes from cloud_save_{}", latest_save.save_id),
+        );
+        app.boards.set_boards(merge_result.boards.clone());
+        app.action_history_manager.reset();
+        refresh_visible_boards_and_cards(app);
+    }
+    if merge_result.boards == latest_boards {
+        info!("Already up to date with cloud_save_{}", latest_save.save_id);
+        send_info_toast(
+            &mut app.widgets.toast_widget,
+            &format!(
+                "👍 Already up to date with cloud_save_{}",
+                latest_save.save_id
+            ),
+        );
+        set_last_synced_save_id(app, latest_save.save_id);
+        set_synced(app);
+        return false;
+    }
+    true
+}
+
+pub fn save_encryption_key_salt_locally(salt: &[u8]) -> Result<String> {
+    let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let mut salt_path = get_config_dir().map_err(|e| anyhow!(e))?;
+    salt_path.push(ENCRYPTION_KEY_SALT_FILE_NAME);
+    std::fs::write(&salt_path, base64_engine.encode(salt))?;
+    Ok(salt_path.to_str().unwrap().to_string())
+}
+
+pub fn get_local_encryption_key_salt() -> Result<Vec<u8>> {
+    let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let mut salt_path = get_config_dir().map_err(|e| anyhow!(e))?;
+    salt_path.push(ENCRYPTION_KEY_SALT_FILE_NAME);
+    if !salt_path.exists() {
+        return Err(anyhow!(
+            "Encryption key salt not found, please login to fetch it"
+        ));
+    }
+    let encoded_salt = std::fs::read_to_string(&salt_path)?;
+    let salt = base64_engine.decode(encoded_salt.trim())?;
+    Ok(salt)
+}
+
+/// The salt is stored in the user metadata next to the cloud saves, a new one
+/// is generated and uploaded if the user does not have one yet
+pub async fn get_or_create_encryption_key_salt(
+    sync_backend: &impl SyncBackend,
+    access_token: &str,
+    cli_mode: bool,
+) -> Result<Vec<u8>> {
+    let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    let user_data_response = sync_backend.get_user(access_token).await?;
+    let user_data_status = user_data_response.status;
+    let user_data_body = user_data_response.json::<serde_json::Value>()?;
+    if user_data_status != StatusCode::OK {
+        if cli_mode {
+            print_debug(&format!(
+                "status code {}, response body: {:?}",
+                user_data_status, user_data_body
+            ));
+        } else {
+            debug!(
+                "status code {}, response body: {:?}",
+                user_data_status, user_data_body
+            );
+        }
+        return Err(anyhow!("Error retrieving user data"));
+    }
+    let existing_salt = user_data_body
+        .get("user_metadata")
+        .and_then(|metadata| metadata.get(ENCRYPTION_KEY_SALT_METADATA_KEY))
+        .and_then(|salt| salt.as_str());
+    if let Some(existing_salt) = existing_salt {
+        return Ok(base64_engine.decode(existing_salt)?);
+    }
+
+    let mut salt = vec![0u8; ENCRYPTION_KEY_SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    let response = sync_backend
+        .update_user(
+            access_token,
+            json!({
+                "data": {
+                    ENCRYPTION_KEY_SALT_METADATA_KEY: base64_engine.encode(&salt)
+                }
+            }),
+        )
+        .await?;
+    let status = response.status;
+    if status != StatusCode::OK {
+        let body = response.body;
+        if cli_mode {
+            print_debug(&format!(
+                "status code {}, response body: {:?}",
+                status, body
+            ));
+        } else {
+            debug!("status code {}, response body: {:?}", status, body);
+        }
+        return Err(anyhow!("Error saving encryption key salt"));
+    }
+    if cli_mode {
+        print_info("Generated a new encryption key salt");
+    } else {
+        info!("Generated a new encryption key salt");
+    }
+    Ok(salt)
+}
+
+/// Fetches the salt for the logged in user and derives the encryption key from
+/// the passphrase, the salt is cached locally so auto login can decrypt the
+/// refresh token before logging in
+async fn set_passphrase_derived_encryption_key(app: &mut App<'_>, access_token: &str) {
+    let passphrase = match app.state.encryption_passphrase.clone() {
+        Some(passphrase) => passphrase,
+        None => return,
+    };
+    let sync_backend = ConfiguredSyncBackend::from_config(&app.config);
+    let derived_key =
+        derive_passphrase_encryption_key(&sync_backend, access_token, &passphrase).await;
+    apply_passphrase_derived_encryption_key(app, derived_key);
+}
+
+/// Does the slow part of deriving the key (fetching the salt and argon2) without the app,
+/// returns the base64 encoded key or the error to show
+async fn derive_passphrase_encryption_key(
+    sync_backend: &impl SyncBackend,
+    access_token: &str,
+    passphrase: &str,
+) -> Result<String, String> {
+    let salt = match get_or_create_encryption_key_salt(sync_backend, access_token, false).await {
+        Ok(salt) => salt,
+        Err(e) => {
+            error!("Error getting encryption key salt: {}", e);
+            return Err(
+                "Error getting encryption key salt, cloud saves cannot be decrypted".to_string(),
+            );
+        }
+    };
+    if let Err(e) = save_encryption_key_salt_locally(&salt) {
+        warn!("Could not cache encryption key salt: {}", e);
+    }
+    match derive_encryption_key_from_passphrase(passphrase, &salt) {
+        Ok(key) => {
+            let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+            info!("Encryption key derived from passphrase");
+            Ok(base64_engine.encode(key))
+        }
+        Err(e) => {
+            error!("{}", e);
+            Err("Error deriving encryption key from passphrase".to_string())
+        }
+    }
+}
+
+fn apply_passphrase_derived_encryption_key(app: &mut App<'_>, derived_key: Result<String, String>) {
+    match derived_key {
+        Ok(derived_key) => app.state.passphrase_derived_encryption_key = Some(derived_key),
+        Err(error_message) => send_error_toast(&mut app.widgets.toast_widget, &error_message),
+    }
+}
+
+pub async fn get_all_save_ids_for_user(
+    sync_backend: &impl SyncBackend,
+    user_id: String,
+    access_token: &str,
+) -> Result<Vec<usize>> {
+    let response = sync_backend
+        .get_saves(access_token, Some(&user_id), Some("save_id"))
         .await;
     if response.is_err() {
         debug!("Error getting save ids: {:?}", response.err());
         return Err(anyhow!("Error getting save ids".to_string()));
     }
     let response = response.unwrap();
-    let status = response.status();
+    let status = response.status;
     if status == StatusCode::OK {
-        let body = response.json::<serde_json::Value>().await;
+        let body = response.json::<serde_json::Value>();
         match body {
             Ok(save_instances) => {
                 let mut save_ids: Vec<usize> = Vec::new();
@@ -1823,35 +2806,28 @@ pub async fn get_all_save_ids_for_user(user_id: String, access_token: &str) -> R
         }
     } else {
         debug!("Status: {:?}", status);
-        debug!("Error getting save ids: {:?}", response.text().await);
+        debug!("Error getting save ids: {:?}", response.body);
         Err(anyhow!("Error getting save ids".to_string()))
     }
 }
 
 pub async fn get_all_save_ids_and_creation_dates_for_user(
+    sync_backend: &impl SyncBackend,
     user_id: String,
     access_token: &str,
     cli_mode: bool,
 ) -> Result<Vec<(usize, String, usize)>> {
-    let client = reqwest::Client::new();
-    let response = client
-        .get(format!(
-            "{}/rest/v1/user_data?user_id=eq.{}&select=save_id,created_at,id",
-            SUPABASE_URL, user_id
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
+    let response = sync_backend
+        .get_saves(access_token, Some(&user_id), Some("save_id,created_at,id"))
         .await;
     if let Err(e) = response {
         debug!("Error getting save ids and created_at: {:?}", e);
         return Err(anyhow!("Error getting save ids".to_string()));
     }
     let response = response.unwrap();
-    let status = response.status();
+    let status = response.status;
     if status == StatusCode::OK {
-        let body = response.json::<serde_json::Value>().await;
+        let body = response.json::<serde_json::Value>();
         match body {
             Ok(save_instances) => {
                 let mut save_ids_and_creation_dates: Vec<(usize, String, usize)> = Vec::new();
@@ -1918,36 +2894,24 @@ pub async fn get_all_save_ids_and_creation_dates_for_user(
     } else {
         if cli_mode {
             print_debug(&format!("Status: {:?}", status));
-            print_debug(&format!(
-                "Error getting save ids: {:?}",
-                response.text().await
-            ));
+            print_debug(&format!("Error getting save ids: {:?}", response.body));
         } else {
             debug!("Status: {:?}", status);
-            debug!("Error getting save ids: {:?}", response.text().await);
+            debug!("Error getting save ids: {:?}", response.body);
         }
         Err(anyhow!("Error getting save ids".to_string()))
     }
 }
 
 pub async fn delete_a_save_from_database(
+    sync_backend: &impl SyncBackend,
     access_token: &str,
     cli_mode: bool,
     save_id: u64,
     save_number: Option<usize>,
 ) -> Result<String> {
-    let client = reqwest::Client::new();
-    let response = client
-        .delete(format!(
-            "{}/rest/v1/user_data?id=eq.{}",
-            SUPABASE_URL, save_id
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
-        .await?;
-    let status = response.status();
+    let response = sync_backend.delete_save(access_token, save_id).await?;
+    let status = response.status;
     if status == StatusCode::NO_CONTENT {
         if cli_mode {
             if save_number.is_some() {
@@ -1964,7 +2928,7 @@ pub async fn delete_a_save_from_database(
             Ok("👍 Cloud save deleted".to_string())
         }
     } else {
-        let body = response.json::<serde_json::Value>().await;
+        let body = response.json::<serde_json::Value>();
         if cli_mode {
             print_error("Error deleting cloud save");
             print_debug(&format!(
@@ -1979,17 +2943,181 @@ pub async fn delete_a_save_from_database(
     }
 }
 
-pub async fn get_user_id_from_database(access_token: &str, cli_mode: bool) -> Result<String> {
-    let user_data_client = reqwest::Client::new();
-    let user_data_response = user_data_client
-        .get(format!("{}/auth/v1/user", SUPABASE_URL))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", access_token))
-        .send()
+pub async fn get_all_cloud_saves_for_user(
+    sync_backend: &impl SyncBackend,
+    user_id: &str,
+    access_token: &str,
+) -> Result<Vec<CloudData>> {
+    let response = sync_backend
+        .get_saves(access_token, Some(user_id), None)
         .await?;
-    let user_data_status = user_data_response.status();
-    let user_data_body = user_data_response.json::<serde_json::Value>().await;
+    let status = response.status;
+    if status == StatusCode::OK {
+        let body = response.json::<Vec<CloudData>>();
+        match body {
+            Ok(cloud_saves) => Ok(cloud_saves),
+            Err(e) => {
+                print_debug(&format!("Error parsing cloud saves: {:?}", e));
+                Err(anyhow!("Error getting cloud saves"))
+            }
+        }
+    } else {
+        print_debug(&format!(
+            "status code {}, response body: {:?}",
+            status, response.body
+        ));
+        Err(anyhow!("Error getting cloud saves"))
+    }
+}
+
+pub async fn update_cloud_save_board_data(
+    sync_backend: &impl SyncBackend,
+    access_token: &str,
+    id: u64,
+    board_data: &str,
+    nonce: &str,
+) -> Result<()> {
+    let response = sync_backend
+        .update_save(
+            access_token,
+            id,
+            json!(
+                {
+                    "board_data": board_data,
+                    "nonce": nonce
+                }
+            ),
+        )
+        .await?;
+    let status = response.status;
+    if status == StatusCode::NO_CONTENT || status == StatusCode::OK {
+        Ok(())
+    } else {
+        print_debug(&format!(
+            "status code {}, response body: {:?}",
+            status, response.body
+        ));
+        Err(anyhow!("Error updating cloud save"))
+    }
+}
+
+/// Re-encrypts every cloud save of the user with the new key and only then replaces the key file.
+/// If any upload or writing the new key fails, the saves updated so far are restored
+pub async fn rotate_encryption_key_for_cloud_saves(
+    sync_backend: &impl SyncBackend,
+    user_id: &str,
+    access_token: &str,
+    old_key: &[u8],
+    new_key: &[u8],
+    save_new_key: bool,
+) -> Result<usize> {
+    let cloud_saves = get_all_cloud_saves_for_user(sync_backend, user_id, access_token).await?;
+    let mut re_encrypted_saves = Vec::new();
+    let date_time_format = get_config(true)
+        .map(|config| config.date_time_format)
+        .unwrap_or_default();
+    for cloud_save in cloud_saves.iter() {
+        let boards = decrypt_save(
+            cloud_save.board_data.clone(),
+            old_key,
+            &cloud_save.nonce,
+            date_time_format,
+        );
+        if let Err(e) = boards {
+            print_debug(&format!(
+                "Error decrypting Cloud_save_{}: {}",
+                cloud_save.save_id, e
+            ));
+            return Err(anyhow!(
+                "Could not decrypt Cloud_save_{} with the current encryption key, nothing was changed",
+                cloud_save.save_id
+            ));
+        }
+        let encrypt_result = encrypt_save(&boards.unwrap(), new_key);
+        if let Err(e) = encrypt_result {
+            print_debug(&format!(
+                "Error encrypting Cloud_save_{}: {}",
+                cloud_save.save_id, e
+            ));
+            return Err(anyhow!(
+                "Could not encrypt Cloud_save_{} with the new encryption key, nothing was changed",
+                cloud_save.save_id
+            ));
+        }
+        re_encrypted_saves.push((cloud_save, encrypt_result.unwrap()));
+    }
+
+    let mut updated_saves = Vec::new();
+    let mut rotation_error = None;
+    for (cloud_save, (board_data, nonce)) in re_encrypted_saves.iter() {
+        print_info(&format!("Re-encrypting Cloud_save_{}", cloud_save.save_id));
+        match update_cloud_save_board_data(
+            sync_backend,
+            access_token,
+            cloud_save.id,
+            board_data,
+            nonce,
+        )
+        .await
+        {
+            Ok(_) => updated_saves.push(*cloud_save),
+            Err(e) => {
+                rotation_error = Some(anyhow!(
+                    "Could not upload Cloud_save_{}: {}",
+                    cloud_save.save_id,
+                    e
+                ));
+                break;
+            }
+        }
+    }
+    if rotation_error.is_none() && save_new_key {
+        if let Err(e) = save_user_encryption_key(new_key) {
+            rotation_error = Some(anyhow!("Could not save the new encryption key: {}", e));
+        }
+    }
+
+    if let Some(rotation_error) = rotation_error {
+        print_warn("Rolling back the save files that were already re-encrypted...");
+        let mut rollback_failed = false;
+        for cloud_save in updated_saves {
+            let rollback_status = update_cloud_save_board_data(
+                sync_backend,
+                access_token,
+                cloud_save.id,
+                &cloud_save.board_data,
+                &cloud_save.nonce,
+            )
+            .await;
+            if let Err(e) = rollback_status {
+                print_error(&format!(
+                    "Could not roll back Cloud_save_{}, it is encrypted with the new key that was not saved",
+                    cloud_save.save_id
+                ));
+                print_debug(&format!("Error: {:?}", e));
+                rollback_failed = true;
+            }
+        }
+        if rollback_failed {
+            let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+            print_warn(&format!(
+                "Keep this new encryption key to access the save files that could not be rolled back: {}",
+                base64_engine.encode(new_key)
+            ));
+        }
+        return Err(rotation_error);
+    }
+    Ok(re_encrypted_saves.len())
+}
+
+pub async fn get_user_id_from_database(
+    sync_backend: &impl SyncBackend,
+    access_token: &str,
+    cli_mode: bool,
+) -> Result<String> {
+    let user_data_response = sync_backend.get_user(access_token).await?;
+    let user_data_status = user_data_response.status;
+    let user_data_body = user_data_response.json::<serde_json::Value>();
     if user_data_status != StatusCode::OK {
         if cli_mode {
             print_error("Error retrieving user data");
@@ -2034,27 +3162,12 @@ pub async fn get_user_id_from_database(access_token: &str, cli_mode: bool) -> Re
 }
 
 pub async fn login_for_user(
+    sync_backend: &impl SyncBackend,
     email_id: &str,
     password: &str,
     cli_mode: bool,
 ) -> Result<(String, String, String), String> {
-    let request_body = json!(
-        {
-            "email": email_id,
-            "password": password
-        }
-    );
-    let client = reqwest::Client::new();
-    let response = client
-        .post(format!(
-            "{}/auth/v1/token?grant_type=password",
-            SUPABASE_URL
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .body(request_body.to_string())
-        .send()
-        .await;
+    let response = sync_backend.login(email_id, password).await;
     if let Err(e) = response {
         if cli_mode {
             print_debug(&format!("Error logging in: {}", e));
@@ -2066,8 +3179,8 @@ pub async fn login_for_user(
         return Err("Error logging in, Something went wrong, please try again later".to_string());
     }
     let response = response.unwrap();
-    let status = response.status();
-    let body = response.json::<serde_json::Value>().await;
+    let status = response.status;
+    let body = response.json::<serde_json::Value>();
     if status == StatusCode::OK {
         match body {
             Ok(body) => {
@@ -2083,9 +3196,10 @@ pub async fn login_for_user(
                             info!("🚀 Login successful");
                             debug!("Access token: {}", access_token);
                         }
-                        let user_id = get_user_id_from_database(access_token, cli_mode)
-                            .await
-                            .unwrap_or_else(|_| "Error getting user id".to_string());
+                        let user_id =
+                            get_user_id_from_database(sync_backend, access_token, cli_mode)
+                                .await
+                                .unwrap_or_else(|_| "Error getting user id".to_string());
                         Ok((access_token.to_string(), user_id))
                     }
                     None => {
@@ -2339,32 +3453,19 @@ async fn delete_refresh_token_from_disk() -> Result<()> {
     Ok(())
 }
 
-async fn refresh_access_token(refresh_token: &str) -> Result<(String, String, String), String> {
-    let request_body = json!(
-        {
-            "grant_type": "refresh_token",
-            "refresh_token": refresh_token
-        }
-    );
-    let client = reqwest::Client::new();
-    let response = client
-        .post(format!(
-            "{}/auth/v1/token?grant_type=refresh_token",
-            SUPABASE_URL
-        ))
-        .header("apikey", SUPABASE_ANON_KEY)
-        .header("Content-Type", "application/json")
-        .body(request_body.to_string())
-        .send()
-        .await;
+async fn refresh_access_token(
+    sync_backend: &impl SyncBackend,
+    refresh_token: &str,
+) -> Result<(String, String, String), String> {
+    let response = sync_backend.refresh_session(refresh_token).await;
     if let Err(e) = response {
         debug!("Error logging in: {}", e);
         error!("Error logging in, Something went wrong, please try again later");
         return Err("Error logging in, Something went wrong, please try again later".to_string());
     }
     let response = response.unwrap();
-    let status = response.status();
-    let body = response.json::<serde_json::Value>().await;
+    let status = response.status;
+    let body = response.json::<serde_json::Value>();
     if status == StatusCode::OK {
         match body {
             Ok(body) => {
@@ -2375,7 +3476,7 @@ async fn refresh_access_token(refresh_token: &str) -> Result<(String, String, St
                         let access_token = access_token.as_str().unwrap();
                         info!("🚀 Login successful");
                         debug!("Access token: {}", access_token);
-                        let user_id = get_user_id_from_database(access_token, false)
+                        let user_id = get_user_id_from_database(sync_backend, access_token, false)
                             .await
                             .unwrap_or_else(|_| "Error getting user id".to_string());
                         Ok((access_token.to_string(), user_id))
@@ -2450,12 +3551,13 @@ async fn refresh_access_token(refresh_token: &str) -> Result<(String, String, St
 }
 
 async fn test_refresh_token_on_disk(
+    sync_backend: &impl SyncBackend,
     encryption_key_from_arguments: Option<String>,
 ) -> Result<UserLoginData> {
     let (refresh_token, email_id) =
         get_refresh_token_from_disk(encryption_key_from_arguments.clone())?;
     debug!("refresh_token: {:?}", refresh_token);
-    let status = refresh_access_token(&refresh_token).await;
+    let status = refresh_access_token(sync_backend, &refresh_token).await;
     if status.is_err() {
         return Err(anyhow!(status.err().unwrap()));
     }
@@ -2476,3 +3578,117 @@ async fn test_refresh_token_on_disk(
     };
     Ok(user_data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{app::kanban::Board, io::sync_backend::MemoryBackend};
+
+    fn test_boards() -> Boards {
+        Boards::from(vec![
+            Board::new("Todo", "Things to do"),
+            Board::new("Done", "Things that are done"),
+        ])
+    }
+
+    #[tokio::test]
+    async fn login_returns_tokens_and_user_id() {
+        let backend = MemoryBackend::new("user@example.com", "Password-123");
+        let (access_token, user_id, refresh_token) =
+            login_for_user(&backend, "user@example.com", "Password-123", false)
+                .await
+                .unwrap();
+        assert_eq!(access_token, MemoryBackend::ACCESS_TOKEN);
+        assert_eq!(refresh_token, MemoryBackend::REFRESH_TOKEN);
+        assert_eq!(user_id, MemoryBackend::USER_ID);
+        assert!(login_for_user(&backend, "user@example.com", "wrong", false)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn encryption_key_salt_is_created_once() {
+        let backend = MemoryBackend::new("user@example.com", "Password-123");
+        let salt = get_or_create_encryption_key_salt(&backend, MemoryBackend::ACCESS_TOKEN, false)
+            .await
+            .unwrap();
+        assert_eq!(salt.len(), ENCRYPTION_KEY_SALT_LENGTH);
+        let second_salt =
+            get_or_create_encryption_key_salt(&backend, MemoryBackend::ACCESS_TOKEN, false)
+                .await
+                .unwrap();
+        assert_eq!(salt, second_salt);
+    }
+
+    #[tokio::test]
+    async fn rotating_the_key_re_encrypts_every_cloud_save() {
+        let backend = MemoryBackend::new("user@example.com", "Password-123");
+        let old_key = generate_new_encryption_key();
+        let new_key = generate_new_encryption_key();
+        let boards = test_boards();
+        for save_id in 1..=2 {
+            let (board_data, nonce) = encrypt_save(&boards, &old_key).unwrap();
+            backend
+                .create_save(
+                    MemoryBackend::ACCESS_TOKEN,
+                    json!({ "save_id": save_id, "board_data": board_data, "nonce": nonce }),
+                )
+                .await
+                .unwrap();
+        }
+
+        let re_encrypted_saves = rotate_encryption_key_for_cloud_saves(
+            &backend,
+            MemoryBackend::USER_ID,
+            MemoryBackend::ACCESS_TOKEN,
+            &old_key,
+            &new_key,
+            false,
+        )
+        .await
+        .unwrap();
+        assert_eq!(re_encrypted_saves, 2);
+
+        for save in backend.saves() {
+            let board_data = save["board_data"].as_str().unwrap().to_string();
+            let nonce = save["nonce"].as_str().unwrap();
+            assert!(decrypt_save(
+                board_data.clone(),
+                &old_key,
+                nonce,
+                DateTimeFormat::default()
+            )
+            .is_err());
+            let decrypted_boards =
+                decrypt_save(board_data, &new_key, nonce, DateTimeFormat::default()).unwrap();
+            assert_eq!(decrypted_boards, boards);
+        }
+    }
+
+    #[tokio::test]
+    async fn rotating_with_the_wrong_key_changes_nothing() {
+        let backend = MemoryBackend::new("user@example.com", "Password-123");
+        let old_key = generate_new_encryption_key();
+        let (board_data, nonce) = encrypt_save(&test_boards(), &old_key).unwrap();
+        backend
+            .create_save(
+                MemoryBackend::ACCESS_TOKEN,
+                json!({ "save_id": 1, "board_data": board_data, "nonce": nonce }),
+            )
+            .await
+            .unwrap();
+        let saves_before = backend.saves();
+
+        let rotation = rotate_encryption_key_for_cloud_saves(
+            &backend,
+            MemoryBackend::USER_ID,
+            MemoryBackend::ACCESS_TOKEN,
+            &generate_new_encryption_key(),
+            &generate_new_encryption_key(),
+            false,
+        )
+        .await;
+        assert!(rotation.is_err());
+        assert_eq!(backend.saves(), saves_before);
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{
+    app::DateTimeFormat,
+    constants::{FIELD_NA, FIELD_NOT_SET, SAVE_FILE_SCHEMA_VERSION},
+    util::parse_date_time,
+};
+use chrono::{DateTime, Local};
+use log::warn;
+use serde_json::Value;
+
+/// Saves written before the schema version was recorded
+const UNVERSIONED_SCHEMA_VERSION: u32 = 1;
+
+/// Migrations get the configured date format as saves used to store dates formatted with it
+type Migration = fn(&mut Value, DateTimeFormat, &mut MigrationReport) -> Result<(), String>;
+
+/// Result of migrating a save, data that could not be carried over is listed so it can be
+/// shown to the user
+#[derive(Debug, Default, PartialEq)]
+pub struct MigrationReport {
+    pub from_schema_version: u32,
+    /// Dates that could not be read, with the card they belonged to and their original value
+    pub dropped_dates: Vec<String>,
+}
+
+/// Each entry upgrades a save from the schema version at its index + 1 to the next one.
+/// Append a migration here and bump SAVE_FILE_SCHEMA_VERSION when the save format changes
+const MIGRATIONS: [Migration; 2] = [migrate_v1_to_v2, migrate_v2_to_v3];
+
+pub fn get_schema_version(save: &Value) -> Result<u32, String> {
+    match save.get("schema_version") {
+        None => Ok(UNVERSIONED_SCHEMA_VERSION),
+        Some(schema_version) => schema_version
+            .as_u64()
+            .and_then(|schema_version| u32::try_from(schema_version).ok())
+            .filter(|schema_version| *schema_version >= UNVERSIONED_SCHEMA_VERSION)
+            .ok_or_else(|| format!("Invalid save file schema version {}", schema_version)),
+    }
+}
+
+/// Upgrades the save to SAVE_FILE_SCHEMA_VERSION, the report has the version it started at.
+/// Saves from a newer version are refused rather than loaded with fields missing
+pub fn migrate_save(
+    save: &mut Value,
+    date_time_format: DateTimeFormat,
+) -> Result<MigrationReport, String> {
+    let schema_version = get_schema_version(save)?;
+    if schema_version > SAVE_FILE_SCHEMA_VERSION {
+        return Err(format!(
+            "Save file uses schema version {} but this version of {} only supports up to {}, please update to open it",
+            schema_version,
+            env!("CARGO_PKG_NAME"),
+            SAVE_FILE_SCHEMA_VERSION
+        ));
+    }
+    let mut migration_report = MigrationReport {
+        from_schema_version: schema_version,
+        dropped_dates: vec![],
+    };
+    for (index, migration) in MIGRATIONS
+        .iter()
+        .enumerate()
+        .skip((schema_version - UNVERSIONED_SCHEMA_VERSION) as usize)
+    {
+        let from_version = index as u32 + UNVERSIONED_SCHEMA_VERSION;
+        migration(save, date_time_format, &mut migration_report).map_err(|e| {
+            format!(
+                "Error migrating save file from schema version {} to {}: {}",
+                from_version,
+                from_version + 1,
+                e
+            )
+        })?;
+    }
+    if let Some(save) = save.as_object_mut() {
+        save.insert(
+            "schema_version".to_string(),
+            Value::from(SAVE_FILE_SCHEMA_VERSION),
+        );
+    }
+    Ok(migration_report)
+}
+
+/// Early versions stored the cards of a board as a plain list, before Cards became a struct
+fn migrate_v1_to_v2(
+    save: &mut Value,
+    _: DateTimeFormat,
+    _: &mut MigrationReport,
+) -> Result<(), String> {
+    for board in get_boards_mut(save)? {
+        if board["cards"].is_array() {
+            let cards = board["cards"].take();
+            board["cards"] = serde_json::json!({ "cards": cards });
+        }
+    }
+    Ok(())
+}
+
+/// Card dates were strings in the configured date format, or "Not Set" and "N/A" when missing.
+/// They are now RFC 3339 timestamps or null, dates that cannot be parsed are dropped and reported
+fn migrate_v2_to_v3(
+    save: &mut Value,
+    date_time_format: DateTimeFormat,
+    migration_report: &mut MigrationReport,
+) -> Result<(), String> {
+    for board in get_boards_mut(save)? {
+        let board_name = board["name"].as_str().unwrap_or_default().to_string();
+        let cards = board["cards"]["cards"]
+            .as_array_mut()
+            .ok_or("board cards is not an array")?;
+        for card in cards.iter_mut().filter_map(|card| card.as_object_mut()) {
+            let card_name = card
+                .get("name")
+                .and_then(|name| name.as_str())
+                .unwrap_or_default()
+                .to_string();
+            for field in [
+                "date_completed",
+                "date_created",
+                "date_modified",
+                "due_date",
+            ] {
+                let date = card
+                    .get(field)
+                    .and_then(|date| date.as_str())
+                    .map(|date| date.trim().to_string())
+                    .filter(|date| !date.is_empty() && date != FIELD_NOT_SET && date != FIELD_NA);
+                let timestamp: Option<DateTime<Local>> = date
+                    .as_ref()
+                    .and_then(|date| parse_date_time(date, date_time_format).ok());
+                if let (Some(date), None) = (&date, timestamp) {
+                    let dropped_date = format!(
+                        "{} '{}' of card '{}' in board '{}'",
+                        field, date, card_name, board_name
+                    );
+                    warn!("Dropped unreadable {} while migrating", dropped_date);
+                    migration_report.dropped_dates.push(dropped_date);
+                }
+                card.insert(field.to_string(), serde_json::to_value(timestamp).unwrap());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_boards_mut(save: &mut Value) -> Result<&mut Vec<Value>, String> {
+    save.get_mut("boards")
+        .and_then(|boards| boards.as_array_mut())
+        .ok_or("boards is not an array".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn unversioned_card(due_date: &str) -> Value {
+        json!({
+            "id": [3, 4],
+            "name": "Card",
+            "date_created": "01/01/2024-10:00:00",
+            "date_modified": "Not Set",
+            "date_completed": "N/A",
+            "due_date": due_date
+        })
+    }
+
+    #[test]
+    fn reads_schema_version() {
+        assert_eq!(get_schema_version(&json!({ "boards": [] })), Ok(1));
+        assert_eq!(
+            get_schema_version(&json!({ "boards": [], "schema_version": 2 })),
+            Ok(2)
+        );
+        assert!(get_schema_version(&json!({ "schema_version": 0 })).is_err());
+        assert!(get_schema_version(&json!({ "schema_version": "3" })).is_err());
+    }
+
+    #[test]
+    fn migrates_unversioned_save_to_current_schema() {
+        let mut save = json!({
+            "boards": [{
+                "id": [1, 2],
+                "name": "Todo",
+                "cards": [unversioned_card("02/01/2024-12:30:00"), unversioned_card("someday")]
+            }]
+        });
+        let migration_report = migrate_save(&mut save, DateTimeFormat::DayMonthYearTime).unwrap();
+        assert_eq!(migration_report.from_schema_version, 1);
+        assert_eq!(save["schema_version"], SAVE_FILE_SCHEMA_VERSION);
+        let cards = save["boards"][0]["cards"]["cards"].as_array().unwrap();
+        assert_eq!(cards.len(), 2);
+        let due_date: DateTime<Local> =
+            serde_json::from_value(cards[0]["due_date"].clone()).unwrap();
+        assert_eq!(
+            due_date.format("%Y-%m-%d %H:%M").to_string(),
+            "2024-01-02 12:30"
+        );
+        assert!(cards[0]["date_created"].is_string());
+        assert!(cards[0]["date_modified"].is_null());
+        assert!(cards[0]["date_completed"].is_null());
+        // Dates that cannot be parsed are dropped rather than failing the whole save
+        assert!(cards[1]["due_date"].is_null());
+        assert_eq!(migration_report.dropped_dates.len(), 1);
+    }
+
+    #[test]
+    fn dropped_dates_are_reported_with_their_original_value() {
+        let mut card = unversioned_card("next tuesday");
+        card["name"] = json!("Plan trip");
+        card["date_modified"] = json!("");
+        let mut save = json!({
+            "boards": [{ "name": "Holidays", "cards": [card] }]
+        });
+        let migration_report = migrate_save(&mut save, DateTimeFormat::DayMonthYearTime).unwrap();
+        // Missing dates ("Not Set", "N/A" or empty) are not reported
+        assert_eq!(
+            migration_report.dropped_dates,
+            vec!["due_date 'next tuesday' of card 'Plan trip' in board 'Holidays'".to_string()]
+        );
+        let card = &save["boards"][0]["cards"]["cards"][0];
+        assert!(card["due_date"].is_null());
+        assert!(card["date_modified"].is_null());
+        assert!(card["date_created"].is_string());
+    }
+
+    #[test]
+    fn dates_are_read_with_the_configured_format() {
+        let mut save = json!({
+            "boards": [{ "cards": [unversioned_card("01/02/2024")] }]
+        });
+        migrate_save(&mut save, DateTimeFormat::MonthDayYear).unwrap();
+        let due_date: DateTime<Local> =
+            serde_json::from_value(save["boards"][0]["cards"]["cards"][0]["due_date"].clone())
+                .unwrap();
+        assert_eq!(due_date.format("%Y-%m-%d").to_string(), "2024-01-02");
+    }
+
+    #[test]
+    fn current_save_is_left_alone() {
+        let mut save = json!({
+            "boards": [{ "cards": { "cards": [] } }],
+            "schema_version": SAVE_FILE_SCHEMA_VERSION
+        });
+        let original_save = save.clone();
+        assert_eq!(
+            migrate_save(&mut save, DateTimeFormat::default()),
+            Ok(MigrationReport {
+                from_schema_version: SAVE_FILE_SCHEMA_VERSION,
+                dropped_dates: vec![],
+            })
+        );
+        assert_eq!(save, original_save);
+    }
+
+    #[test]
+    fn newer_and_malformed_saves_are_refused() {
+        let mut newer_save = json!({
+            "boards": [],
+            "schema_version": SAVE_FILE_SCHEMA_VERSION + 1
+        });
+        assert!(migrate_save(&mut newer_save, DateTimeFormat::default()).is_err());
+        let mut malformed_save = json!({ "boards": "not a list" });
+        assert!(migrate_save(&mut malformed_save, DateTimeFormat::default()).is_err());
+    }
+}
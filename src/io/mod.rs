@@ -1,12 +1,22 @@
 pub mod data_handler;
+pub mod export;
+pub mod html;
+pub mod ical;
 pub mod io_handler;
 pub mod logger;
+pub mod migration;
+pub mod sync_backend;
+pub mod todo_txt;
+
+use export::ExportFormat;
 
 #[derive(Debug, Clone)]
 pub enum IoEvent {
     AutoSave,
+    CheckSaveDirectory,
     DeleteCloudSave,
     DeleteLocalSave,
+    ExportBoards(ExportFormat),
     GetCloudData,
     Initialize,
     LoadCloudPreview,
@@ -15,10 +25,12 @@ pub enum IoEvent {
     LoadSaveLocal,
     Login(String, String),
     Logout,
+    PruneLocalSaves,
     ResetPassword(String, String, String),
     ResetVisibleBoardsandCards,
     SaveLocalData,
     SendResetPasswordEmail(String),
     SignUp(String, String, String),
     SyncLocalData,
+    UnlockLocalSaves(String),
 }
@@ -0,0 +1,1043 @@
+use crate::{
+    app::AppConfig,
+    constants::{
+        FILESYSTEM_SYNC_METADATA_FILE_NAME, FILESYSTEM_SYNC_SAVE_FILE_PREFIX,
+        FILESYSTEM_SYNC_USER_ID,
+    },
+    io::data_handler::write_local_file,
+};
+use eyre::{anyhow, Result};
+use log::{debug, info, warn};
+use reqwest::{Method, RequestBuilder, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+};
+use strum::{Display, EnumString};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, EnumString, Display)]
+pub enum SyncBackendType {
+    #[default]
+    Supabase,
+    Filesystem,
+}
+
+/// Status and raw body of a sync backend response, the body is read eagerly so
+/// callers can inspect it as many times as needed
+#[derive(Debug, Clone)]
+pub struct SyncBackendResponse {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl SyncBackendResponse {
+    pub fn json<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_str(&self.body)
+    }
+}
+
+/// Reads the access token out of the url a password recovery link redirects to
+fn access_token_from_recovery_url(url: &str) -> Option<&str> {
+    let (_, access_token) = url.split_once("access_token=")?;
+    let access_token = access_token.split('&').next().unwrap_or_default();
+    if access_token.is_empty() {
+        None
+    } else {
+        Some(access_token)
+    }
+}
+
+/// True when the sync backend could not be reached at all, as opposed to the backend
+/// rejecting the request
+pub fn is_connection_error(err: &eyre::Report) -> bool {
+    if let Some(request_error) = err.downcast_ref::<reqwest::Error>() {
+        return request_error.is_connect()
+            || request_error.is_timeout()
+            || request_error.is_request();
+    }
+    err.downcast_ref::<std::io::Error>().is_some()
+}
+
+/// Every call the app makes to the cloud goes through this trait, the default
+/// implementation talks to a Supabase (GoTrue + PostgREST) compatible server
+pub trait SyncBackend: Send + Sync {
+    /// Backends that are not tied to an account can be used without logging in
+    fn requires_login(&self) -> bool {
+        true
+    }
+    fn login(
+        &self,
+        email_id: &str,
+        password: &str,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    fn refresh_session(
+        &self,
+        refresh_token: &str,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    fn logout(
+        &self,
+        access_token: &str,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    fn signup(
+        &self,
+        email_id: &str,
+        password: &str,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    fn send_password_recovery(
+        &self,
+        email_id: &str,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    /// Follows the link from a password recovery email, returns the access token it grants
+    fn verify_password_recovery_link(
+        &self,
+        reset_link: &str,
+    ) -> impl Future<Output = Result<String>> + Send;
+    fn get_user(
+        &self,
+        access_token: &str,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    /// Updates the logged in user, e.g. `{"password": ...}` or `{"data": {...}}` for metadata
+    fn update_user(
+        &self,
+        access_token: &str,
+        user_data: Value,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    /// Lists the saves visible to the user, optionally filtered by user id and
+    /// limited to the given comma separated columns
+    fn get_saves(
+        &self,
+        access_token: &str,
+        user_id: Option<&str>,
+        columns: Option<&str>,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    fn create_save(
+        &self,
+        access_token: &str,
+        save_data: Value,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    fn update_save(
+        &self,
+        access_token: &str,
+        id: u64,
+        save_data: Value,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+    fn delete_save(
+        &self,
+        access_token: &str,
+        id: u64,
+    ) -> impl Future<Output = Result<SyncBackendResponse>> + Send;
+}
+
+#[derive(Debug, Clone)]
+pub struct SupabaseBackend {
+    client: reqwest::Client,
+    url: String,
+    anon_key: String,
+}
+
+impl SupabaseBackend {
+    pub fn new(url: &str, anon_key: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.trim_end_matches('/').to_string(),
+            anon_key: anon_key.to_string(),
+        }
+    }
+
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self::new(&config.sync_backend_url, &config.sync_backend_anon_key)
+    }
+
+    fn request(&self, method: Method, path: &str, access_token: Option<&str>) -> RequestBuilder {
+        let request = self
+            .client
+            .request(method, format!("{}{}", self.url, path))
+            .header("apikey", &self.anon_key)
+            .header("Content-Type", "application/json");
+        if let Some(access_token) = access_token {
+            request.header("Authorization", format!("Bearer {}", access_token))
+        } else {
+            request
+        }
+    }
+
+    async fn send(request: RequestBuilder) -> Result<SyncBackendResponse> {
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+        Ok(SyncBackendResponse { status, body })
+    }
+}
+
+impl SyncBackend for SupabaseBackend {
+    async fn login(&self, email_id: &str, password: &str) -> Result<SyncBackendResponse> {
+        let request_body = json!({ "email": email_id, "password": password });
+        Self::send(
+            self.request(Method::POST, "/auth/v1/token?grant_type=password", None)
+                .body(request_body.to_string()),
+        )
+        .await
+    }
+
+    async fn refresh_session(&self, refresh_token: &str) -> Result<SyncBackendResponse> {
+        let request_body = json!({ "refresh_token": refresh_token });
+        Self::send(
+            self.request(
+                Method::POST,
+                "/auth/v1/token?grant_type=refresh_token",
+                None,
+            )
+            .body(request_body.to_string()),
+        )
+        .await
+    }
+
+    async fn logout(&self, access_token: &str) -> Result<SyncBackendResponse> {
+        Self::send(self.request(Method::POST, "/auth/v1/logout", Some(access_token))).await
+    }
+
+    async fn signup(&self, email_id: &str, password: &str) -> Result<SyncBackendResponse> {
+        let request_body = json!({ "email": email_id, "password": password });
+        Self::send(
+            self.request(Method::POST, "/auth/v1/signup", None)
+                .body(request_body.to_string()),
+        )
+        .await
+    }
+
+    async fn send_password_recovery(&self, email_id: &str) -> Result<SyncBackendResponse> {
+        let request_body = json!({ "email": email_id });
+        Self::send(
+            self.request(Method::POST, "/auth/v1/recover", None)
+                .body(request_body.to_string()),
+        )
+        .await
+    }
+
+    async fn verify_password_recovery_link(&self, reset_link: &str) -> Result<String> {
+        // The link redirects to the site url with the token in it, that redirect is
+        // expected to fail locally so the token is read from the url of the error
+        match self.client.get(reset_link).send().await {
+            Ok(_) => Err(anyhow!(
+                "The reset link did not redirect with an access token"
+            )),
+            Err(e) => e
+                .url()
+                .and_then(|error_url| access_token_from_recovery_url(error_url.as_str()))
+                .map(|access_token| access_token.to_string())
+                .ok_or_else(|| anyhow!("The reset link did not contain an access token")),
+        }
+    }
+
+    async fn get_user(&self, access_token: &str) -> Result<SyncBackendResponse> {
+        Self::send(self.request(Method::GET, "/auth/v1/user", Some(access_token))).await
+    }
+
+    async fn update_user(
+        &self,
+        access_token: &str,
+        user_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        Self::send(
+            self.request(Method::PUT, "/auth/v1/user", Some(access_token))
+                .body(user_data.to_string()),
+        )
+        .await
+    }
+
+    async fn get_saves(
+        &self,
+        access_token: &str,
+        user_id: Option<&str>,
+        columns: Option<&str>,
+    ) -> Result<SyncBackendResponse> {
+        let mut filters = Vec::new();
+        if let Some(user_id) = user_id {
+            filters.push(format!("user_id=eq.{}", user_id));
+        }
+        if let Some(columns) = columns {
+            filters.push(format!("select={}", columns));
+        }
+        let path = if filters.is_empty() {
+            "/rest/v1/user_data".to_string()
+        } else {
+            format!("/rest/v1/user_data?{}", filters.join("&"))
+        };
+        Self::send(self.request(Method::GET, &path, Some(access_token))).await
+    }
+
+    async fn create_save(
+        &self,
+        access_token: &str,
+        save_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        Self::send(
+            self.request(Method::POST, "/rest/v1/user_data", Some(access_token))
+                .body(save_data.to_string()),
+        )
+        .await
+    }
+
+    async fn update_save(
+        &self,
+        access_token: &str,
+        id: u64,
+        save_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        Self::send(
+            self.request(
+                Method::PATCH,
+                &format!("/rest/v1/user_data?id=eq.{}", id),
+                Some(access_token),
+            )
+            .body(save_data.to_string()),
+        )
+        .await
+    }
+
+    async fn delete_save(&self, access_token: &str, id: u64) -> Result<SyncBackendResponse> {
+        Self::send(self.request(
+            Method::DELETE,
+            &format!("/rest/v1/user_data?id=eq.{}", id),
+            Some(access_token),
+        ))
+        .await
+    }
+}
+
+/// Stores every save as a json file in a user chosen directory (e.g. a Syncthing
+/// folder or a git repository), there are no accounts so the auth calls fail
+#[derive(Debug, Clone)]
+pub struct FilesystemBackend {
+    directory: PathBuf,
+    git_commit: bool,
+}
+
+impl FilesystemBackend {
+    pub fn new(directory: &Path, git_commit: bool) -> Self {
+        Self {
+            directory: directory.to_path_buf(),
+            git_commit,
+        }
+    }
+
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self::new(&config.sync_directory, config.git_commit_on_sync)
+    }
+
+    fn check_directory(&self) -> Result<()> {
+        if self.directory.as_os_str().is_empty() {
+            return Err(anyhow!("Sync directory is not set"));
+        }
+        if !self.directory.is_dir() {
+            // Reported as an io error so a network share that is not mounted is retried
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Sync directory {} does not exist", self.directory.display()),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    fn save_file_path(&self, id: u64) -> PathBuf {
+        self.directory
+            .join(format!("{}{}.json", FILESYSTEM_SYNC_SAVE_FILE_PREFIX, id))
+    }
+
+    fn metadata_file_path(&self) -> PathBuf {
+        self.directory.join(FILESYSTEM_SYNC_METADATA_FILE_NAME)
+    }
+
+    fn read_json_file(path: &Path) -> Result<Value> {
+        let file_contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&file_contents)?)
+    }
+
+    /// Written atomically, a torn file would otherwise be synced to every other device
+    fn write_json_file(path: &Path, value: &Value) -> Result<()> {
+        write_local_file(path, serde_json::to_string_pretty(value)?, None)
+            .map_err(|e| anyhow!("Could not write {}: {}", path.display(), e))
+    }
+
+    fn read_metadata(&self) -> Result<Value> {
+        let metadata_file_path = self.metadata_file_path();
+        if metadata_file_path.exists() {
+            Self::read_json_file(&metadata_file_path)
+        } else {
+            Ok(json!({}))
+        }
+    }
+
+    fn read_saves(&self) -> Result<Vec<Value>> {
+        let mut saves = Vec::new();
+        for entry in std::fs::read_dir(&self.directory)? {
+            let file_name = entry?.file_name().to_string_lossy().to_string();
+            if !file_name.starts_with(FILESYSTEM_SYNC_SAVE_FILE_PREFIX)
+                || !file_name.ends_with(".json")
+            {
+                continue;
+            }
+            match Self::read_json_file(&self.directory.join(&file_name)) {
+                Ok(save) => saves.push(save),
+                Err(e) => warn!("Skipping unreadable sync file {}: {}", file_name, e),
+            }
+        }
+        saves.sort_by_key(|save| save["save_id"].as_u64().unwrap_or(0));
+        Ok(saves)
+    }
+
+    /// Commits only the given file so unrelated changes in the directory are left alone,
+    /// a failing git command is logged but does not fail the sync
+    async fn git_commit(&self, file_path: &Path, message: &str) {
+        if !self.git_commit {
+            return;
+        }
+        let mut git_add = tokio::process::Command::new("git");
+        git_add
+            .arg("-C")
+            .arg(&self.directory)
+            .args(["add", "-A", "--"])
+            .arg(file_path);
+        let mut git_commit = tokio::process::Command::new("git");
+        git_commit
+            .arg("-C")
+            .arg(&self.directory)
+            .args(["commit", "-m", message, "--"])
+            .arg(file_path);
+        for mut git_command in [git_add, git_commit] {
+            match git_command.output().await {
+                Ok(output) if output.status.success() => {}
+                Ok(output) => {
+                    warn!(
+                        "git failed in the sync directory: {}",
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    );
+                    return;
+                }
+                Err(e) => {
+                    warn!("Could not run git in the sync directory: {}", e);
+                    return;
+                }
+            }
+        }
+        info!("Committed {} in the sync directory", file_path.display());
+    }
+
+    fn unsupported(action: &str) -> Result<SyncBackendResponse> {
+        Err(anyhow!(
+            "{} is not supported by the filesystem sync backend",
+            action
+        ))
+    }
+
+    fn response(status: StatusCode, body: &Value) -> Result<SyncBackendResponse> {
+        Ok(SyncBackendResponse {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+impl SyncBackend for FilesystemBackend {
+    fn requires_login(&self) -> bool {
+        false
+    }
+
+    async fn login(&self, _email_id: &str, _password: &str) -> Result<SyncBackendResponse> {
+        Self::unsupported("Login")
+    }
+
+    async fn refresh_session(&self, _refresh_token: &str) -> Result<SyncBackendResponse> {
+        Self::unsupported("Refreshing a session")
+    }
+
+    async fn logout(&self, _access_token: &str) -> Result<SyncBackendResponse> {
+        Self::unsupported("Logout")
+    }
+
+    async fn signup(&self, _email_id: &str, _password: &str) -> Result<SyncBackendResponse> {
+        Self::unsupported("Signup")
+    }
+
+    async fn send_password_recovery(&self, _email_id: &str) -> Result<SyncBackendResponse> {
+        Self::unsupported("Password recovery")
+    }
+
+    async fn verify_password_recovery_link(&self, _reset_link: &str) -> Result<String> {
+        Self::unsupported("Password recovery").map(|_| String::new())
+    }
+
+    async fn get_user(&self, _access_token: &str) -> Result<SyncBackendResponse> {
+        self.check_directory()?;
+        let user_metadata = self.read_metadata()?;
+        Self::response(
+            StatusCode::OK,
+            &json!({ "id": FILESYSTEM_SYNC_USER_ID, "user_metadata": user_metadata }),
+        )
+    }
+
+    async fn update_user(
+        &self,
+        access_token: &str,
+        user_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        self.check_directory()?;
+        let new_metadata = user_data.get("data").and_then(|data| data.as_object());
+        if new_metadata.is_none() {
+            return Self::unsupported("Updating anything but user metadata");
+        }
+        let mut user_metadata = self.read_metadata()?;
+        if !user_metadata.is_object() {
+            user_metadata = json!({});
+        }
+        for (key, value) in new_metadata.unwrap() {
+            user_metadata[key] = value.clone();
+        }
+        let metadata_file_path = self.metadata_file_path();
+        Self::write_json_file(&metadata_file_path, &user_metadata)?;
+        self.git_commit(&metadata_file_path, "Update sync metadata")
+            .await;
+        self.get_user(access_token).await
+    }
+
+    async fn get_saves(
+        &self,
+        _access_token: &str,
+        _user_id: Option<&str>,
+        _columns: Option<&str>,
+    ) -> Result<SyncBackendResponse> {
+        self.check_directory()?;
+        let saves = self.read_saves()?;
+        debug!(
+            "Found {} saves in {}",
+            saves.len(),
+            self.directory.display()
+        );
+        Self::response(StatusCode::OK, &Value::Array(saves))
+    }
+
+    async fn create_save(
+        &self,
+        _access_token: &str,
+        mut save_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        self.check_directory()?;
+        let save_id = save_data["save_id"].as_u64();
+        if save_id.is_none() {
+            return Err(anyhow!("Save is missing a save_id"));
+        }
+        let save_id = save_id.unwrap();
+        let save_file_path = self.save_file_path(save_id);
+        if save_file_path.exists() {
+            return Self::response(
+                StatusCode::CONFLICT,
+                &json!({ "message": format!("Save {} already exists", save_id) }),
+            );
+        }
+        // Same shape as a row in the hosted database so CloudData can parse it
+        save_data["id"] = json!(save_id);
+        save_data["user_id"] = json!(FILESYSTEM_SYNC_USER_ID);
+        save_data["created_at"] = json!(chrono::Utc::now()
+            .format("%Y-%m-%dT%H:%M:%S%.6f+00:00")
+            .to_string());
+        Self::write_json_file(&save_file_path, &save_data)?;
+        self.git_commit(&save_file_path, &format!("Add cloud save {}", save_id))
+            .await;
+        Self::response(StatusCode::CREATED, &save_data)
+    }
+
+    async fn update_save(
+        &self,
+        _access_token: &str,
+        id: u64,
+        save_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        self.check_directory()?;
+        let save_file_path = self.save_file_path(id);
+        if !save_file_path.exists() {
+            return Self::response(
+                StatusCode::NOT_FOUND,
+                &json!({ "message": format!("Save {} does not exist", id) }),
+            );
+        }
+        let mut existing_save = Self::read_json_file(&save_file_path)?;
+        if let Some(save_data) = save_data.as_object() {
+            for (key, value) in save_data {
+                existing_save[key] = value.clone();
+            }
+        }
+        Self::write_json_file(&save_file_path, &existing_save)?;
+        self.git_commit(&save_file_path, &format!("Update cloud save {}", id))
+            .await;
+        Self::response(StatusCode::NO_CONTENT, &Value::Null)
+    }
+
+    async fn delete_save(&self, _access_token: &str, id: u64) -> Result<SyncBackendResponse> {
+        self.check_directory()?;
+        let save_file_path = self.save_file_path(id);
+        if !save_file_path.exists() {
+            return Self::response(
+                StatusCode::NOT_FOUND,
+                &json!({ "message": format!("Save {} does not exist", id) }),
+            );
+        }
+        std::fs::remove_file(&save_file_path)?;
+        self.git_commit(&save_file_path, &format!("Delete cloud save {}", id))
+            .await;
+        Self::response(StatusCode::NO_CONTENT, &Value::Null)
+    }
+}
+
+/// The backend selected in the config, lets callers stay generic over [`SyncBackend`]
+#[derive(Debug, Clone)]
+pub enum ConfiguredSyncBackend {
+    Supabase(SupabaseBackend),
+    Filesystem(FilesystemBackend),
+}
+
+impl ConfiguredSyncBackend {
+    pub fn from_config(config: &AppConfig) -> Self {
+        match config.sync_backend_type {
+            SyncBackendType::Supabase => Self::Supabase(SupabaseBackend::from_config(config)),
+            SyncBackendType::Filesystem => Self::Filesystem(FilesystemBackend::from_config(config)),
+        }
+    }
+}
+
+impl SyncBackend for ConfiguredSyncBackend {
+    fn requires_login(&self) -> bool {
+        match self {
+            Self::Supabase(backend) => backend.requires_login(),
+            Self::Filesystem(backend) => backend.requires_login(),
+        }
+    }
+
+    async fn login(&self, email_id: &str, password: &str) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.login(email_id, password).await,
+            Self::Filesystem(backend) => backend.login(email_id, password).await,
+        }
+    }
+
+    async fn refresh_session(&self, refresh_token: &str) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.refresh_session(refresh_token).await,
+            Self::Filesystem(backend) => backend.refresh_session(refresh_token).await,
+        }
+    }
+
+    async fn logout(&self, access_token: &str) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.logout(access_token).await,
+            Self::Filesystem(backend) => backend.logout(access_token).await,
+        }
+    }
+
+    async fn signup(&self, email_id: &str, password: &str) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.signup(email_id, password).await,
+            Self::Filesystem(backend) => backend.signup(email_id, password).await,
+        }
+    }
+
+    async fn send_password_recovery(&self, email_id: &str) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.send_password_recovery(email_id).await,
+            Self::Filesystem(backend) => backend.send_password_recovery(email_id).await,
+        }
+    }
+
+    async fn verify_password_recovery_link(&self, reset_link: &str) -> Result<String> {
+        match self {
+            Self::Supabase(backend) => backend.verify_password_recovery_link(reset_link).await,
+            Self::Filesystem(backend) => backend.verify_password_recovery_link(reset_link).await,
+        }
+    }
+
+    async fn get_user(&self, access_token: &str) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.get_user(access_token).await,
+            Self::Filesystem(backend) => backend.get_user(access_token).await,
+        }
+    }
+
+    async fn update_user(
+        &self,
+        access_token: &str,
+        user_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.update_user(access_token, user_data).await,
+            Self::Filesystem(backend) => backend.update_user(access_token, user_data).await,
+        }
+    }
+
+    async fn get_saves(
+        &self,
+        access_token: &str,
+        user_id: Option<&str>,
+        columns: Option<&str>,
+    ) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.get_saves(access_token, user_id, columns).await,
+            Self::Filesystem(backend) => backend.get_saves(access_token, user_id, columns).await,
+        }
+    }
+
+    async fn create_save(
+        &self,
+        access_token: &str,
+        save_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.create_save(access_token, save_data).await,
+            Self::Filesystem(backend) => backend.create_save(access_token, save_data).await,
+        }
+    }
+
+    async fn update_save(
+        &self,
+        access_token: &str,
+        id: u64,
+        save_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.update_save(access_token, id, save_data).await,
+            Self::Filesystem(backend) => backend.update_save(access_token, id, save_data).await,
+        }
+    }
+
+    async fn delete_save(&self, access_token: &str, id: u64) -> Result<SyncBackendResponse> {
+        match self {
+            Self::Supabase(backend) => backend.delete_save(access_token, id).await,
+            Self::Filesystem(backend) => backend.delete_save(access_token, id).await,
+        }
+    }
+}
+
+/// Keeps one account and its saves in memory so the sync code can be tested
+/// without a server or a sync directory
+#[cfg(test)]
+pub struct MemoryBackend {
+    state: parking_lot::Mutex<MemoryBackendState>,
+}
+
+#[cfg(test)]
+struct MemoryBackendState {
+    email_id: String,
+    password: String,
+    user_metadata: Value,
+    saves: Vec<Value>,
+}
+
+#[cfg(test)]
+impl MemoryBackend {
+    pub const ACCESS_TOKEN: &'static str = "memory-access-token";
+    pub const REFRESH_TOKEN: &'static str = "memory-refresh-token";
+    pub const USER_ID: &'static str = "memory-user";
+
+    pub fn new(email_id: &str, password: &str) -> Self {
+        Self {
+            state: parking_lot::Mutex::new(MemoryBackendState {
+                email_id: email_id.to_string(),
+                password: password.to_string(),
+                user_metadata: json!({}),
+                saves: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn recovery_link() -> String {
+        format!(
+            "memory://recover#access_token={}&expires_at=0",
+            Self::ACCESS_TOKEN
+        )
+    }
+
+    pub fn saves(&self) -> Vec<Value> {
+        self.state.lock().saves.clone()
+    }
+
+    fn response(status: StatusCode, body: &Value) -> Result<SyncBackendResponse> {
+        Ok(SyncBackendResponse {
+            status,
+            body: body.to_string(),
+        })
+    }
+
+    fn unauthorized(access_token: &str) -> Option<Result<SyncBackendResponse>> {
+        if access_token == Self::ACCESS_TOKEN {
+            None
+        } else {
+            Some(Self::response(
+                StatusCode::UNAUTHORIZED,
+                &json!({ "message": "invalid access token" }),
+            ))
+        }
+    }
+
+    fn session() -> Result<SyncBackendResponse> {
+        Self::response(
+            StatusCode::OK,
+            &json!({
+                "access_token": Self::ACCESS_TOKEN,
+                "refresh_token": Self::REFRESH_TOKEN,
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+impl SyncBackend for MemoryBackend {
+    async fn login(&self, email_id: &str, password: &str) -> Result<SyncBackendResponse> {
+        let state = self.state.lock();
+        if state.email_id == email_id && state.password == password {
+            Self::session()
+        } else {
+            Self::response(
+                StatusCode::BAD_REQUEST,
+                &json!({ "error_description": "Invalid login credentials" }),
+            )
+        }
+    }
+
+    async fn refresh_session(&self, refresh_token: &str) -> Result<SyncBackendResponse> {
+        if refresh_token == Self::REFRESH_TOKEN {
+            Self::session()
+        } else {
+            Self::response(StatusCode::BAD_REQUEST, &json!({}))
+        }
+    }
+
+    async fn logout(&self, access_token: &str) -> Result<SyncBackendResponse> {
+        Self::unauthorized(access_token)
+            .unwrap_or_else(|| Self::response(StatusCode::NO_CONTENT, &Value::Null))
+    }
+
+    async fn signup(&self, _email_id: &str, _password: &str) -> Result<SyncBackendResponse> {
+        Self::response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            &json!({ "msg": "User already registered" }),
+        )
+    }
+
+    async fn send_password_recovery(&self, _email_id: &str) -> Result<SyncBackendResponse> {
+        Self::response(StatusCode::OK, &json!({}))
+    }
+
+    async fn verify_password_recovery_link(&self, reset_link: &str) -> Result<String> {
+        access_token_from_recovery_url(reset_link)
+            .map(|access_token| access_token.to_string())
+            .ok_or_else(|| anyhow!("The reset link did not contain an access token"))
+    }
+
+    async fn get_user(&self, access_token: &str) -> Result<SyncBackendResponse> {
+        if let Some(response) = Self::unauthorized(access_token) {
+            return response;
+        }
+        let state = self.state.lock();
+        Self::response(
+            StatusCode::OK,
+            &json!({ "id": Self::USER_ID, "user_metadata": state.user_metadata }),
+        )
+    }
+
+    async fn update_user(
+        &self,
+        access_token: &str,
+        user_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        if let Some(response) = Self::unauthorized(access_token) {
+            return response;
+        }
+        {
+            let mut state = self.state.lock();
+            if let Some(password) = user_data.get("password").and_then(|p| p.as_str()) {
+                if state.password == password {
+                    return Self::response(
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        &json!({ "msg": "New password should be different from the old password" }),
+                    );
+                }
+                state.password = password.to_string();
+            }
+            if let Some(new_metadata) = user_data.get("data").and_then(|data| data.as_object()) {
+                for (key, value) in new_metadata {
+                    state.user_metadata[key] = value.clone();
+                }
+            }
+        }
+        self.get_user(access_token).await
+    }
+
+    async fn get_saves(
+        &self,
+        access_token: &str,
+        _user_id: Option<&str>,
+        _columns: Option<&str>,
+    ) -> Result<SyncBackendResponse> {
+        if let Some(response) = Self::unauthorized(access_token) {
+            return response;
+        }
+        Self::response(StatusCode::OK, &Value::Array(self.saves()))
+    }
+
+    async fn create_save(
+        &self,
+        access_token: &str,
+        mut save_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        if let Some(response) = Self::unauthorized(access_token) {
+            return response;
+        }
+        let mut state = self.state.lock();
+        save_data["id"] = json!(state.saves.len() as u64 + 1);
+        save_data["user_id"] = json!(Self::USER_ID);
+        save_data["created_at"] = json!("2024-01-01T00:00:00.000000+00:00");
+        state.saves.push(save_data.clone());
+        Self::response(StatusCode::CREATED, &save_data)
+    }
+
+    async fn update_save(
+        &self,
+        access_token: &str,
+        id: u64,
+        save_data: Value,
+    ) -> Result<SyncBackendResponse> {
+        if let Some(response) = Self::unauthorized(access_token) {
+            return response;
+        }
+        let mut state = self.state.lock();
+        let existing_save = state
+            .saves
+            .iter_mut()
+            .find(|save| save["id"].as_u64() == Some(id));
+        if let Some(existing_save) = existing_save {
+            if let Some(save_data) = save_data.as_object() {
+                for (key, value) in save_data {
+                    existing_save[key] = value.clone();
+                }
+            }
+            Self::response(StatusCode::NO_CONTENT, &Value::Null)
+        } else {
+            Self::response(StatusCode::NOT_FOUND, &json!({}))
+        }
+    }
+
+    async fn delete_save(&self, access_token: &str, id: u64) -> Result<SyncBackendResponse> {
+        if let Some(response) = Self::unauthorized(access_token) {
+            return response;
+        }
+        self.state
+            .lock()
+            .saves
+            .retain(|save| save["id"].as_u64() != Some(id));
+        Self::response(StatusCode::NO_CONTENT, &Value::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_access_token_from_recovery_url() {
+        assert_eq!(
+            access_token_from_recovery_url(
+                "http://localhost:3000/#access_token=abc.def&expires_at=1&type=recovery"
+            ),
+            Some("abc.def")
+        );
+        assert_eq!(
+            access_token_from_recovery_url("http://localhost:3000/#access_token=abc"),
+            Some("abc")
+        );
+        assert_eq!(
+            access_token_from_recovery_url("http://localhost:3000/#error=expired"),
+            None
+        );
+        assert_eq!(
+            access_token_from_recovery_url("http://localhost:3000/#access_token=&expires_at=1"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn password_reset_goes_through_the_backend() {
+        let backend = MemoryBackend::new("user@example.com", "Old-password1");
+        let access_token = backend
+            .verify_password_recovery_link(&MemoryBackend::recovery_link())
+            .await
+            .unwrap();
+        let response = backend
+            .update_user(&access_token, json!({ "password": "New-password1" }))
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let old_login = backend
+            .login("user@example.com", "Old-password1")
+            .await
+            .unwrap();
+        assert_eq!(old_login.status, StatusCode::BAD_REQUEST);
+        let new_login = backend
+            .login("user@example.com", "New-password1")
+            .await
+            .unwrap();
+        assert_eq!(new_login.status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn filesystem_backend_writes_saves_and_metadata_in_place() {
+        let directory =
+            std::env::temp_dir().join(format!("rust_kanban_test_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let backend = FilesystemBackend::new(&directory, false);
+
+        backend
+            .create_save("", json!({ "save_id": 1, "board_data": "first" }))
+            .await
+            .unwrap();
+        backend
+            .update_save("", 1, json!({ "board_data": "second" }))
+            .await
+            .unwrap();
+        backend
+            .update_user("", json!({ "data": { "salt": "abc" } }))
+            .await
+            .unwrap();
+
+        let mut file_names = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect::<Vec<String>>();
+        file_names.sort();
+        let mut expected_file_names = vec![
+            FILESYSTEM_SYNC_METADATA_FILE_NAME.to_string(),
+            backend
+                .save_file_path(1)
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .to_string(),
+        ];
+        expected_file_names.sort();
+        assert_eq!(file_names, expected_file_names);
+        let saves = backend.read_saves().unwrap();
+        assert_eq!(saves[0]["board_data"], "second");
+        assert_eq!(backend.read_metadata().unwrap()["salt"], "abc");
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn filesystem_backend_rejects_password_recovery() {
+        let backend = FilesystemBackend::new(Path::new(""), false);
+        assert!(backend
+            .verify_password_recovery_link(&MemoryBackend::recovery_link())
+            .await
+            .is_err());
+    }
+}
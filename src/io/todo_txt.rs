@@ -0,0 +1,339 @@
+use crate::app::kanban::{Boards, Card, CardPriority, CardStatus};
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone};
+
+const TODO_TXT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// One todo.txt line per card, the board becomes the +project and the tags become @contexts.
+/// Completed cards keep their priority as a pri: tag since todo.txt drops it on completion
+pub fn boards_to_todo_txt(boards: &Boards) -> String {
+    let mut lines = vec![];
+    for board in boards.get_boards() {
+        for card in board.cards.get_all_cards() {
+            lines.push(card_to_todo_txt(card, &board.name));
+        }
+    }
+    let mut todo_txt = lines.join("\n");
+    if !todo_txt.is_empty() {
+        todo_txt.push('\n');
+    }
+    todo_txt
+}
+
+fn card_to_todo_txt(card: &Card, board_name: &str) -> String {
+    let mut words = vec![];
+    if card.card_status == CardStatus::Complete {
+        words.push("x".to_string());
+        // A completion date is required when a creation date follows it
+        let date_completed = card
+            .date_completed
+            .or(card.date_modified)
+            .unwrap_or(Local::now());
+        words.push(format_todo_txt_date(date_completed));
+    } else {
+        words.push(format!("({})", todo_txt_priority(&card.priority)));
+    }
+    if let Some(date_created) = card.date_created {
+        words.push(format_todo_txt_date(date_created));
+    }
+    words.extend(
+        card.name
+            .split_whitespace()
+            .enumerate()
+            .map(|(index, word)| escape_name_word(word, index == 0)),
+    );
+    let project = to_todo_txt_word(board_name);
+    if !project.is_empty() {
+        words.push(format!("+{}", project));
+    }
+    for tag in &card.tags {
+        let context = to_todo_txt_word(tag);
+        if !context.is_empty() {
+            words.push(format!("@{}", context));
+        }
+    }
+    if let Some(due_date) = card.due_date {
+        words.push(format!("due:{}", format_todo_txt_date(due_date)));
+    }
+    if card.card_status == CardStatus::Complete {
+        words.push(format!("pri:{}", todo_txt_priority(&card.priority)));
+    }
+    words.join(" ")
+}
+
+/// Parses todo.txt lines into new cards, +project tags are dropped as the cards go into the
+/// board chosen for the import. Lines that can not become a card are skipped and returned as
+/// messages so one bad line does not stop the rest from being imported
+pub fn parse_todo_txt(content: &str) -> (Vec<Card>, Vec<String>) {
+    let mut cards = vec![];
+    let mut skipped_lines = vec![];
+    for (line_index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_todo_txt_line(line) {
+            Ok(card) => cards.push(card),
+            Err(err) => skipped_lines.push(format!("Line {}: {}", line_index + 1, err)),
+        }
+    }
+    (cards, skipped_lines)
+}
+
+fn parse_todo_txt_line(line: &str) -> Result<Card, String> {
+    let mut words = line.split_whitespace().peekable();
+    let mut card_status = CardStatus::Active;
+    let mut priority = None;
+    let mut date_completed = None;
+
+    if words.peek() == Some(&"x") {
+        words.next();
+        card_status = CardStatus::Complete;
+        date_completed = words.peek().and_then(|word| parse_todo_txt_date(word));
+        if date_completed.is_some() {
+            words.next();
+        }
+    } else if let Some(parsed_priority) = words.peek().and_then(|word| parse_priority(word)) {
+        priority = Some(parsed_priority);
+        words.next();
+    }
+    let date_created = words.peek().and_then(|word| parse_todo_txt_date(word));
+    if date_created.is_some() {
+        words.next();
+    }
+
+    let mut name_words = vec![];
+    let mut tags = vec![];
+    let mut due_date = None;
+    // Values that do not parse are kept as part of the name, as todo.txt does for any key:value
+    for word in words {
+        let due = word.strip_prefix("due:").and_then(parse_todo_txt_date);
+        let pri = word
+            .strip_prefix("pri:")
+            .and_then(|pri| parse_priority(&format!("({})", pri.to_uppercase())));
+        if let Some(escaped) = word.strip_prefix('\\').filter(|word| !word.is_empty()) {
+            name_words.push(escaped);
+        } else if word.len() > 1 && word.starts_with('+') {
+            continue;
+        } else if let Some(context) = word.strip_prefix('@').filter(|c| !c.is_empty()) {
+            if !tags.contains(&context.to_string()) {
+                tags.push(context.to_string());
+            }
+        } else if due.is_some() {
+            due_date = due;
+        } else if pri.is_some() {
+            priority = pri;
+        } else {
+            name_words.push(word);
+        }
+    }
+    if name_words.is_empty() {
+        return Err("Task has no description".to_string());
+    }
+
+    let mut card = Card::new(
+        &name_words.join(" "),
+        "",
+        due_date,
+        priority.unwrap_or(CardPriority::Low),
+        tags,
+        vec![],
+    );
+    card.card_status = card_status;
+    if card.card_status == CardStatus::Complete {
+        card.date_completed = date_completed.or(Some(Local::now()));
+    }
+    if date_created.is_some() {
+        card.date_created = date_created;
+    }
+    Ok(card)
+}
+
+/// Name words that would be read back as a +project, @context, due: or pri: tag, or as a
+/// creation date at the start of the name, get a leading backslash which the parser removes
+fn escape_name_word(word: &str, is_first_word: bool) -> String {
+    let is_tag = word.len() > 1 && (word.starts_with('+') || word.starts_with('@'));
+    let needs_escape = is_tag
+        || word.starts_with('\\')
+        || word.starts_with("due:")
+        || word.starts_with("pri:")
+        || (is_first_word && parse_todo_txt_date(word).is_some());
+    if needs_escape {
+        format!("\\{}", word)
+    } else {
+        word.to_string()
+    }
+}
+
+fn todo_txt_priority(priority: &CardPriority) -> char {
+    match priority {
+        CardPriority::High => 'A',
+        CardPriority::Medium => 'B',
+        CardPriority::Low => 'C',
+    }
+}
+
+/// (A) is high, (B) medium and everything from (C) down is low
+fn parse_priority(word: &str) -> Option<CardPriority> {
+    let letter = word.strip_prefix('(')?.strip_suffix(')')?;
+    let mut chars = letter.chars();
+    let letter = chars.next()?;
+    if chars.next().is_some() || !letter.is_ascii_uppercase() {
+        return None;
+    }
+    match letter {
+        'A' => Some(CardPriority::High),
+        'B' => Some(CardPriority::Medium),
+        _ => Some(CardPriority::Low),
+    }
+}
+
+fn format_todo_txt_date(date_time: DateTime<Local>) -> String {
+    date_time.format(TODO_TXT_DATE_FORMAT).to_string()
+}
+
+fn parse_todo_txt_date(word: &str) -> Option<DateTime<Local>> {
+    let date = NaiveDate::parse_from_str(word, TODO_TXT_DATE_FORMAT).ok()?;
+    Local
+        .from_local_datetime(&date.and_time(NaiveTime::MIN))
+        .earliest()
+}
+
+/// Projects and contexts can not contain spaces
+fn to_todo_txt_word(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::Board;
+
+    fn date(month: u32, day: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn export(cards: Vec<Card>) -> String {
+        let mut board = Board::new("Side projects", "");
+        for card in cards {
+            board.cards.add_card(card);
+        }
+        boards_to_todo_txt(&Boards::from(vec![board]))
+    }
+
+    #[test]
+    fn active_cards_round_trip() {
+        let mut card = Card::new(
+            "Write the report",
+            "",
+            Some(date(6, 1)),
+            CardPriority::Medium,
+            vec!["work".to_string(), "writing".to_string()],
+            vec![],
+        );
+        card.date_created = Some(date(5, 1));
+        let todo_txt = export(vec![card.clone()]);
+        assert_eq!(
+            todo_txt,
+            "(B) 2024-05-01 Write the report +Side_projects @work @writing due:2024-06-01\n"
+        );
+
+        let (cards, _) = parse_todo_txt(&todo_txt);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].name, card.name);
+        assert_eq!(cards[0].priority, card.priority);
+        assert_eq!(cards[0].tags, card.tags);
+        assert_eq!(cards[0].due_date, card.due_date);
+        assert_eq!(cards[0].date_created, card.date_created);
+        assert_eq!(cards[0].card_status, CardStatus::Active);
+    }
+
+    #[test]
+    fn completed_cards_keep_their_priority_and_dates() {
+        let mut card = Card::new("Ship it", "", None, CardPriority::High, vec![], vec![]);
+        card.card_status = CardStatus::Complete;
+        card.date_created = Some(date(5, 1));
+        card.date_completed = Some(date(5, 3));
+        let todo_txt = export(vec![card]);
+        assert_eq!(
+            todo_txt,
+            "x 2024-05-03 2024-05-01 Ship it +Side_projects pri:A\n"
+        );
+
+        let (cards, _) = parse_todo_txt(&todo_txt);
+        assert_eq!(cards[0].card_status, CardStatus::Complete);
+        assert_eq!(cards[0].priority, CardPriority::High);
+        assert_eq!(cards[0].date_completed, Some(date(5, 3)));
+        assert_eq!(cards[0].date_created, Some(date(5, 1)));
+    }
+
+    #[test]
+    fn parses_lines_from_other_clients() {
+        let (cards, skipped_lines) = parse_todo_txt(
+            "(D) Low priority thing\n\n\
+             Plain task @home @home +Chores\n\
+             x Done without dates\n",
+        );
+        assert!(skipped_lines.is_empty());
+        assert_eq!(cards.len(), 3);
+        assert_eq!(cards[0].priority, CardPriority::Low);
+        assert_eq!(cards[0].name, "Low priority thing");
+        assert_eq!(cards[1].name, "Plain task");
+        assert_eq!(cards[1].tags, vec!["home".to_string()]);
+        assert_eq!(cards[2].card_status, CardStatus::Complete);
+        assert!(cards[2].date_completed.is_some());
+    }
+
+    #[test]
+    fn names_that_look_like_tags_round_trip() {
+        let names = [
+            "Ask @bob about +launch",
+            "Ship it due:soon",
+            "Set pri:x on it",
+            "2024-05-01 retro notes",
+            "Path \\server\\share",
+        ];
+        let cards = names
+            .iter()
+            .map(|name| Card::new(name, "", None, CardPriority::Low, vec![], vec![]))
+            .collect::<Vec<Card>>();
+        let (imported, skipped_lines) = parse_todo_txt(&export(cards));
+        assert!(skipped_lines.is_empty(), "{:?}", skipped_lines);
+        for (card, name) in imported.iter().zip(names) {
+            assert_eq!(card.name, name);
+            assert!(card.tags.is_empty());
+            assert_eq!(card.due_date, None);
+        }
+    }
+
+    #[test]
+    fn unparseable_values_stay_in_the_name() {
+        let (cards, skipped_lines) = parse_todo_txt("Ship it due:soon pri:1 owner:me\n");
+        assert!(skipped_lines.is_empty());
+        assert_eq!(cards[0].name, "Ship it due:soon pri:1 owner:me");
+        assert_eq!(cards[0].due_date, None);
+        assert_eq!(cards[0].priority, CardPriority::Low);
+    }
+
+    #[test]
+    fn lines_without_a_name_are_skipped() {
+        let (cards, skipped_lines) =
+            parse_todo_txt("(A) Fine\n(B) 2024-05-01 @work due:2024-06-01\nx +Chores\nAlso fine\n");
+        assert_eq!(
+            cards
+                .iter()
+                .map(|card| card.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Fine", "Also fine"]
+        );
+        assert_eq!(skipped_lines.len(), 2);
+        assert!(
+            skipped_lines[0].starts_with("Line 2:"),
+            "{}",
+            skipped_lines[0]
+        );
+        assert!(
+            skipped_lines[1].starts_with("Line 3:"),
+            "{}",
+            skipped_lines[1]
+        );
+    }
+}
@@ -1,15 +1,22 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use crossterm::{event::DisableMouseCapture, execute, terminal};
 use eyre::Result;
 use log::LevelFilter;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use rust_kanban::{
     app::App,
-    constants::APP_TITLE,
-    io::{io_handler::IoAsyncHandler, logger, IoEvent},
-    util::{gen_new_key_main, print_error, reset_app_main, start_ui},
+    constants::{APP_TITLE, DEFAULT_REMINDER_WINDOW},
+    io::{
+        export::ExportFormat, io_handler::IoAsyncHandler, logger,
+        sync_backend::ConfiguredSyncBackend, IoEvent,
+    },
+    util::{
+        derive_encryption_key_from_cached_salt, export_main, gen_new_key_main,
+        import_todo_txt_main, migrate_save_file_main, print_error, read_encryption_passphrase,
+        remind_main, reset_app_main, start_ui,
+    },
 };
-use std::{io::stdout, sync::Arc};
+use std::{io::stdout, path::PathBuf, sync::Arc};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -24,8 +31,43 @@ struct CliArgs {
     password: Option<String>,
     #[arg(long)]
     encryption_key: Option<String>,
+    #[arg(long, default_value = "false", conflicts_with = "encryption_key")]
+    passphrase: bool,
+    #[arg(long, conflicts_with_all = ["encryption_key", "passphrase"])]
+    passphrase_fd: Option<i32>,
     #[arg(short, long, default_value = "false")]
     debug_mode: bool,
+    #[arg(long, default_value = "false")]
+    ignore_lock: bool,
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+}
+
+#[derive(Subcommand, Debug)]
+enum CliCommand {
+    /// Upgrades a save file to the current save format in place, keeping a backup of the original
+    Migrate { file: PathBuf },
+    /// Runs the reminders that came due since the last run, meant to be called from cron
+    Remind {
+        /// Minutes to look back when there is no record of a previous run
+        #[arg(long, default_value_t = DEFAULT_REMINDER_WINDOW)]
+        window: u32,
+    },
+    /// Exports all boards of the latest local save
+    Export {
+        #[arg(long, value_enum)]
+        format: ExportFormat,
+        /// Defaults to kanban_export.<extension> in the save directory
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Imports a todo.txt file into a board of the latest local save
+    Import {
+        file: PathBuf,
+        /// Name of the board to add the cards to
+        #[arg(long)]
+        board: String,
+    },
 }
 
 #[tokio::main]
@@ -76,16 +118,23 @@ async fn main() -> Result<()> {
         reset_app_main();
         return Ok(());
     }
-    if args.generate_new_encryption_key {
-        if args.email_id.is_none() || args.password.is_none() {
-            println!();
-            print_error(
-                "Please provide your email id (-e) and password (-p) to reset your encryption key",
-            );
-            println!();
-            return Ok(());
+    let passphrase = if args.encryption_key.is_some() {
+        None
+    } else {
+        match read_encryption_passphrase(args.passphrase, args.passphrase_fd) {
+            Ok(passphrase) => passphrase,
+            Err(err) => {
+                println!();
+                print_error(&err);
+                println!();
+                return Ok(());
+            }
         }
-        gen_new_key_main(args.email_id.unwrap(), args.password.unwrap()).await?;
+    };
+    if args.generate_new_encryption_key {
+        let sync_backend =
+            ConfiguredSyncBackend::from_config(&main_app_instance.lock().await.config);
+        gen_new_key_main(&sync_backend, args.email_id, args.password, passphrase).await?;
         return Ok(());
     } else if args.email_id.is_some() || args.password.is_some() {
         println!();
@@ -93,10 +142,36 @@ async fn main() -> Result<()> {
         println!();
         return Ok(());
     }
+    if let Some(command) = &args.command {
+        let encryption_key_override = args.encryption_key.clone().or(passphrase
+            .as_ref()
+            .and_then(|passphrase| derive_encryption_key_from_cached_salt(passphrase)));
+        match command {
+            CliCommand::Migrate { file } => migrate_save_file_main(file, encryption_key_override),
+            CliCommand::Remind { window } => remind_main(*window, encryption_key_override),
+            CliCommand::Export { format, output } => {
+                export_main(*format, output.as_deref(), encryption_key_override)
+            }
+            CliCommand::Import { file, board } => {
+                import_todo_txt_main(file, board, encryption_key_override)
+            }
+        }
+        return Ok(());
+    }
     if let Some(encryption_key) = args.encryption_key {
         let mut app = main_app_instance.lock().await;
         app.state.encryption_key_from_arguments = Some(encryption_key);
     }
+    if args.ignore_lock {
+        let mut app = main_app_instance.lock().await;
+        app.state.ignore_save_directory_lock = true;
+    }
+    if let Some(passphrase) = passphrase {
+        let mut app = main_app_instance.lock().await;
+        app.state.passphrase_derived_encryption_key =
+            derive_encryption_key_from_cached_salt(&passphrase);
+        app.state.encryption_passphrase = Some(passphrase);
+    }
 
     tokio::spawn(async move {
         let mut handler = IoAsyncHandler::new(main_app_instance);
@@ -9,14 +9,18 @@ use ratatui::{
 use rendering::{
     popup::{
         widgets::{CommandPalette, DateTimePicker, TagPicker},
-        CardPrioritySelector, CardStatusSelector, ChangeDateFormat, ChangeTheme, ChangeView,
-        ConfirmDiscardCardChanges, CustomHexColorPrompt, EditGeneralConfig, EditSpecificKeybinding,
-        EditThemeStyle, FilterByTag, SaveThemePrompt, SelectDefaultView, ViewCard,
+        BoardTemplatePicker, BulkActions, BulkEditPrompt, CardPrioritySelector, CardStatusSelector,
+        CardTemplatePicker, ChangeDateFormat, ChangeTheme, ChangeView, ConfirmDiscardCardChanges,
+        CustomHexColorPrompt, DueDatePrompt, EditGeneralConfig, EditSpecificKeybinding,
+        EditThemeStyle, EncryptionPassphrasePrompt, ExternalSaveChanged, FilterByTag, GoToBoard,
+        ImportTodoTxtPrompt, ResolveMergeConflicts, SaveBoardTemplatePrompt,
+        SaveCardTemplatePrompt, SaveThemePrompt, SelectDefaultView, TagManagerActions,
+        TagManagerPrompt, UndoHistory, ViewCard,
     },
     view::{
         BodyHelpLog, BodyLog, ConfigMenu, CreateTheme, EditKeybindings, HelpMenu, LoadASave,
         LoadCloudSave, LogView, Login, MainMenuView, NewBoardForm, NewCardForm, ResetPassword,
-        Signup, TitleBodyHelp, TitleBodyHelpLog, TitleBodyLog,
+        Signup, TagManager, TitleBodyHelp, TitleBodyHelpLog, TitleBodyLog,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -116,10 +120,10 @@ impl TextColorOptions {
             TextColorOptions::Green => (0, 128, 0),
             TextColorOptions::LightBlue => (0, 0, 255),
             TextColorOptions::LightCyan => (0, 255, 255),
-            TextColorOptions::LightGreen => (255, 255, 0),
+            TextColorOptions::LightGreen => (0, 255, 0),
             TextColorOptions::LightMagenta => (255, 0, 255),
             TextColorOptions::LightRed => (255, 0, 0),
-            TextColorOptions::LightYellow => (0, 255, 0),
+            TextColorOptions::LightYellow => (255, 255, 0),
             TextColorOptions::Magenta => (128, 0, 128),
             TextColorOptions::None => (0, 0, 0),
             TextColorOptions::Red => (128, 0, 0),
@@ -183,6 +187,7 @@ pub enum View {
     NewCard,
     ResetPassword,
     SignUp,
+    TagManager,
     TitleBody,
     TitleBodyHelp,
     TitleBodyHelpLog,
@@ -210,6 +215,7 @@ impl View {
             "New Card" => Some(View::NewCard),
             "Reset Password" => Some(View::ResetPassword),
             "Sign Up" => Some(View::SignUp),
+            "Tag Manager" => Some(View::TagManager),
             "Title and Body" => Some(View::TitleBody),
             "Title, Body and Help" => Some(View::TitleBodyHelp),
             "Title, Body, Help and Log" => Some(View::TitleBodyHelpLog),
@@ -266,6 +272,7 @@ impl View {
                 Focus::CardName,
                 Focus::CardDescription,
                 Focus::CardDueDate,
+                Focus::ExtraFocus,
                 Focus::SubmitButton,
             ],
             View::ResetPassword => vec![
@@ -286,6 +293,7 @@ impl View {
                 Focus::ExtraFocus,
                 Focus::SubmitButton,
             ],
+            View::TagManager => vec![Focus::TagManagerTable],
             View::TitleBody => vec![Focus::Title, Focus::Body],
             View::TitleBodyHelp => vec![Focus::Title, Focus::Body, Focus::Help],
             View::TitleBodyHelpLog => vec![Focus::Title, Focus::Body, Focus::Help, Focus::Log],
@@ -381,6 +389,7 @@ impl View {
             View::SignUp => Signup::render(rect, app, is_active),
             View::ResetPassword => ResetPassword::render(rect, app, is_active),
             View::LoadCloudSave => LoadCloudSave::render(rect, app, is_active),
+            View::TagManager => TagManager::render(rect, app, is_active),
         }
     }
 }
@@ -404,6 +413,7 @@ impl fmt::Display for View {
             View::NewCard => write!(f, "New Card"),
             View::ResetPassword => write!(f, "Reset Password"),
             View::SignUp => write!(f, "Sign Up"),
+            View::TagManager => write!(f, "Tag Manager"),
             View::TitleBody => write!(f, "Title and Body"),
             View::TitleBodyHelp => write!(f, "Title, Body and Help"),
             View::TitleBodyHelpLog => write!(f, "Title, Body, Help and Log"),
@@ -433,6 +443,23 @@ pub enum PopUp {
     FilterByTag,
     DateTimePicker,
     TagPicker,
+    GoToBoard,
+    BulkActions,
+    MoveSelectedCardsToBoard,
+    DuplicateCardToBoard,
+    BulkEditPrompt,
+    UndoHistory,
+    ResolveMergeConflicts,
+    ExternalSaveChanged,
+    DueDatePrompt,
+    ImportTodoTxtPrompt,
+    EncryptionPassphrasePrompt,
+    CardTemplatePicker,
+    SaveCardTemplatePrompt,
+    BoardTemplatePicker,
+    SaveBoardTemplatePrompt,
+    TagManagerActions,
+    TagManagerPrompt,
 }
 
 impl fmt::Display for PopUp {
@@ -456,6 +483,23 @@ impl fmt::Display for PopUp {
             PopUp::FilterByTag => write!(f, "Filter By Tag"),
             PopUp::DateTimePicker => write!(f, "Date Time Picker"),
             PopUp::TagPicker => write!(f, "Tag Picker"),
+            PopUp::GoToBoard => write!(f, "Go To Board"),
+            PopUp::BulkActions => write!(f, "Bulk Actions"),
+            PopUp::MoveSelectedCardsToBoard => write!(f, "Move Selected Cards To Board"),
+            PopUp::DuplicateCardToBoard => write!(f, "Duplicate Card To Board"),
+            PopUp::BulkEditPrompt => write!(f, "Bulk Edit Prompt"),
+            PopUp::UndoHistory => write!(f, "Undo History"),
+            PopUp::ResolveMergeConflicts => write!(f, "Resolve Merge Conflicts"),
+            PopUp::ExternalSaveChanged => write!(f, "Save File Changed"),
+            PopUp::DueDatePrompt => write!(f, "Due Date Prompt"),
+            PopUp::ImportTodoTxtPrompt => write!(f, "Import todo.txt Prompt"),
+            PopUp::EncryptionPassphrasePrompt => write!(f, "Encryption Passphrase Prompt"),
+            PopUp::CardTemplatePicker => write!(f, "Card Template Picker"),
+            PopUp::SaveCardTemplatePrompt => write!(f, "Save Card Template Prompt"),
+            PopUp::BoardTemplatePicker => write!(f, "Board Template Picker"),
+            PopUp::SaveBoardTemplatePrompt => write!(f, "Save Board Template Prompt"),
+            PopUp::TagManagerActions => write!(f, "Tag Manager Actions"),
+            PopUp::TagManagerPrompt => write!(f, "Tag Manager Prompt"),
         }
     }
 }
@@ -507,6 +551,23 @@ impl PopUp {
                 Focus::DTPSecond,
             ],
             PopUp::TagPicker => vec![Focus::CardTags],
+            PopUp::GoToBoard => vec![Focus::TextInput],
+            PopUp::BulkActions => vec![],
+            PopUp::MoveSelectedCardsToBoard => vec![Focus::TextInput],
+            PopUp::DuplicateCardToBoard => vec![Focus::TextInput],
+            PopUp::BulkEditPrompt => vec![Focus::TextInput],
+            PopUp::UndoHistory => vec![],
+            PopUp::ResolveMergeConflicts => vec![],
+            PopUp::ExternalSaveChanged => vec![],
+            PopUp::DueDatePrompt => vec![Focus::TextInput],
+            PopUp::ImportTodoTxtPrompt => vec![Focus::TextInput],
+            PopUp::EncryptionPassphrasePrompt => vec![Focus::TextInput],
+            PopUp::CardTemplatePicker => vec![],
+            PopUp::SaveCardTemplatePrompt => vec![Focus::TextInput],
+            PopUp::BoardTemplatePicker => vec![],
+            PopUp::SaveBoardTemplatePrompt => vec![Focus::TextInput],
+            PopUp::TagManagerActions => vec![],
+            PopUp::TagManagerPrompt => vec![Focus::TextInput],
         }
     }
 
@@ -588,6 +649,51 @@ impl PopUp {
             PopUp::TagPicker => {
                 TagPicker::render(rect, app, is_active);
             }
+            PopUp::GoToBoard | PopUp::MoveSelectedCardsToBoard | PopUp::DuplicateCardToBoard => {
+                GoToBoard::render(rect, app, is_active);
+            }
+            PopUp::BulkActions => {
+                BulkActions::render(rect, app, is_active);
+            }
+            PopUp::BulkEditPrompt => {
+                BulkEditPrompt::render(rect, app, is_active);
+            }
+            PopUp::UndoHistory => {
+                UndoHistory::render(rect, app, is_active);
+            }
+            PopUp::ResolveMergeConflicts => {
+                ResolveMergeConflicts::render(rect, app, is_active);
+            }
+            PopUp::ExternalSaveChanged => {
+                ExternalSaveChanged::render(rect, app, is_active);
+            }
+            PopUp::DueDatePrompt => {
+                DueDatePrompt::render(rect, app, is_active);
+            }
+            PopUp::ImportTodoTxtPrompt => {
+                ImportTodoTxtPrompt::render(rect, app, is_active);
+            }
+            PopUp::EncryptionPassphrasePrompt => {
+                EncryptionPassphrasePrompt::render(rect, app, is_active);
+            }
+            PopUp::CardTemplatePicker => {
+                CardTemplatePicker::render(rect, app, is_active);
+            }
+            PopUp::SaveCardTemplatePrompt => {
+                SaveCardTemplatePrompt::render(rect, app, is_active);
+            }
+            PopUp::BoardTemplatePicker => {
+                BoardTemplatePicker::render(rect, app, is_active);
+            }
+            PopUp::SaveBoardTemplatePrompt => {
+                SaveBoardTemplatePrompt::render(rect, app, is_active);
+            }
+            PopUp::TagManagerActions => {
+                TagManagerActions::render(rect, app, is_active);
+            }
+            PopUp::TagManagerPrompt => {
+                TagManagerPrompt::render(rect, app, is_active);
+            }
         }
     }
 }
@@ -2,14 +2,14 @@ use crate::{
     app::{
         app_helper::reset_card_drag_mode,
         kanban::{Boards, Card, CardPriority, CardStatus},
-        state::{Focus, KeyBindingEnum},
-        App, DateTimeFormat,
+        state::{Focus, KeyBindingEnum, SyncStatus},
+        App,
     },
     constants::{
         APP_TITLE, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH, FIELD_NOT_SET,
-        HIDDEN_PASSWORD_SYMBOL, LIST_SELECTED_SYMBOL, MOUSE_OUT_OF_BOUNDS_COORDINATES,
-        PATTERN_CHANGE_INTERVAL, SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL,
-        SCROLLBAR_TRACK_SYMBOL,
+        HIDDEN_PASSWORD_SYMBOL, LIST_SELECTED_SYMBOL, MAX_BOARDS_IN_MINI_MAP,
+        MOUSE_OUT_OF_BOUNDS_COORDINATES, PATTERN_CHANGE_INTERVAL, SCROLLBAR_BEGIN_SYMBOL,
+        SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL,
     },
     io::logger::{get_logs, get_selected_index, RUST_KANBAN_LOGGER},
     ui::{
@@ -19,10 +19,10 @@ use crate::{
             get_mouse_focusable_field_style,
         },
         theme::Theme,
+        View,
     },
-    util::{date_format_converter, date_format_finder, update_current_board_and_card},
+    util::update_current_board_and_card,
 };
-use chrono::{Local, NaiveDate, NaiveDateTime};
 use log::Level;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
@@ -34,10 +34,7 @@ use ratatui::{
     },
     Frame,
 };
-use std::{
-    cmp::Ordering,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 pub fn render_body(
     rect: &mut Frame,
@@ -167,8 +164,9 @@ pub fn render_body(
     }
 
     let mut constraints = vec![];
-    if boards.len() > app.config.no_of_boards_to_show.into() {
-        for _i in 0..app.config.no_of_boards_to_show {
+    let no_of_boards_to_show = app.get_no_of_boards_to_show();
+    if boards.len() > no_of_boards_to_show.into() {
+        for _i in 0..no_of_boards_to_show {
             constraints.push(Constraint::Fill(1));
         }
     } else {
@@ -208,6 +206,26 @@ pub fn render_body(
         } else {
             board_title
         };
+        // In the compact layout only one board is visible, hint at the boards hidden on either side
+        let board_title = if app.state.compact_layout {
+            let board_index_in_all_boards = boards.get_board_index(*board_id).unwrap_or(0);
+            let previous_boards_marker = if board_index_in_all_boards > 0 {
+                "< "
+            } else {
+                ""
+            };
+            let next_boards_marker = if board_index_in_all_boards + 1 < boards.len() {
+                " >"
+            } else {
+                ""
+            };
+            format!(
+                "{}{}{}",
+                previous_boards_marker, board_title, next_boards_marker
+            )
+        } else {
+            board_title
+        };
 
         let mut card_constraints = vec![];
         if board_cards.len() > app.config.no_of_cards_to_show.into() {
@@ -688,6 +706,12 @@ fn render_a_single_card(
     } else {
         card.name.clone()
     };
+    let card_is_selected = app.state.selected_cards.contains(&card.id);
+    let card_title = if card_is_selected {
+        format!("[x] {}", card_title)
+    } else {
+        card_title
+    };
     let card_title = if app.state.current_card_id.unwrap_or((0, 0)) == card.id {
         format!("{} {}", ">>", card_title)
     } else {
@@ -722,85 +746,16 @@ fn render_a_single_card(
     );
 
     let mut card_extra_info = vec![Line::from("")];
-    if card.due_date == FIELD_NOT_SET {
-        card_extra_info.push(Line::from(Span::styled(
-            format!("Due: {}", FIELD_NOT_SET),
-            card_due_default_style,
-        )))
-    } else {
-        let card_due_date = card.due_date.clone();
-        let parsed_due_date =
-            date_format_converter(card_due_date.trim(), app.config.date_time_format);
-        let card_due_date_styled = if let Ok(parsed_due_date) = parsed_due_date {
-            if parsed_due_date == FIELD_NOT_SET || parsed_due_date.is_empty() {
-                Line::from(Span::styled(
-                    format!("Due: {}", parsed_due_date),
-                    card_due_default_style,
-                ))
-            } else {
-                let formatted_date_format = date_format_finder(&parsed_due_date).unwrap();
-                let (days_left, parsed_due_date) = match formatted_date_format {
-                    DateTimeFormat::DayMonthYear
-                    | DateTimeFormat::MonthDayYear
-                    | DateTimeFormat::YearMonthDay => {
-                        let today = Local::now().date_naive();
-                        let string_to_naive_date_format = NaiveDate::parse_from_str(
-                            &parsed_due_date,
-                            app.config.date_time_format.to_parser_string(),
-                        )
-                        .unwrap();
-                        let days_left = string_to_naive_date_format
-                            .signed_duration_since(today)
-                            .num_days();
-                        let parsed_due_date = string_to_naive_date_format
-                            .format(app.config.date_time_format.to_parser_string())
-                            .to_string();
-                        (days_left, parsed_due_date)
-                    }
-                    DateTimeFormat::DayMonthYearTime
-                    | DateTimeFormat::MonthDayYearTime
-                    | DateTimeFormat::YearMonthDayTime {} => {
-                        let today = Local::now().naive_local();
-                        let string_to_naive_date_format = NaiveDateTime::parse_from_str(
-                            &parsed_due_date,
-                            app.config.date_time_format.to_parser_string(),
-                        )
-                        .unwrap();
-                        let days_left = string_to_naive_date_format
-                            .signed_duration_since(today)
-                            .num_days();
-                        let parsed_due_date = string_to_naive_date_format
-                            .format(app.config.date_time_format.to_parser_string())
-                            .to_string();
-                        (days_left, parsed_due_date)
-                    }
-                };
-                if days_left >= 0 {
-                    match days_left.cmp(&(app.config.warning_delta as i64)) {
-                        Ordering::Less | Ordering::Equal => Line::from(Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            card_due_warning_style,
-                        )),
-                        Ordering::Greater => Line::from(Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            card_due_default_style,
-                        )),
-                    }
-                } else {
-                    Line::from(Span::styled(
-                        format!("Due: {}", parsed_due_date),
-                        card_due_overdue_style,
-                    ))
-                }
-            }
-        } else {
-            Line::from(Span::styled(
-                format!("Due: {}", card_due_date),
-                card_due_default_style,
-            ))
-        };
-        card_extra_info.extend(vec![card_due_date_styled]);
-    }
+    let card_due_date = format!(
+        "Due: {}",
+        app.config.date_time_format.format_timestamp(card.due_date)
+    );
+    let card_due_date_style = match card.get_days_until_due() {
+        Some(days_left) if days_left < 0 => card_due_overdue_style,
+        Some(days_left) if days_left <= app.config.warning_delta as i64 => card_due_warning_style,
+        _ => card_due_default_style,
+    };
+    card_extra_info.push(Line::from(Span::styled(card_due_date, card_due_date_style)));
 
     let mut card_status = format!("Status: {}", card.card_status.clone());
     let mut card_priority = format!("Priority: {}", card.priority.clone());
@@ -850,7 +805,11 @@ fn render_a_single_card(
         .title(&*card_title)
         .borders(Borders::ALL)
         .border_style(card_style)
-        .border_type(BorderType::Rounded);
+        .border_type(if card_is_selected {
+            BorderType::Double
+        } else {
+            BorderType::Rounded
+        });
     let card_paragraph = Paragraph::new(card_description)
         .alignment(Alignment::Left)
         .block(Block::default())
@@ -873,15 +832,113 @@ pub fn draw_title<'a>(app: &mut App, render_area: Rect, is_active: bool) -> Para
     );
     let border_style =
         get_mouse_focusable_field_style(app, Focus::Title, &render_area, is_active, false);
+    let mut title_block = Block::default()
+        .style(title_style)
+        .borders(Borders::ALL)
+        .border_style(border_style)
+        .border_type(BorderType::Rounded);
+    if View::views_with_kanban_board().contains(&app.state.current_view) {
+        if let Some(board_mini_map) = get_board_mini_map(app, is_active) {
+            title_block = title_block.title(board_mini_map.right_aligned());
+        }
+    }
+    if app.main_menu.logged_in {
+        title_block = title_block.title(get_sync_status_indicator(app, is_active));
+    }
+    if app.state.read_only {
+        let read_only_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.log_warn_style,
+        );
+        title_block = title_block.title(Line::from(Span::styled(" Read Only ", read_only_style)));
+    }
     Paragraph::new(APP_TITLE)
         .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .style(title_style)
-                .borders(Borders::ALL)
-                .border_style(border_style)
-                .border_type(BorderType::Rounded),
-        )
+        .block(title_block)
+}
+
+fn get_sync_status_indicator<'a>(app: &App, is_active: bool) -> Line<'a> {
+    let sync_status = app.get_sync_status();
+    let status_style = match sync_status {
+        SyncStatus::Synced => app.current_theme.log_info_style,
+        SyncStatus::Pending => app.current_theme.log_warn_style,
+        SyncStatus::Error => app.current_theme.log_error_style,
+    };
+    let status_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        status_style,
+    );
+    let status_symbol = match sync_status {
+        SyncStatus::Synced => "✔",
+        SyncStatus::Pending => "↻",
+        SyncStatus::Error => "✘",
+    };
+    Line::from(Span::styled(
+        format!(" {} {} ", status_symbol, sync_status),
+        status_style,
+    ))
+}
+
+/// One glyph per board, filled for the boards currently on screen and highlighted for the
+/// current board, falls back to a "Boards x-y of n" summary when there are too many boards
+fn get_board_mini_map<'a>(app: &App, is_active: bool) -> Option<Line<'a>> {
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    if boards.is_empty() {
+        return None;
+    }
+    let general_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.general_style,
+    );
+    let current_board_style = check_if_active_and_get_style(
+        is_active,
+        app.current_theme.inactive_text_style,
+        app.current_theme.keyboard_focus_style,
+    );
+    let visible_board_indexes = boards
+        .get_boards()
+        .iter()
+        .enumerate()
+        .filter(|(_, board)| app.visible_boards_and_cards.contains_key(&board.id))
+        .map(|(board_index, _)| board_index)
+        .collect::<Vec<usize>>();
+
+    if boards.len() > MAX_BOARDS_IN_MINI_MAP {
+        let (first_visible, last_visible) = (
+            visible_board_indexes.first().copied().unwrap_or(0),
+            visible_board_indexes.last().copied().unwrap_or(0),
+        );
+        return Some(Line::from(Span::styled(
+            format!(
+                " Boards {}-{} of {} ",
+                first_visible + 1,
+                last_visible + 1,
+                boards.len()
+            ),
+            general_style,
+        )));
+    }
+
+    let mut mini_map_spans = vec![Span::styled(" ", general_style)];
+    for (board_index, board) in boards.get_boards().iter().enumerate() {
+        let (glyph, style) = if app.state.current_board_id == Some(board.id) {
+            ("■", current_board_style)
+        } else if visible_board_indexes.contains(&board_index) {
+            ("■", general_style)
+        } else {
+            ("□", general_style)
+        };
+        mini_map_spans.push(Span::styled(glyph, style));
+        mini_map_spans.push(Span::styled(" ", general_style));
+    }
+    Some(Line::from(mini_map_spans))
 }
 
 pub fn draw_help<'a>(
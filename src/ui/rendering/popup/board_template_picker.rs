@@ -0,0 +1,81 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::BoardTemplatePicker,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for BoardTemplatePicker {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let inactive_text_style = app.current_theme.inactive_text_style;
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let border_block = Block::default()
+            .title("Create Boards from a Template")
+            .style(general_style)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        let all_templates = app
+            .board_templates
+            .iter()
+            .map(|template| {
+                ListItem::new(vec![Line::from(vec![
+                    Span::raw(if template.built_in {
+                        template.name.clone()
+                    } else {
+                        format!("{} (saved)", template.name)
+                    }),
+                    Span::styled(format!(" - {}", template.summary()), inactive_text_style),
+                ])])
+            })
+            .collect::<Vec<ListItem>>();
+        let popup_height = (all_templates.len() as u16 + 2).min(rect.area().height);
+        let popup_area = centered_rect_with_length(80, popup_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &all_templates,
+                popup_area,
+                &mut app.state.app_list_states.board_template_picker,
+            );
+        }
+        let templates = List::new(all_templates)
+            .block(border_block)
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            templates,
+            popup_area,
+            &mut app.state.app_list_states.board_template_picker,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
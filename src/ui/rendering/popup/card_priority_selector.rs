@@ -54,6 +54,18 @@ impl Renderable for CardPrioritySelector {
             .iter()
             .map(|p| ListItem::new(vec![Line::from(p.to_string())]))
             .collect::<Vec<ListItem>>();
+        let popup_title =
+            if app.state.card_being_edited.is_none() && !app.state.selected_cards.is_empty() {
+                format!(
+                    "Changing Priority of {} selected cards",
+                    app.state.selected_cards.len()
+                )
+            } else {
+                format!(
+                    "Changing Priority of \"{}\" in \"{}\"",
+                    card_name, board_name
+                )
+            };
         let percent_height =
             (((all_priorities.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
         let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
@@ -70,10 +82,7 @@ impl Renderable for CardPrioritySelector {
         let priorities = List::new(all_priorities)
             .block(
                 Block::default()
-                    .title(format!(
-                        "Changing Priority of \"{}\" in \"{}\"",
-                        card_name, board_name
-                    ))
+                    .title(popup_title)
                     .style(general_style)
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded),
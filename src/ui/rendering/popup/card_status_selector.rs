@@ -54,6 +54,15 @@ impl Renderable for CardStatusSelector {
             .iter()
             .map(|s| ListItem::new(vec![Line::from(s.to_string())]))
             .collect::<Vec<ListItem>>();
+        let popup_title =
+            if app.state.card_being_edited.is_none() && !app.state.selected_cards.is_empty() {
+                format!(
+                    "Changing Status of {} selected cards",
+                    app.state.selected_cards.len()
+                )
+            } else {
+                format!("Changing Status of \"{}\" in \"{}\"", card_name, board_name)
+            };
         let percent_height =
             (((all_statuses.len() + 3) as f32 / rect.area().height as f32) * 100.0) as u16;
         let popup_area = centered_rect_with_percentage(50, percent_height, rect.area());
@@ -70,10 +79,7 @@ impl Renderable for CardStatusSelector {
         let statuses = List::new(all_statuses)
             .block(
                 Block::default()
-                    .title(format!(
-                        "Changing Status of \"{}\" in \"{}\"",
-                        card_name, board_name
-                    ))
+                    .title(popup_title)
                     .style(general_style)
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded),
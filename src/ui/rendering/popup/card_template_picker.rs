@@ -0,0 +1,94 @@
+use crate::{
+    app::{state::Focus, App},
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::CardTemplatePicker,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::Alignment,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+impl Renderable for CardTemplatePicker {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let inactive_text_style = app.current_theme.inactive_text_style;
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let border_block = Block::default()
+            .title("Pick a Card Template")
+            .style(general_style)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        if app.card_templates.is_empty() {
+            let popup_area = centered_rect_with_length(60, 4, rect.area());
+            let no_templates = Paragraph::new(
+                "No card templates saved yet, use 'Save Current Card as Template' from the command palette",
+            )
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true })
+            .block(border_block);
+            render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+            rect.render_widget(no_templates, popup_area);
+            if app.config.enable_mouse_support {
+                render_close_button(rect, app, is_active);
+            }
+            return;
+        }
+
+        let all_templates = app
+            .card_templates
+            .iter()
+            .map(|template| {
+                ListItem::new(vec![Line::from(vec![
+                    Span::raw(template.name.clone()),
+                    Span::styled(format!(" ({})", template.summary()), inactive_text_style),
+                ])])
+            })
+            .collect::<Vec<ListItem>>();
+        let popup_height = (all_templates.len() as u16 + 2).min(rect.area().height);
+        let popup_area = centered_rect_with_length(60, popup_height, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &all_templates,
+                popup_area,
+                &mut app.state.app_list_states.card_template_picker,
+            );
+        }
+        let templates = List::new(all_templates)
+            .block(border_block)
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            templates,
+            popup_area,
+            &mut app.state.app_list_states.card_template_picker,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
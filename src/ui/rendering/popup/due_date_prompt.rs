@@ -0,0 +1,145 @@
+use crate::{
+    app::{
+        natural_date::parse_due_date,
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App,
+    },
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::DueDatePrompt,
+            utils::{
+                calculate_viewport_corrected_cursor_position, centered_rect_with_length,
+                check_if_active_and_get_style, get_mouse_focusable_field_style,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+    Frame,
+};
+
+impl Renderable for DueDatePrompt {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(60, 9, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(3),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let error_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.error_text_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let text_input_style =
+            get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], is_active, true);
+
+        let input = app.state.text_buffers.due_date.get_joined_lines();
+        let preview = if input.trim().is_empty() {
+            Span::styled("Leave empty to clear the due date", help_text_style)
+        } else {
+            match parse_due_date(
+                &input,
+                app.config.date_time_format,
+                &app.config.date_picker_calender_format,
+            ) {
+                Ok(due_date) => Span::styled(
+                    format!(
+                        "Due: {}",
+                        app.config.date_time_format.format_timestamp(Some(due_date))
+                    ),
+                    general_style,
+                ),
+                Err(err) => Span::styled(err, error_text_style),
+            }
+        };
+
+        app.state
+            .text_buffers
+            .due_date
+            .set_placeholder_text(format!(
+                "{} or tomorrow 5pm, next fri, +3d",
+                app.config.date_time_format.to_human_readable_string()
+            ));
+        app.state.text_buffers.due_date.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(text_input_style)
+                .border_type(BorderType::Rounded),
+        );
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to set the due date, ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to cancel", help_text_style),
+        ]))
+        .alignment(Alignment::Center);
+
+        let prompt_text = Paragraph::new("Type a date or something like 'in 2 weeks'")
+            .style(general_style)
+            .alignment(Alignment::Center);
+        let preview_text = Paragraph::new(Line::from(preview)).alignment(Alignment::Center);
+
+        let border_block = Block::default()
+            .title("Set Due Date")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(border_block, popup_area);
+        rect.render_widget(prompt_text, chunks[0]);
+        rect.render_widget(app.state.text_buffers.due_date.widget(), chunks[1]);
+        rect.render_widget(preview_text, chunks[2]);
+        rect.render_widget(help_text, chunks[3]);
+
+        if app.state.app_status == AppStatus::UserInput && app.state.focus == Focus::TextInput {
+            let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                &app.state.text_buffers.due_date,
+                &app.config.show_line_numbers,
+                &chunks[1],
+            );
+            rect.set_cursor_position((x_pos, y_pos));
+        }
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
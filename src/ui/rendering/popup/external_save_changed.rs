@@ -0,0 +1,80 @@
+use crate::{
+    app::{
+        state::{ExternalSaveChangeAction, Focus},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ExternalSaveChanged,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+impl Renderable for ExternalSaveChanged {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let all_actions = ExternalSaveChangeAction::all()
+            .iter()
+            .map(|action| ListItem::new(vec![Line::from(action.to_string())]))
+            .collect::<Vec<ListItem>>();
+        let popup_area = centered_rect_with_length(70, all_actions.len() as u16 + 2, rect.area());
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &all_actions,
+                popup_area,
+                &mut app.state.app_list_states.external_save_change_actions,
+            );
+        }
+        let title = if let Some(external_save_change) = &app.state.external_save_change {
+            format!(
+                "{} was changed outside of this instance",
+                external_save_change.file_name
+            )
+        } else {
+            "Save file was changed outside of this instance".to_string()
+        };
+        let actions = List::new(all_actions)
+            .block(
+                Block::default()
+                    .title(title)
+                    .style(general_style)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_stateful_widget(
+            actions,
+            popup_area,
+            &mut app.state.app_list_states.external_save_change_actions,
+        );
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
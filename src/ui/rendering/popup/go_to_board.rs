@@ -0,0 +1,191 @@
+use crate::{
+    app::{
+        state::{AppStatus, Focus, KeyBindingEnum},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::GoToBoard,
+            utils::{
+                calculate_mouse_list_select_index, calculate_viewport_corrected_cursor_position,
+                centered_rect_with_length, check_if_active_and_get_style,
+                check_if_mouse_is_in_area,
+            },
+        },
+        PopUp, Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+impl Renderable for GoToBoard {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(60, 16, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Fill(1),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let keyboard_focus_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.keyboard_focus_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+        let error_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.error_text_style,
+        );
+
+        let (popup_title, accept_help_text) = match app.state.z_stack.last() {
+            Some(PopUp::MoveSelectedCardsToBoard) => (
+                format!(
+                    "Move {} Selected Cards To Board",
+                    app.state.selected_cards.len()
+                ),
+                " to move the selected cards there, ",
+            ),
+            Some(PopUp::DuplicateCardToBoard) => (
+                "Duplicate Current Card To Board".to_string(),
+                " to copy the card there, ",
+            ),
+            _ => ("Go To Board".to_string(), " to go to the selected board, "),
+        };
+
+        let search_results = app.get_go_to_board_search_results();
+        if search_results.is_empty() {
+            app.state.app_list_states.go_to_board.select(None);
+        } else if app
+            .state
+            .app_list_states
+            .go_to_board
+            .selected()
+            .is_none_or(|selected| selected >= search_results.len())
+        {
+            app.state.app_list_states.go_to_board.select(Some(0));
+        }
+
+        app.state
+            .text_buffers
+            .go_to_board
+            .set_placeholder_text("Start typing to search for a board");
+        let search_box_block = Block::default()
+            .title("Board Name")
+            .borders(Borders::ALL)
+            .style(general_style)
+            .border_style(keyboard_focus_style)
+            .border_type(BorderType::Rounded);
+        app.state
+            .text_buffers
+            .go_to_board
+            .set_block(search_box_block);
+
+        let board_items = search_results
+            .iter()
+            .map(|(board_index, _, board_name)| {
+                ListItem::new(Line::from(format!("{}. {}", board_index + 1, board_name)))
+            })
+            .collect::<Vec<ListItem>>();
+
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0]) {
+            app.state.mouse_focus = Some(Focus::TextInput);
+        }
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[1]) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &board_items,
+                chunks[1],
+                &mut app.state.app_list_states.go_to_board,
+            );
+        }
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(accept_help_text, help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to cancel", help_text_style),
+        ]))
+        .alignment(Alignment::Center);
+
+        let border_block = Block::default()
+            .title(popup_title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(border_block, popup_area);
+        rect.render_widget(app.state.text_buffers.go_to_board.widget(), chunks[0]);
+        if board_items.is_empty() {
+            let no_results = Paragraph::new("No matching boards found")
+                .style(error_text_style)
+                .alignment(Alignment::Center);
+            rect.render_widget(no_results, chunks[1]);
+        } else {
+            let board_list = List::new(board_items)
+                .style(general_style)
+                .highlight_style(list_select_style)
+                .highlight_symbol(LIST_SELECTED_SYMBOL);
+            rect.render_stateful_widget(
+                board_list,
+                chunks[1],
+                &mut app.state.app_list_states.go_to_board,
+            );
+        }
+        rect.render_widget(help_text, chunks[2]);
+
+        if app.state.app_status == AppStatus::UserInput && app.state.focus == Focus::TextInput {
+            let (x_pos, y_pos) = calculate_viewport_corrected_cursor_position(
+                &app.state.text_buffers.go_to_board,
+                &app.config.show_line_numbers,
+                &chunks[0],
+            );
+            rect.set_cursor_position((x_pos, y_pos));
+        }
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
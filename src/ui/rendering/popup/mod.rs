@@ -1,16 +1,31 @@
+pub mod board_template_picker;
+pub mod bulk_actions;
+pub mod bulk_edit_prompt;
 pub mod card_priority_selector;
 pub mod card_status_selector;
+pub mod card_template_picker;
 pub mod change_date_format;
 pub mod change_theme;
 pub mod change_view;
 pub mod confirm_discard_card_changes;
 pub mod custom_hex_color_prompt;
+pub mod due_date_prompt;
 pub mod edit_general_config;
 pub mod edit_specific_keybinding;
 pub mod edit_theme_style;
+pub mod encryption_passphrase_prompt;
+pub mod external_save_changed;
 pub mod filter_by_tag;
+pub mod go_to_board;
+pub mod import_todo_txt_prompt;
+pub mod resolve_merge_conflicts;
+pub mod save_board_template_prompt;
+pub mod save_card_template_prompt;
 pub mod save_theme_prompt;
 pub mod select_default_view;
+pub mod tag_manager_actions;
+pub mod tag_manager_prompt;
+pub mod undo_history;
 pub mod view_card;
 pub mod widgets;
 
@@ -27,4 +42,19 @@ pub struct CustomHexColorPrompt;
 pub struct ConfirmDiscardCardChanges;
 pub struct CardPrioritySelector;
 pub struct FilterByTag;
+pub struct GoToBoard;
+pub struct BulkActions;
+pub struct BulkEditPrompt;
+pub struct UndoHistory;
+pub struct ResolveMergeConflicts;
+pub struct ExternalSaveChanged;
+pub struct DueDatePrompt;
+pub struct ImportTodoTxtPrompt;
+pub struct EncryptionPassphrasePrompt;
+pub struct CardTemplatePicker;
+pub struct SaveCardTemplatePrompt;
+pub struct BoardTemplatePicker;
+pub struct SaveBoardTemplatePrompt;
+pub struct TagManagerActions;
+pub struct TagManagerPrompt;
 pub struct ChangeDateFormat;
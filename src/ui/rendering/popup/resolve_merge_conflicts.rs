@@ -0,0 +1,191 @@
+use crate::{
+    app::{
+        merge::{MergeSide, MergeSource},
+        state::{Focus, KeyBindingEnum},
+        App,
+    },
+    constants::LIST_SELECTED_SYMBOL,
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::ResolveMergeConflicts,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+const MAX_CONFLICT_VALUE_LENGTH: usize = 30;
+
+impl Renderable for ResolveMergeConflicts {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(110, 20, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(1)].as_ref())
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let inactive_text_style = app.current_theme.inactive_text_style;
+        let keyboard_focus_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.keyboard_focus_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let (theirs_label, accept_text) = match app
+            .state
+            .pending_merge
+            .as_ref()
+            .map(|pending_merge| &pending_merge.source)
+        {
+            Some(MergeSource::LocalSave(_)) => ("save file", " to merge and save, "),
+            _ => ("cloud", " to merge and sync, "),
+        };
+        let (title, conflict_items) = if let Some(pending_merge) = &app.state.pending_merge {
+            let conflict_items = pending_merge
+                .conflicts
+                .iter()
+                .enumerate()
+                .map(|(index, conflict)| {
+                    let (ours_style, theirs_style) = match pending_merge.get_resolution(index) {
+                        MergeSide::Ours => (keyboard_focus_style, inactive_text_style),
+                        MergeSide::Theirs => (inactive_text_style, keyboard_focus_style),
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            format!("{} - {}: ", shorten(&conflict.title), conflict.field),
+                            general_style,
+                        ),
+                        Span::styled(
+                            format!("This device: {}", shorten(&conflict.ours)),
+                            ours_style,
+                        ),
+                        Span::styled(" | ", general_style),
+                        Span::styled(
+                            format!(
+                                "{}: {}",
+                                pending_merge.source.theirs_label(),
+                                shorten(&conflict.theirs)
+                            ),
+                            theirs_style,
+                        ),
+                    ]))
+                })
+                .collect::<Vec<ListItem>>();
+            (
+                format!(
+                    "Resolve Conflicts with {} ({} conflicts)",
+                    pending_merge.source,
+                    pending_merge.conflicts.len()
+                ),
+                conflict_items,
+            )
+        } else {
+            ("Resolve Conflicts".to_string(), vec![])
+        };
+
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0]) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &conflict_items,
+                chunks[0],
+                &mut app.state.app_list_states.merge_conflicts,
+            );
+        }
+
+        let left_key = app
+            .get_first_keybinding(KeyBindingEnum::Left)
+            .unwrap_or("".to_string());
+        let right_key = app
+            .get_first_keybinding(KeyBindingEnum::Right)
+            .unwrap_or("".to_string());
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(left_key, help_key_style),
+            Span::styled(" to keep this device's version, ", help_text_style),
+            Span::styled(right_key, help_key_style),
+            Span::styled(
+                format!(" to keep the {} version, ", theirs_label),
+                help_text_style,
+            ),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(accept_text, help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to cancel", help_text_style),
+        ]))
+        .alignment(Alignment::Center);
+
+        let border_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+        let conflict_list = List::new(conflict_items)
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(border_block, popup_area);
+        rect.render_stateful_widget(
+            conflict_list,
+            chunks[0],
+            &mut app.state.app_list_states.merge_conflicts,
+        );
+        rect.render_widget(help_text, chunks[1]);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
+
+fn shorten(value: &str) -> String {
+    let value = value.replace('\n', " ");
+    if value.chars().count() > MAX_CONFLICT_VALUE_LENGTH {
+        format!(
+            "{}...",
+            value
+                .chars()
+                .take(MAX_CONFLICT_VALUE_LENGTH)
+                .collect::<String>()
+        )
+    } else {
+        value
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    app::{
+        state::{Focus, KeyBindingEnum},
+        App,
+    },
+    constants::{FIELD_NA, LIST_SELECTED_SYMBOL},
+    ui::{
+        rendering::{
+            common::{render_blank_styled_canvas, render_close_button},
+            popup::UndoHistory,
+            utils::{
+                calculate_mouse_list_select_index, centered_rect_with_length,
+                check_if_active_and_get_style, check_if_mouse_is_in_area,
+            },
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+impl Renderable for UndoHistory {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let popup_area = centered_rect_with_length(80, 20, rect.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(1)].as_ref())
+            .margin(1)
+            .split(popup_area);
+
+        let general_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let inactive_text_style = app.current_theme.inactive_text_style;
+        let keyboard_focus_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.keyboard_focus_style,
+        );
+        let list_select_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let history_index = app.action_history_manager.history_index;
+        let date_time_format = app.config.date_time_format.to_parser_string();
+        // Position 0 is the state before any recorded action, position n is after the nth action
+        let mut history_items = vec![(0, "Start of history".to_string(), String::new())];
+        history_items.extend(app.action_history_manager.history.iter().enumerate().map(
+            |(index, entry)| {
+                let timestamp = chrono::DateTime::from_timestamp(entry.timestamp, 0)
+                    .map(|timestamp| {
+                        timestamp
+                            .with_timezone(&chrono::Local)
+                            .format(date_time_format)
+                            .to_string()
+                    })
+                    .unwrap_or(FIELD_NA.to_string());
+                (index + 1, entry.action.describe(), timestamp)
+            },
+        ));
+        let history_items = history_items
+            .into_iter()
+            .map(|(position, description, timestamp)| {
+                let (style, suffix) = if position == history_index {
+                    (keyboard_focus_style, " (current)")
+                } else if position > history_index {
+                    (inactive_text_style, " (undone)")
+                } else {
+                    (general_style, "")
+                };
+                let mut spans = vec![];
+                if !timestamp.is_empty() {
+                    spans.push(Span::styled(format!("{} ", timestamp), help_text_style));
+                }
+                spans.push(Span::styled(format!("{}{}", description, suffix), style));
+                ListItem::new(Line::from(spans))
+            })
+            .collect::<Vec<ListItem>>();
+
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0]) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &history_items,
+                chunks[0],
+                &mut app.state.app_list_states.undo_history,
+            );
+        }
+
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" to jump to the selected point, ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to cancel", help_text_style),
+        ]))
+        .alignment(Alignment::Center);
+
+        let border_block = Block::default()
+            .title(format!(
+                "Undo History ({} actions)",
+                app.action_history_manager.history.len()
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(general_style);
+        let history_list = List::new(history_items)
+            .highlight_style(list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+        render_blank_styled_canvas(rect, &app.current_theme, popup_area, is_active);
+        rect.render_widget(border_block, popup_area);
+        rect.render_stateful_widget(
+            history_list,
+            chunks[0],
+            &mut app.state.app_list_states.undo_history,
+        );
+        rect.render_widget(help_text, chunks[1]);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active);
+        }
+    }
+}
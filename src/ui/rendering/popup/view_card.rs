@@ -1,8 +1,9 @@
 use crate::{
     app::{
         kanban::{CardPriority, CardStatus},
+        reminders::format_reminder_offsets,
         state::{AppStatus, Focus},
-        App, DateTimeFormat,
+        App,
     },
     constants::FIELD_NOT_SET,
     ui::{
@@ -12,14 +13,13 @@ use crate::{
             utils::{
                 calculate_viewport_corrected_cursor_position, centered_rect_with_percentage,
                 check_if_active_and_get_style, check_if_mouse_is_in_area, get_button_style,
+                get_tag_style,
             },
         },
         widgets::SelfViewportCorrection,
         PopUp, Renderable,
     },
-    util::{date_format_converter, date_format_finder},
 };
-use chrono::{Local, NaiveDate, NaiveDateTime};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     text::{Line, Span},
@@ -118,20 +118,9 @@ impl Renderable for ViewCard {
         } else {
             card.unwrap().to_owned()
         };
-        if app.widgets.date_time_picker.selected_date_time.is_none()
-            && !card.due_date.is_empty()
-            && card.due_date != FIELD_NOT_SET
-        {
-            if let Ok(current_format) = date_format_finder(card.due_date.trim()) {
-                app.widgets.date_time_picker.selected_date_time =
-                    match NaiveDateTime::parse_from_str(
-                        card.due_date.trim(),
-                        current_format.to_parser_string(),
-                    ) {
-                        Ok(date_time) => Some(date_time),
-                        Err(_) => None,
-                    };
-            }
+        if app.widgets.date_time_picker.selected_date_time.is_none() {
+            app.widgets.date_time_picker.selected_date_time =
+                card.due_date.map(|due_date| due_date.naive_local());
         }
         let board_name = board.name.clone();
         let card_name = card.name.clone();
@@ -180,146 +169,57 @@ impl Renderable for ViewCard {
 
         // Process Card Extra Info
         let (card_extra_info_widget, card_extra_info_items_len, card_due_date_width) = {
-            let card_date_created = if date_format_finder(&card.date_created).is_ok() {
-                if let Ok(parsed_date) =
-                    date_format_converter(&card.date_created, app.config.date_time_format)
-                {
-                    Span::styled(format!("Created: {}", parsed_date), general_style)
-                } else {
-                    match NaiveDateTime::parse_from_str(
-                        &card.date_created,
-                        app.config.date_time_format.to_parser_string(),
-                    ) {
-                        Ok(parsed_date) => Span::styled(
-                            format!(
-                                "Created: {}",
-                                parsed_date.format(app.config.date_time_format.to_parser_string())
-                            ),
-                            general_style,
-                        ),
-                        Err(_) => {
-                            Span::styled(format!("Created: {}", card.date_created), general_style)
-                        }
-                    }
-                }
-            } else {
-                Span::styled(format!("Created: {}", card.date_created), general_style)
-            };
-            let card_date_modified = if date_format_finder(&card.date_modified).is_ok() {
-                if let Ok(parsed_date) =
-                    date_format_converter(&card.date_modified, app.config.date_time_format)
-                {
-                    Span::styled(format!("Modified: {}", parsed_date), general_style)
-                } else {
-                    Span::styled(format!("Modified: {}", card.date_modified), general_style)
-                }
-            } else {
-                match NaiveDateTime::parse_from_str(
-                    &card.date_modified,
-                    app.config.date_time_format.to_parser_string(),
-                ) {
-                    Ok(parsed_date) => Span::styled(
-                        format!(
-                            "Modified: {}",
-                            parsed_date.format(app.config.date_time_format.to_parser_string())
-                        ),
-                        general_style,
-                    ),
-                    Err(_) => {
-                        Span::styled(format!("Modified: {}", card.date_modified), general_style)
-                    }
-                }
-            };
-            let card_date_completed = if date_format_finder(&card.date_completed).is_ok() {
-                if let Ok(parsed_date) =
-                    date_format_converter(&card.date_completed, app.config.date_time_format)
-                {
-                    Span::styled(format!("Completed: {}", parsed_date), general_style)
-                } else {
-                    Span::styled(format!("Completed: {}", card.date_completed), general_style)
-                }
-            } else {
-                match NaiveDateTime::parse_from_str(
-                    &card.date_completed,
-                    app.config.date_time_format.to_parser_string(),
-                ) {
-                    Ok(parsed_date) => Span::styled(
-                        format!(
-                            "Completed: {}",
-                            parsed_date.format(app.config.date_time_format.to_parser_string())
-                        ),
-                        general_style,
-                    ),
-                    Err(_) => {
-                        Span::styled(format!("Completed: {}", card.date_completed), general_style)
+            let date_time_format = app.config.date_time_format;
+            let card_date_created = Span::styled(
+                format!(
+                    "Created: {}",
+                    date_time_format.format_timestamp(card.date_created)
+                ),
+                general_style,
+            );
+            let card_date_modified = Span::styled(
+                format!(
+                    "Modified: {}",
+                    date_time_format.format_timestamp(card.date_modified)
+                ),
+                general_style,
+            );
+            let card_date_completed = Span::styled(
+                format!(
+                    "Completed: {}",
+                    date_time_format.format_timestamp(card.date_completed)
+                ),
+                general_style,
+            );
+            let card_reminders = Span::styled(
+                format!(
+                    "Reminders: {}",
+                    if card.reminders.is_empty() {
+                        FIELD_NOT_SET.to_string()
+                    } else {
+                        format!("{} before due", format_reminder_offsets(&card.reminders))
                     }
-                }
-            };
+                ),
+                general_style,
+            );
             let card_priority = format!("Priority: {}", card.priority);
             let card_status = format!("Status: {}", card.card_status);
-            let parsed_due_date = if date_format_finder(&card.due_date).is_ok() {
-                date_format_converter(&card.due_date, app.config.date_time_format)
+            let card_due_date =
+                format!("Due: {}", date_time_format.format_timestamp(card.due_date));
+            let card_due_date_styled = if app.state.focus == Focus::CardDueDate {
+                Span::styled(card_due_date, list_select_style)
+            } else if !is_active {
+                Span::styled(card_due_date, app.current_theme.inactive_text_style)
             } else {
-                Ok(FIELD_NOT_SET.to_string())
-            };
-            let card_due_date_styled = if let Ok(parsed_due_date) = parsed_due_date {
-                if app.state.focus == Focus::CardDueDate {
-                    Span::styled(format!("Due: {}", parsed_due_date), list_select_style)
-                } else if parsed_due_date == FIELD_NOT_SET || parsed_due_date.is_empty() {
-                    Span::styled(format!("Due: {}", parsed_due_date), card_due_default_style)
-                } else {
-                    let formatted_date_format = date_format_finder(&parsed_due_date).unwrap();
-                    let days_left = match formatted_date_format {
-                        DateTimeFormat::DayMonthYear
-                        | DateTimeFormat::MonthDayYear
-                        | DateTimeFormat::YearMonthDay => {
-                            let today = Local::now().date_naive();
-                            let string_to_naive_date_format = NaiveDate::parse_from_str(
-                                &parsed_due_date,
-                                app.config.date_time_format.to_parser_string(),
-                            )
-                            .unwrap();
-                            string_to_naive_date_format
-                                .signed_duration_since(today)
-                                .num_days()
-                        }
-                        DateTimeFormat::DayMonthYearTime
-                        | DateTimeFormat::MonthDayYearTime
-                        | DateTimeFormat::YearMonthDayTime {} => {
-                            let today = Local::now().naive_local();
-                            let string_to_naive_date_format = NaiveDateTime::parse_from_str(
-                                &parsed_due_date,
-                                app.config.date_time_format.to_parser_string(),
-                            )
-                            .unwrap();
-                            string_to_naive_date_format
-                                .signed_duration_since(today)
-                                .num_days()
-                        }
-                    };
-                    if !is_active {
-                        Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            app.current_theme.inactive_text_style,
-                        )
-                    } else if days_left <= app.config.warning_delta.into() && days_left >= 0 {
-                        Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            app.current_theme.card_due_warning_style,
-                        )
-                    } else if days_left < 0 {
-                        Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            app.current_theme.card_due_overdue_style,
-                        )
-                    } else {
-                        Span::styled(format!("Due: {}", parsed_due_date), card_due_default_style)
+                match card.get_days_until_due() {
+                    Some(days_left) if days_left < 0 => {
+                        Span::styled(card_due_date, app.current_theme.card_due_overdue_style)
                     }
+                    Some(days_left) if days_left <= app.config.warning_delta.into() => {
+                        Span::styled(card_due_date, app.current_theme.card_due_warning_style)
+                    }
+                    _ => Span::styled(card_due_date, card_due_default_style),
                 }
-            } else if app.state.focus == Focus::CardDueDate {
-                Span::styled(format!("Due: {}", FIELD_NOT_SET), list_select_style)
-            } else {
-                Span::styled(format!("Due: {}", FIELD_NOT_SET), general_style)
             };
             let card_priority_styled = if !is_active {
                 Span::styled(card_priority, app.current_theme.inactive_text_style)
@@ -354,6 +254,7 @@ impl Renderable for ViewCard {
                 ListItem::new(vec![Line::from(card_date_completed)]),
                 ListItem::new(vec![Line::from(card_priority_styled)]),
                 ListItem::new(vec![Line::from(card_status_styled)]),
+                ListItem::new(vec![Line::from(card_reminders)]),
             ];
             let card_extra_info_items_len = card_extra_info_items.len();
             let card_extra_info = List::new(card_extra_info_items).block(
@@ -385,7 +286,7 @@ impl Renderable for ViewCard {
                     for (index, tag) in card.tags.iter().enumerate() {
                         tags.push(Span::styled(
                             format!("{}) {} ", index + 1, tag),
-                            general_style,
+                            get_tag_style(app, tag, is_active, general_style),
                         ));
                     }
                 } else {
@@ -404,7 +305,7 @@ impl Renderable for ViewCard {
                         } else {
                             tags.push(Span::styled(
                                 format!("{}) {} ", index + 1, tag),
-                                general_style,
+                                get_tag_style(app, tag, is_active, general_style),
                             ));
                         }
                     }
@@ -415,7 +316,7 @@ impl Renderable for ViewCard {
                 for (index, tag) in card.tags.iter().enumerate() {
                     tags.push(Span::styled(
                         format!("{}) {} ", index + 1, tag),
-                        general_style,
+                        get_tag_style(app, tag, is_active, general_style),
                     ));
                 }
                 tags
@@ -532,7 +433,7 @@ impl Renderable for ViewCard {
             let max_height: u16 = popup_area.height - border_height;
             let submit_button_height: u16 = 3;
             let card_name_box_height: u16 = 3;
-            let card_extra_info_height: u16 = 8;
+            let card_extra_info_height = card_extra_info_items_len as u16 + 2;
             let mut available_height: u16 = if app.state.card_being_edited.is_some() {
                 max_height - card_name_box_height - card_extra_info_height - submit_button_height
             } else {
@@ -3,8 +3,9 @@ use crate::{
     constants::{LIST_SELECTED_SYMBOL, TAG_SELECTOR_HEIGHT, TAG_SELECTOR_WIDTH},
     ui::{
         rendering::{
-            common::render_blank_styled_canvas, popup::widgets::TagPicker,
-            utils::check_if_active_and_get_style,
+            common::render_blank_styled_canvas,
+            popup::widgets::TagPicker,
+            utils::{check_if_active_and_get_style, get_tag_style},
         },
         widgets::SelfViewportCorrection,
         Renderable,
@@ -34,7 +35,9 @@ impl Renderable for TagPicker {
             .tag_picker
             .available_tags
             .iter()
-            .map(|tag| ListItem::new(tag.clone()))
+            .map(|tag| {
+                ListItem::new(tag.clone()).style(get_tag_style(app, tag, is_active, general_style))
+            })
             .collect::<Vec<ListItem>>();
 
         let anchor = app
@@ -26,6 +26,14 @@ pub fn check_if_active_and_get_style(
     }
 }
 
+/// Applies the colour configured for a tag to the style passed, inactive elements keep their style
+pub fn get_tag_style(app: &App, tag: &str, is_active: bool, style: Style) -> Style {
+    match app.config.get_tag_color(tag) {
+        Some(color) if is_active => style.fg(color),
+        _ => style,
+    }
+}
+
 pub fn check_for_card_drag_and_get_style(
     card_drag_mode: bool,
     is_active: bool,
@@ -38,7 +38,7 @@ impl Renderable for LoadASave {
                 [
                     Constraint::Length(3),
                     Constraint::Fill(1),
-                    Constraint::Length(9),
+                    Constraint::Length(10),
                 ]
                 .as_ref(),
             )
@@ -146,6 +146,9 @@ impl Renderable for LoadASave {
         let delete_key = app
             .get_first_keybinding(KeyBindingEnum::DeleteCard)
             .unwrap_or("".to_string());
+        let prune_key = app
+            .get_first_keybinding(KeyBindingEnum::DeleteBoard)
+            .unwrap_or("".to_string());
         let accept_key = app
             .get_first_keybinding(KeyBindingEnum::Accept)
             .unwrap_or("".to_string());
@@ -164,8 +167,10 @@ impl Renderable for LoadASave {
             Span::styled(cancel_key, help_key_style),
             Span::styled(" to cancel. Press ", help_text_style),
             Span::styled(delete_key, help_key_style),
+            Span::styled(" to delete a save file. Press ", help_text_style),
+            Span::styled(prune_key, help_key_style),
             Span::styled(
-                " to delete a save file. If using a mouse click on a save file to preview",
+                " to prune old saves. If using a mouse click on a save file to preview",
                 help_text_style,
             ),
         ]);
@@ -16,6 +16,7 @@ pub mod new_board_form;
 pub mod new_card_form;
 pub mod reset_password;
 pub mod signup;
+pub mod tag_manager;
 pub mod title_body;
 pub mod title_body_help;
 pub mod title_body_help_log;
@@ -42,5 +43,6 @@ pub struct LoadASave;
 pub struct CreateTheme;
 pub struct Login;
 pub struct Signup;
+pub struct TagManager;
 pub struct ResetPassword;
 pub struct LoadCloudSave;
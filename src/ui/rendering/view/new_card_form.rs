@@ -32,6 +32,7 @@ impl Renderable for NewCardForm {
                     Constraint::Length(5),
                     Constraint::Fill(1),
                     Constraint::Length(3),
+                    Constraint::Length(3),
                     Constraint::Length(4),
                     Constraint::Length(3),
                 ]
@@ -70,6 +71,8 @@ impl Renderable for NewCardForm {
         );
         let due_date_style =
             get_mouse_focusable_field_style(app, Focus::CardDueDate, &chunks[3], is_active, false);
+        let template_style =
+            get_mouse_focusable_field_style(app, Focus::ExtraFocus, &chunks[4], is_active, false);
         let help_key_style = check_if_active_and_get_style(
             is_active,
             app.current_theme.inactive_text_style,
@@ -81,7 +84,7 @@ impl Renderable for NewCardForm {
             app.current_theme.help_text_style,
         );
         let submit_style =
-            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[5], is_active, false);
+            get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[6], is_active, false);
 
         let title_paragraph = Paragraph::new("Create a new Card")
             .alignment(Alignment::Center)
@@ -125,15 +128,40 @@ impl Renderable for NewCardForm {
             .widgets
             .date_time_picker
             .get_date_time_as_string(app.config.date_time_format);
+        let card_due_date_title = format!(
+            "Due Date ({} to type a date, {} to pick one)",
+            app.get_first_keybinding(KeyBindingEnum::TakeUserInput)
+                .unwrap_or("".to_string()),
+            app.get_first_keybinding(KeyBindingEnum::Accept)
+                .unwrap_or("".to_string())
+        );
         let card_due_date_paragraph = Paragraph::new(card_due_date).block(
             Block::default()
-                .title("Due Date")
+                .title(card_due_date_title)
                 .borders(Borders::ALL)
                 .style(due_date_style)
                 .border_type(BorderType::Rounded),
         );
         rect.render_widget(card_due_date_paragraph, chunks[3]);
 
+        let template_text = if let Some(template) = &app.state.applied_card_template {
+            format!("{} ({})", template.name, template.summary())
+        } else {
+            "None".to_string()
+        };
+        let template_paragraph = Paragraph::new(template_text).block(
+            Block::default()
+                .title(format!(
+                    "Template ({} to pick one)",
+                    app.get_first_keybinding(KeyBindingEnum::Accept)
+                        .unwrap_or("".to_string())
+                ))
+                .borders(Borders::ALL)
+                .style(template_style)
+                .border_type(BorderType::Rounded),
+        );
+        rect.render_widget(template_paragraph, chunks[4]);
+
         let input_mode_key = app
             .get_first_keybinding(KeyBindingEnum::TakeUserInput)
             .unwrap_or("".to_string());
@@ -180,7 +208,7 @@ impl Renderable for NewCardForm {
                     .border_style(general_style),
             )
             .wrap(ratatui::widgets::Wrap { trim: true });
-        rect.render_widget(help_paragraph, chunks[4]);
+        rect.render_widget(help_paragraph, chunks[5]);
 
         let submit_button = Paragraph::new("Submit").alignment(Alignment::Center).block(
             Block::default()
@@ -188,7 +216,7 @@ impl Renderable for NewCardForm {
                 .style(submit_style)
                 .border_type(BorderType::Rounded),
         );
-        rect.render_widget(submit_button, chunks[5]);
+        rect.render_widget(submit_button, chunks[6]);
 
         if app.state.app_status == AppStatus::UserInput {
             match app.state.focus {
@@ -0,0 +1,195 @@
+use crate::{
+    app::{
+        state::{Focus, KeyBindingEnum},
+        App,
+    },
+    constants::{SCROLLBAR_BEGIN_SYMBOL, SCROLLBAR_END_SYMBOL, SCROLLBAR_TRACK_SYMBOL},
+    ui::{
+        rendering::{
+            common::{draw_title, render_close_button},
+            utils::{
+                check_if_active_and_get_style, get_mouse_focusable_field_style, get_tag_style,
+            },
+            view::TagManager,
+        },
+        Renderable,
+    },
+};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Margin},
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation,
+        ScrollbarState, Table,
+    },
+    Frame,
+};
+
+impl Renderable for TagManager {
+    fn render(rect: &mut Frame, app: &mut App, is_active: bool) {
+        let chunks = Layout::default()
+            .constraints(
+                [
+                    Constraint::Length(3),
+                    Constraint::Fill(1),
+                    Constraint::Length(5),
+                ]
+                .as_ref(),
+            )
+            .split(rect.area());
+
+        let default_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.general_style,
+        );
+        let scrollbar_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.progress_bar_style,
+        );
+        let current_element_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.list_select_style,
+        );
+        let table_border_style = get_mouse_focusable_field_style(
+            app,
+            Focus::TagManagerTable,
+            &chunks[1],
+            is_active,
+            false,
+        );
+        let help_key_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_key_style,
+        );
+        let help_text_style = check_if_active_and_get_style(
+            is_active,
+            app.current_theme.inactive_text_style,
+            app.current_theme.help_text_style,
+        );
+
+        let up_key = app
+            .get_first_keybinding(KeyBindingEnum::Up)
+            .unwrap_or("".to_string());
+        let down_key = app
+            .get_first_keybinding(KeyBindingEnum::Down)
+            .unwrap_or("".to_string());
+        let accept_key = app
+            .get_first_keybinding(KeyBindingEnum::Accept)
+            .unwrap_or("".to_string());
+        let cancel_key = app
+            .get_first_keybinding(KeyBindingEnum::GoToPreviousViewOrCancel)
+            .unwrap_or("".to_string());
+        let undo_key = app
+            .get_first_keybinding(KeyBindingEnum::Undo)
+            .unwrap_or("".to_string());
+
+        let tag_manager_help_spans = Line::from(vec![
+            Span::styled("Use ", help_text_style),
+            Span::styled(up_key, help_key_style),
+            Span::styled(" and ", help_text_style),
+            Span::styled(down_key, help_key_style),
+            Span::styled(" or scroll with the mouse", help_text_style),
+            Span::styled(" to select a tag, Press ", help_text_style),
+            Span::styled(accept_key, help_key_style),
+            Span::styled(" or ", help_text_style),
+            Span::styled("<Mouse Left Click>", help_key_style),
+            Span::styled(" to rename, merge, colour or delete it, ", help_text_style),
+            Span::styled(undo_key, help_key_style),
+            Span::styled(" to undo a change, ", help_text_style),
+            Span::styled(cancel_key, help_key_style),
+            Span::styled(" to go back", help_text_style),
+        ]);
+
+        let all_tags = app.calculate_tags();
+        // Renames, merges and undo can shrink the list under the selection
+        if all_tags.is_empty() {
+            app.state.app_table_states.tag_manager.select(None);
+        } else if app
+            .state
+            .app_table_states
+            .tag_manager
+            .selected()
+            .is_none_or(|selected| selected >= all_tags.len())
+        {
+            app.state.app_table_states.tag_manager.select(Some(0));
+        }
+        let rows = all_tags.iter().map(|(tag, count)| {
+            let color_name = app
+                .config
+                .get_tag_color(tag)
+                .map(|color| color.to_string())
+                .unwrap_or("None".to_string());
+            Row::new(vec![
+                Cell::from(tag.clone()).style(get_tag_style(app, tag, is_active, default_style)),
+                Cell::from(count.to_string()),
+                Cell::from(color_name),
+            ])
+        });
+
+        let current_index = app
+            .state
+            .app_table_states
+            .tag_manager
+            .selected()
+            .unwrap_or(0);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(SCROLLBAR_BEGIN_SYMBOL)
+            .style(scrollbar_style)
+            .end_symbol(SCROLLBAR_END_SYMBOL)
+            .track_symbol(SCROLLBAR_TRACK_SYMBOL)
+            .track_style(app.current_theme.inactive_text_style);
+        let mut scrollbar_state = ScrollbarState::new(all_tags.len()).position(current_index);
+        let scrollbar_area = chunks[1].inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        });
+
+        let title = if all_tags.is_empty() {
+            "Tag Manager (no tags found on any card)".to_string()
+        } else {
+            format!("Tag Manager ({} tags)", all_tags.len())
+        };
+        let t = Table::new(
+            rows,
+            [
+                Constraint::Fill(2),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ],
+        )
+        .header(Row::new(vec!["Tag", "Cards", "Colour"]).style(help_text_style))
+        .block(
+            Block::default()
+                .title(title)
+                .style(default_style)
+                .border_style(table_border_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .row_highlight_style(current_element_style)
+        .highlight_symbol(">> ");
+
+        let tag_manager_help = Paragraph::new(tag_manager_help_spans)
+            .block(
+                Block::default()
+                    .title("Help")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .style(default_style)
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+
+        rect.render_widget(draw_title(app, chunks[0], is_active), chunks[0]);
+        rect.render_stateful_widget(t, chunks[1], &mut app.state.app_table_states.tag_manager);
+        rect.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
+        rect.render_widget(tag_manager_help, chunks[2]);
+        if app.config.enable_mouse_support {
+            render_close_button(rect, app, is_active)
+        }
+    }
+}
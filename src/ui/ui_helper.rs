@@ -1,7 +1,7 @@
 use crate::{
     app::App,
     constants::{
-        MAX_TOASTS_TO_DISPLAY, MIN_TERM_HEIGHT, MIN_TERM_WIDTH, SCREEN_TO_TOAST_WIDTH_RATIO,
+        MAX_TOASTS_TO_DISPLAY, MIN_COMPACT_TERM_WIDTH, MIN_TERM_HEIGHT, SCREEN_TO_TOAST_WIDTH_RATIO,
     },
     ui::{
         rendering::{
@@ -75,10 +75,10 @@ pub fn draw_loading_screen(rect: &mut Frame, size: &Rect, app: &mut App) {
 }
 
 pub fn check_size(rect: &Rect) -> Result<(), String> {
-    if rect.width < MIN_TERM_WIDTH {
+    if rect.width < MIN_COMPACT_TERM_WIDTH {
         Err(format!(
             "For optimal viewing experience, Terminal width should be >= {}, (current width {})",
-            MIN_TERM_WIDTH, rect.width
+            MIN_COMPACT_TERM_WIDTH, rect.width
         ))
     } else if rect.height < MIN_TERM_HEIGHT {
         Err(format!(
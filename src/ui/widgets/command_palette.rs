@@ -1,12 +1,12 @@
 use crate::{
     app::{
-        app_helper::reset_preview_boards,
+        app_helper::{handle_duplicate_board, handle_open_duplicate_card, reset_preview_boards},
         handle_exit,
         state::{AppState, AppStatus, Focus},
-        App, AppReturn,
+        ActionHistory, App, AppReturn,
     },
     constants::RANDOM_SEARCH_TERM,
-    io::{io_handler::refresh_visible_boards_and_cards, IoEvent},
+    io::{export::ExportFormat, io_handler::refresh_visible_boards_and_cards, IoEvent},
     ui::{widgets::Widget, PopUp, View},
     util::{send_error_toast, send_info_toast, send_warning_toast},
 };
@@ -90,6 +90,10 @@ impl CommandPaletteWidget {
                         app.set_view(View::ConfigMenu);
                         app.state.app_table_states.config.select(Some(0));
                     }
+                    CommandPaletteActions::TagManager => {
+                        app.close_popup();
+                        app.set_view(View::TagManager);
+                    }
                     CommandPaletteActions::MainMenu => {
                         app.close_popup();
                         app.set_view(View::MainMenu);
@@ -104,6 +108,93 @@ impl CommandPaletteWidget {
                         app.close_popup();
                         app.dispatch(IoEvent::SaveLocalData).await;
                     }
+                    CommandPaletteActions::ExportToHtml => {
+                        app.close_popup();
+                        app.dispatch(IoEvent::ExportBoards(ExportFormat::Html))
+                            .await;
+                    }
+                    CommandPaletteActions::ExportToICalendar => {
+                        app.close_popup();
+                        app.dispatch(IoEvent::ExportBoards(ExportFormat::ICalendar))
+                            .await;
+                    }
+                    CommandPaletteActions::ExportToTodoTxt => {
+                        app.close_popup();
+                        app.dispatch(IoEvent::ExportBoards(ExportFormat::TodoTxt))
+                            .await;
+                    }
+                    CommandPaletteActions::ImportFromTodoTxt => {
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            if app.state.current_board_id.is_none() {
+                                send_error_toast(
+                                    &mut app.widgets.toast_widget,
+                                    "No board Selected / Available",
+                                );
+                                app.close_popup();
+                                app.state.app_status = AppStatus::Initialized;
+                                return AppReturn::Continue;
+                            }
+                            app.close_popup();
+                            app.set_popup(PopUp::ImportTodoTxtPrompt);
+                        } else {
+                            app.close_popup();
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot import cards in this view",
+                            );
+                        }
+                    }
+                    CommandPaletteActions::SaveCurrentCardAsTemplate => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.close_popup();
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot save a card template in this view",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        let current_card_exists = app
+                            .state
+                            .current_board_id
+                            .zip(app.state.current_card_id)
+                            .and_then(|(board_id, card_id)| {
+                                app.boards
+                                    .get_board_with_id(board_id)
+                                    .and_then(|board| board.cards.get_card_with_id(card_id))
+                            })
+                            .is_some();
+                        app.close_popup();
+                        if current_card_exists {
+                            app.set_popup(PopUp::SaveCardTemplatePrompt);
+                        } else {
+                            app.state.app_status = AppStatus::Initialized;
+                            send_error_toast(&mut app.widgets.toast_widget, "No card selected");
+                        }
+                    }
+                    CommandPaletteActions::DuplicateCurrentCard => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            handle_open_duplicate_card(app);
+                        } else {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot duplicate a card in this view",
+                            );
+                        }
+                    }
+                    CommandPaletteActions::DuplicateCurrentBoard
+                    | CommandPaletteActions::DuplicateCurrentBoardWithoutCards => {
+                        let with_cards = *command == CommandPaletteActions::DuplicateCurrentBoard;
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            handle_duplicate_board(app, with_cards);
+                        } else {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot duplicate a board in this view",
+                            );
+                        }
+                    }
                     CommandPaletteActions::NewBoard => {
                         if View::views_with_kanban_board().contains(&app.state.current_view) {
                             app.close_popup();
@@ -116,6 +207,26 @@ impl CommandPaletteWidget {
                             );
                         }
                     }
+                    CommandPaletteActions::NewBoardsFromTemplate => {
+                        app.close_popup();
+                        if View::views_with_kanban_board().contains(&app.state.current_view) {
+                            app.set_popup(PopUp::BoardTemplatePicker);
+                        } else {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot create new boards in this view",
+                            );
+                        }
+                    }
+                    CommandPaletteActions::SaveBoardsAsTemplate => {
+                        app.close_popup();
+                        if app.boards.is_empty() {
+                            app.state.app_status = AppStatus::Initialized;
+                            send_error_toast(&mut app.widgets.toast_widget, "No boards to save");
+                        } else {
+                            app.set_popup(PopUp::SaveBoardTemplatePrompt);
+                        }
+                    }
                     CommandPaletteActions::NewCard => {
                         if View::views_with_kanban_board().contains(&app.state.current_view) {
                             if app.state.current_board_id.is_none() {
@@ -246,6 +357,50 @@ impl CommandPaletteWidget {
                             app.state.all_available_tags = Some(tags);
                         }
                     }
+                    CommandPaletteActions::BulkActions => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot use bulk actions in this view",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        if app.state.selected_cards.is_empty() {
+                            send_warning_toast(
+                                &mut app.widgets.toast_widget,
+                                "No cards selected, select cards before using bulk actions",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        app.set_popup(PopUp::BulkActions);
+                    }
+                    CommandPaletteActions::UndoHistory => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot view undo history in this view",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        app.set_popup(PopUp::UndoHistory);
+                    }
+                    CommandPaletteActions::GoToBoard => {
+                        if !View::views_with_kanban_board().contains(&app.state.current_view) {
+                            send_error_toast(
+                                &mut app.widgets.toast_widget,
+                                "Cannot go to a board in this view",
+                            );
+                            return AppReturn::Continue;
+                        }
+                        if app.boards.is_empty() {
+                            send_warning_toast(&mut app.widgets.toast_widget, "No boards to go to");
+                            return AppReturn::Continue;
+                        }
+                        app.close_popup();
+                        app.set_popup(PopUp::GoToBoard);
+                    }
                     CommandPaletteActions::ClearFilter => {
                         if app.filtered_boards.is_empty() {
                             send_warning_toast(
@@ -299,7 +454,7 @@ impl CommandPaletteWidget {
                         app.close_popup();
                     }
                     CommandPaletteActions::LoadASaveCloud => {
-                        if app.state.user_login_data.auth_token.is_some() {
+                        if app.main_menu.logged_in {
                             app.set_view(View::LoadCloudSave);
                             reset_preview_boards(app);
                             app.dispatch(IoEvent::GetCloudData).await;
@@ -340,11 +495,19 @@ impl CommandPaletteWidget {
                                 .boards
                                 .swap(current_board_index, current_board_index - 1);
 
-                            if swap_result.is_err() {
+                            if swap_result.is_ok() {
+                                app.action_history_manager
+                                    .new_action(ActionHistory::MoveBoard(
+                                        current_board_id,
+                                        current_board_index,
+                                        current_board_index - 1,
+                                    ));
+                            } else {
                                 send_error_toast(
                                     &mut app.widgets.toast_widget,
                                     format!("Could not move '{}' to the left", board_name).as_str(),
                                 );
+                                return AppReturn::Continue;
                             }
 
                             app.close_popup();
@@ -385,12 +548,20 @@ impl CommandPaletteWidget {
                                 .boards
                                 .swap(current_board_index, current_board_index + 1);
 
-                            if swap_result.is_err() {
+                            if swap_result.is_ok() {
+                                app.action_history_manager
+                                    .new_action(ActionHistory::MoveBoard(
+                                        current_board_id,
+                                        current_board_index,
+                                        current_board_index + 1,
+                                    ));
+                            } else {
                                 send_error_toast(
                                     &mut app.widgets.toast_widget,
                                     format!("Could not move '{}' to the right", board_name)
                                         .as_str(),
                                 );
+                                return AppReturn::Continue;
                             }
 
                             app.close_popup();
@@ -415,9 +586,20 @@ impl CommandPaletteWidget {
             app.widgets.command_palette.already_in_user_input_mode = false;
             app.widgets.command_palette.last_focus = None;
         }
-        if app.state.z_stack.last() != Some(&PopUp::CustomHexColorPromptFG)
-            || app.state.z_stack.last() != Some(&PopUp::CustomHexColorPromptBG)
-        {
+        if !matches!(
+            app.state.z_stack.last(),
+            Some(
+                PopUp::CustomHexColorPromptFG
+                    | PopUp::CustomHexColorPromptBG
+                    | PopUp::GoToBoard
+                    | PopUp::MoveSelectedCardsToBoard
+                    | PopUp::DuplicateCardToBoard
+                    | PopUp::BulkEditPrompt
+                    | PopUp::ImportTodoTxtPrompt
+                    | PopUp::SaveCardTemplatePrompt
+                    | PopUp::SaveBoardTemplatePrompt
+            )
+        ) {
             app.state.app_status = AppStatus::Initialized;
         }
         AppReturn::Continue
@@ -573,6 +755,7 @@ impl Widget for CommandPaletteWidget {
 
 #[derive(Clone, Debug, PartialEq, EnumIter, EnumString)]
 pub enum CommandPaletteActions {
+    BulkActions,
     ChangeCurrentCardStatus,
     ChangeCurrentCardPriority,
     ChangeDateFormat,
@@ -582,22 +765,35 @@ pub enum CommandPaletteActions {
     ConfigMenu,
     CreateATheme,
     DebugMenu,
+    DuplicateCurrentBoard,
+    DuplicateCurrentBoardWithoutCards,
+    DuplicateCurrentCard,
+    ExportToHtml,
+    ExportToICalendar,
+    ExportToTodoTxt,
     FilterByTag,
+    GoToBoard,
     HelpMenu,
+    ImportFromTodoTxt,
     LoadASaveCloud,
     LoadASaveLocal,
     Login,
     Logout,
     MainMenu,
     NewBoard,
+    NewBoardsFromTemplate,
     NewCard,
     NoCommandsFound,
     Quit,
     ResetPassword,
     ResetUI,
+    SaveBoardsAsTemplate,
+    SaveCurrentCardAsTemplate,
     SaveKanbanState,
     SignUp,
     SyncLocalData,
+    TagManager,
+    UndoHistory,
     MoveBoardLeft,
     MoveBoardRight,
 }
@@ -605,6 +801,7 @@ pub enum CommandPaletteActions {
 impl Display for CommandPaletteActions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::BulkActions => write!(f, "Bulk Actions on Selected Cards"),
             Self::ChangeCurrentCardStatus => write!(f, "Change Current Card Status"),
             Self::ChangeCurrentCardPriority => write!(f, "Change Current Card Priority"),
             Self::ChangeDateFormat => write!(f, "Change Date Format"),
@@ -613,12 +810,23 @@ impl Display for CommandPaletteActions {
             Self::ClearFilter => write!(f, "Clear Filter"),
             Self::CreateATheme => write!(f, "Create a Theme"),
             Self::DebugMenu => write!(f, "Toggle Debug Panel"),
+            Self::DuplicateCurrentBoard => write!(f, "Duplicate Current Board"),
+            Self::DuplicateCurrentBoardWithoutCards => {
+                write!(f, "Duplicate Current Board Without Cards")
+            }
+            Self::DuplicateCurrentCard => write!(f, "Duplicate Current Card"),
+            Self::ExportToHtml => write!(f, "Export to HTML"),
+            Self::ExportToICalendar => write!(f, "Export to iCalendar (.ics)"),
+            Self::ExportToTodoTxt => write!(f, "Export to todo.txt"),
+            Self::ImportFromTodoTxt => write!(f, "Import from todo.txt"),
             Self::FilterByTag => write!(f, "Filter by Tag"),
+            Self::GoToBoard => write!(f, "Go to Board"),
             Self::LoadASaveCloud => write!(f, "Load a Save (Cloud)"),
             Self::LoadASaveLocal => write!(f, "Load a Save (Local)"),
             Self::Login => write!(f, "Login"),
             Self::Logout => write!(f, "Logout"),
             Self::NewBoard => write!(f, "New Board"),
+            Self::NewBoardsFromTemplate => write!(f, "New Boards from Template"),
             Self::NewCard => write!(f, "New Card"),
             Self::NoCommandsFound => write!(f, "No Commands Found"),
             Self::ConfigMenu => write!(f, "Configure"),
@@ -627,9 +835,13 @@ impl Display for CommandPaletteActions {
             Self::Quit => write!(f, "Quit"),
             Self::ResetPassword => write!(f, "Reset Password"),
             Self::ResetUI => write!(f, "Reset UI"),
+            Self::SaveBoardsAsTemplate => write!(f, "Save Boards as Template"),
+            Self::SaveCurrentCardAsTemplate => write!(f, "Save Current Card as Template"),
             Self::SaveKanbanState => write!(f, "Save Kanban State"),
             Self::SignUp => write!(f, "Sign Up"),
             Self::SyncLocalData => write!(f, "Sync Local Data"),
+            Self::TagManager => write!(f, "Manage Tags"),
+            Self::UndoHistory => write!(f, "Undo History"),
             Self::MoveBoardLeft => write!(f, "Move Current Board Left"),
             Self::MoveBoardRight => write!(f, "Move Current Board Right"),
         }
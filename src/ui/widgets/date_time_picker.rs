@@ -10,7 +10,7 @@ use crate::{
         PopUp, View,
     },
 };
-use chrono::{Datelike, NaiveDate, Timelike};
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Timelike};
 use log::debug;
 use ratatui::{
     layout::Rect,
@@ -609,6 +609,12 @@ impl DateTimePickerWidget<'_> {
         self.calculated_mouse_coords = Some((record, date, render_area));
     }
 
+    pub fn get_selected_date_time(&self) -> Option<DateTime<Local>> {
+        self.selected_date_time.and_then(|selected_date_time| {
+            Local.from_local_datetime(&selected_date_time).earliest()
+        })
+    }
+
     pub fn get_date_time_as_string(&self, date_time_format: DateTimeFormat) -> String {
         if let Some(selected_date) = self.selected_date_time {
             selected_date
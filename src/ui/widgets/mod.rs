@@ -3,6 +3,7 @@ use close_button::CloseButtonWidget;
 use command_palette::CommandPaletteWidget;
 use date_time_picker::{CalenderType, DateTimePickerWidget};
 use ratatui::layout::Rect;
+use reminder::ReminderWidget;
 use std::sync::Arc;
 use tag_picker::TagPickerWidget;
 use toast::ToastWidget;
@@ -10,6 +11,7 @@ use toast::ToastWidget;
 pub mod close_button;
 pub mod command_palette;
 pub mod date_time_picker;
+pub mod reminder;
 pub mod tag_picker;
 pub mod toast;
 
@@ -33,6 +35,7 @@ impl WidgetManager<'_> {
         CloseButtonWidget::update(&mut app);
         DateTimePickerWidget::update(&mut app);
         TagPickerWidget::update(&mut app);
+        ReminderWidget::update(&mut app);
     }
 }
 
@@ -42,6 +45,7 @@ pub struct Widgets<'a> {
     pub toast_widget: ToastWidget,
     pub date_time_picker: DateTimePickerWidget<'a>,
     pub tag_picker: TagPickerWidget,
+    pub reminder: ReminderWidget,
 }
 
 impl Widgets<'_> {
@@ -52,6 +56,7 @@ impl Widgets<'_> {
             toast_widget: ToastWidget::default(),
             date_time_picker: DateTimePickerWidget::new(calender_type),
             tag_picker: TagPickerWidget::default(),
+            reminder: ReminderWidget::default(),
         }
     }
 }
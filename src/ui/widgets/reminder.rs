@@ -0,0 +1,97 @@
+use crate::{
+    app::{
+        reminders::{build_reminder_command, get_due_reminders},
+        App,
+    },
+    constants::{REMINDER_CHECK_INTERVAL, REMINDER_TOAST_DURATION},
+    ui::widgets::{
+        toast::{Toast, ToastType},
+        Widget,
+    },
+};
+use chrono::{DateTime, Local};
+use std::{
+    process::Stdio,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug)]
+pub struct ReminderWidget {
+    last_check: Instant,
+    last_checked_time: DateTime<Local>,
+}
+
+impl Default for ReminderWidget {
+    fn default() -> Self {
+        Self {
+            last_check: Instant::now(),
+            last_checked_time: Local::now(),
+        }
+    }
+}
+
+impl Widget for ReminderWidget {
+    fn update(app: &mut App) {
+        if app.widgets.reminder.last_check.elapsed() < Duration::from_secs(REMINDER_CHECK_INTERVAL)
+        {
+            return;
+        }
+        let now = Local::now();
+        let due_reminders =
+            get_due_reminders(&app.boards, app.widgets.reminder.last_checked_time, now);
+        app.widgets.reminder.last_check = Instant::now();
+        app.widgets.reminder.last_checked_time = now;
+        for reminder in due_reminders {
+            let message = reminder.message(app.config.date_time_format);
+            log::info!("Reminder: {}", message);
+            app.widgets.toast_widget.toasts.push(Toast::new_with_title(
+                "Reminder".to_string(),
+                message,
+                Duration::from_secs(REMINDER_TOAST_DURATION),
+                ToastType::Warning,
+            ));
+            if app.config.reminder_command.is_empty() {
+                continue;
+            }
+            match build_reminder_command(
+                &app.config.reminder_command,
+                &reminder,
+                app.config.date_time_format,
+            ) {
+                Ok((program, args)) => {
+                    tokio::spawn(async move {
+                        // The terminal belongs to the ui, so the command must not read from
+                        // or write to it, anything it reports on stderr goes to the log
+                        let output = tokio::process::Command::new(&program)
+                            .args(&args)
+                            .stdin(Stdio::null())
+                            .stdout(Stdio::null())
+                            .stderr(Stdio::piped())
+                            .output()
+                            .await;
+                        match output {
+                            Ok(output) => {
+                                let stderr = String::from_utf8_lossy(&output.stderr);
+                                let stderr = stderr.trim();
+                                if !output.status.success() {
+                                    log::error!(
+                                        "Reminder command {} exited with {}: {}",
+                                        program,
+                                        output.status,
+                                        stderr
+                                    )
+                                } else if !stderr.is_empty() {
+                                    log::warn!("Reminder command {}: {}", program, stderr)
+                                }
+                            }
+                            Err(err) => {
+                                log::error!("Could not run reminder command {}: {}", program, err)
+                            }
+                        }
+                    });
+                }
+                Err(err) => log::error!("{}", err),
+            }
+        }
+    }
+}
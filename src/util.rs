@@ -1,29 +1,45 @@
 use crate::{
     app::{
+        kanban::Boards,
+        reminders::{build_reminder_command, get_due_reminders},
         state::{AppState, KeyBindings},
-        App, AppReturn, DateTimeFormat, VisibleBoardsAndCards,
+        App, AppConfig, AppReturn, DateTimeFormat, VisibleBoardsAndCards,
+    },
+    constants::{
+        DEFAULT_TOAST_DURATION, ENCRYPTION_KEY_FILE_NAME, ENCRYPTION_PASSPHRASE_ENV_VAR,
+        FILESYSTEM_SYNC_USER_ID, IO_EVENT_WAIT_TIME, MAX_EXIT_WAIT_TIME,
+        REMINDER_LAST_RUN_FILE_NAME, SAVE_FILE_SCHEMA_VERSION,
     },
-    constants::{DEFAULT_TOAST_DURATION, ENCRYPTION_KEY_FILE_NAME, FIELD_NOT_SET},
     inputs::{events::Events, key::Key, InputEvent},
     io::{
-        data_handler::reset_config,
+        data_handler::{
+            get_config, get_local_kanban_state, get_saved_themes, is_encrypted_file,
+            migrate_save_file, reset_config, save_kanban_state_locally,
+        },
+        export::{export_boards, get_default_export_path, ExportFormat},
         io_handler::{
-            delete_a_save_from_database, generate_new_encryption_key,
-            get_all_save_ids_and_creation_dates_for_user, get_config_dir, login_for_user,
+            delete_a_save_from_database, derive_encryption_key_from_passphrase,
+            generate_new_encryption_key, get_all_save_ids_and_creation_dates_for_user,
+            get_config_dir, get_latest_save_file, get_local_encryption_key_salt,
+            get_or_create_encryption_key_salt, get_user_encryption_key, login_for_user,
+            rotate_encryption_key_for_cloud_saves, save_encryption_key_salt_locally,
             save_user_encryption_key,
         },
+        sync_backend::SyncBackend,
+        todo_txt::parse_todo_txt,
         IoEvent,
     },
     ui::{
+        theme::Theme,
         ui_main,
         widgets::toast::{Toast, ToastType, ToastWidget},
     },
 };
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
 use crossterm::{event::EnableMouseCapture, execute};
 use eyre::Result;
 use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
-use std::{borrow::Cow, io::stdout, sync::Arc, time::Duration};
+use std::{borrow::Cow, fs, io::stdout, path::Path, sync::Arc, time::Duration};
 use tokio::time::Instant;
 
 pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
@@ -47,6 +63,8 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
 
     {
         let mut app = app.lock().await;
+        let terminal_size = terminal.size()?;
+        app.handle_resize(terminal_size.width, terminal_size.height);
         app.dispatch(IoEvent::Initialize).await;
     }
 
@@ -68,10 +86,13 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
         let result = match events.next().await {
             InputEvent::KeyBoardInput(key) => app.do_action(key).await,
             InputEvent::MouseAction(mouse_action) => app.handle_mouse(mouse_action).await,
+            InputEvent::Resize(width, height) => app.handle_resize(width, height),
             InputEvent::Tick => {
                 if app.state.previous_mouse_coordinates != app.state.current_mouse_coordinates {
                     app.state.previous_mouse_coordinates = app.state.current_mouse_coordinates;
                 }
+                app.handle_auto_sync().await;
+                app.handle_save_directory_check().await;
                 AppReturn::Continue
             }
         };
@@ -81,6 +102,20 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
         }
     }
 
+    // Let the save and sync dispatched on exit finish before the runtime shuts down
+    let exit_time = Instant::now();
+    loop {
+        let mut app = app.lock().await;
+        if !app.has_pending_io_events()
+            || exit_time.elapsed() > Duration::from_secs(MAX_EXIT_WAIT_TIME)
+        {
+            break;
+        }
+        terminal.draw(|rect| ui_main::draw(rect, &mut app))?;
+        drop(app);
+        tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
+    }
+
     execute!(stdout(), crossterm::event::DisableMouseCapture)?;
     terminal.clear()?;
     terminal.set_cursor_position((0, 0))?;
@@ -150,116 +185,114 @@ pub fn parse_hex_to_rgb(hex_string: &str) -> Option<(u8, u8, u8)> {
     }
 }
 
+/// Scores how well the search term matches the candidate as a case insensitive subsequence,
+/// consecutive characters and matches at the start of a word score higher, None if it does not match
+pub fn fuzzy_match_score(search_term: &str, candidate: &str) -> Option<i64> {
+    if search_term.is_empty() {
+        return Some(0);
+    }
+    let candidate_chars = candidate.to_lowercase().chars().collect::<Vec<char>>();
+    let mut score = 0;
+    let mut candidate_index = 0;
+    let mut last_match_index: Option<usize> = None;
+    for search_char in search_term.to_lowercase().chars() {
+        if search_char.is_whitespace() {
+            continue;
+        }
+        let match_index = candidate_chars[candidate_index..]
+            .iter()
+            .position(|c| *c == search_char)?
+            + candidate_index;
+        score += 1;
+        if match_index == 0 || !candidate_chars[match_index - 1].is_alphanumeric() {
+            score += 5;
+        }
+        if last_match_index.is_some_and(|last| last + 1 == match_index) {
+            score += 3;
+        }
+        last_match_index = Some(match_index);
+        candidate_index = match_index + 1;
+    }
+    // prefer shorter candidates when the match quality is the same
+    Some(score * 100 - candidate_chars.len() as i64)
+}
+
 // TODO: Find a way to get the terminal background color
 pub fn get_term_bg_color() -> (u8, u8, u8) {
     (0, 0, 0)
 }
 
-pub fn date_format_finder(date_string: &str) -> Result<DateTimeFormat, String> {
+/// Parses a date typed by the user or stored as text by older versions. The preferred format
+/// is tried first as day and month order is ambiguous, dates without a time are taken as midnight
+pub fn parse_date_time(
+    date_string: &str,
+    preferred_format: DateTimeFormat,
+) -> Result<DateTime<Local>, String> {
+    let date_string = date_string.trim();
     let all_formats_with_time = DateTimeFormat::all_formats_with_time();
-    for date_format in DateTimeFormat::get_all_date_formats() {
-        if all_formats_with_time.contains(&date_format) {
-            match NaiveDateTime::parse_from_str(date_string, date_format.to_parser_string()) {
-                Ok(_) => return Ok(date_format),
-                Err(_) => {
-                    continue;
-                }
-            }
+    let preferred_formats = [
+        DateTimeFormat::add_time_to_date_format(preferred_format),
+        DateTimeFormat::remove_time_from_date_format(preferred_format),
+    ];
+    for date_format in preferred_formats
+        .into_iter()
+        .chain(DateTimeFormat::get_all_date_formats())
+    {
+        let naive_date_time = if all_formats_with_time.contains(&date_format) {
+            NaiveDateTime::parse_from_str(date_string, date_format.to_parser_string()).ok()
         } else {
-            match NaiveDate::parse_from_str(date_string, date_format.to_parser_string()) {
-                Ok(_) => return Ok(date_format),
-                Err(_) => {
-                    continue;
-                }
-            }
+            NaiveDate::parse_from_str(date_string, date_format.to_parser_string())
+                .ok()
+                .map(|naive_date| naive_date.and_time(NaiveTime::MIN))
+        };
+        if let Some(date_time) = naive_date_time
+            .and_then(|naive_date_time| Local.from_local_datetime(&naive_date_time).earliest())
+        {
+            return Ok(date_time);
         }
     }
     Err("Invalid date format".to_string())
 }
 
-pub fn date_format_converter(
-    date_string: &str,
-    date_format: DateTimeFormat,
-) -> Result<String, String> {
-    if date_string == FIELD_NOT_SET || date_string.is_empty() {
-        return Ok(date_string.to_string());
-    }
-    let given_date_format = date_format_finder(date_string)?;
-    if given_date_format == date_format {
-        return Ok(date_string.to_string());
+/// Logs in when the sync backend has accounts, returns the access token and user id
+async fn login_for_key_change(
+    sync_backend: &impl SyncBackend,
+    email_id: Option<String>,
+    password: Option<String>,
+) -> Option<(String, String)> {
+    if !sync_backend.requires_login() {
+        return Some((String::new(), FILESYSTEM_SYNC_USER_ID.to_string()));
     }
-    let all_formats_with_time = DateTimeFormat::all_formats_with_time();
-    let all_formats_without_time = DateTimeFormat::all_formats_without_time();
-    if all_formats_with_time.contains(&given_date_format)
-        && all_formats_without_time.contains(&date_format)
-    {
-        let naive_date_time =
-            NaiveDateTime::parse_from_str(date_string, given_date_format.to_parser_string());
-        if let Ok(naive_date_time) = naive_date_time {
-            let naive_date = NaiveDate::from_ymd_opt(
-                naive_date_time.year(),
-                naive_date_time.month(),
-                naive_date_time.day(),
-            );
-            if let Some(naive_date) = naive_date {
-                Ok(naive_date
-                    .format(date_format.to_parser_string())
-                    .to_string())
-            } else {
-                Err("Invalid date format".to_string())
-            }
-        } else {
-            Err("Invalid date format".to_string())
-        }
-    } else if all_formats_without_time.contains(&given_date_format)
-        && all_formats_with_time.contains(&date_format)
-    {
-        let naive_date =
-            NaiveDate::parse_from_str(date_string, given_date_format.to_parser_string());
-        if let Ok(naive_date) = naive_date {
-            let default_time = NaiveTime::from_hms_opt(0, 0, 0);
-            if let Some(default_time) = default_time {
-                let naive_date_time = NaiveDateTime::new(naive_date, default_time);
-                return Ok(naive_date_time
-                    .format(date_format.to_parser_string())
-                    .to_string());
-            } else {
-                Err("Invalid date format".to_string())
-            }
-        } else {
-            Err("Invalid date format".to_string())
-        }
-    } else if all_formats_with_time.contains(&given_date_format)
-        && all_formats_with_time.contains(&date_format)
-    {
-        let naive_date_time =
-            NaiveDateTime::parse_from_str(date_string, given_date_format.to_parser_string());
-        if let Ok(naive_date_time) = naive_date_time {
-            return Ok(naive_date_time
-                .format(date_format.to_parser_string())
-                .to_string());
-        } else {
-            Err("Invalid date format".to_string())
-        }
-    } else if all_formats_without_time.contains(&given_date_format)
-        && all_formats_without_time.contains(&date_format)
-    {
-        let naive_date =
-            NaiveDate::parse_from_str(date_string, given_date_format.to_parser_string());
-        if let Ok(naive_date) = naive_date {
-            return Ok(naive_date
-                .format(date_format.to_parser_string())
-                .to_string());
-        } else {
-            Err("Invalid date format".to_string())
-        }
+    let (email_id, password) = if let (Some(email_id), Some(password)) = (email_id, password) {
+        (email_id, password)
     } else {
-        Err("Invalid date format".to_string())
+        print_error(
+            "Please provide your email id (-e) and password (-p) to reset your encryption key",
+        );
+        return None;
+    };
+    print_info("Trying to login...");
+    match login_for_user(sync_backend, &email_id, &password, false).await {
+        Ok((access_token, user_id, _refresh_token)) => Some((access_token, user_id)),
+        Err(err) => {
+            print_debug(&format!("Error logging in: {:?}", err));
+            print_error("Error logging in, please check your credentials and try again");
+            print_error("Aborting...");
+            None
+        }
     }
 }
 
 /// only to be used as a cli argument function
-pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()> {
+pub async fn gen_new_key_main(
+    sync_backend: &impl SyncBackend,
+    email_id: Option<String>,
+    password: Option<String>,
+    passphrase: Option<String>,
+) -> Result<()> {
+    if let Some(passphrase) = passphrase {
+        return switch_to_passphrase_main(sync_backend, email_id, password, passphrase).await;
+    }
     let mut previous_key_lost = false;
     let mut key_default_path = get_config_dir().unwrap();
     key_default_path.push(ENCRYPTION_KEY_FILE_NAME);
@@ -283,20 +316,19 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
         );
         previous_key_lost = true;
     }
-    print_info("Trying to login...");
-    let (access_token, user_id, _refresh_token) =
-        match login_for_user(&email_id, &password, false).await {
-            Ok((access_token, user_id, refresh_token)) => (access_token, user_id, refresh_token),
-            Err(err) => {
-                print_debug(&format!("Error logging in: {:?}", err));
-                print_error("Error logging in, please check your credentials and try again");
-                print_error("Aborting...");
-                return Ok(());
-            }
+    let (access_token, user_id) =
+        if let Some(login) = login_for_key_change(sync_backend, email_id, password).await {
+            login
+        } else {
+            return Ok(());
         };
-    let save_ids =
-        get_all_save_ids_and_creation_dates_for_user(user_id.to_owned(), &access_token, true)
-            .await?;
+    let save_ids = get_all_save_ids_and_creation_dates_for_user(
+        sync_backend,
+        user_id.to_owned(),
+        &access_token,
+        true,
+    )
+    .await?;
     if save_ids.is_empty() {
         print_warn("No Cloud save files found");
         print_info("Generating new encryption key...");
@@ -333,6 +365,24 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
             ));
         }
         print_info("-------------------------");
+        let previous_key = if previous_key_lost {
+            None
+        } else {
+            match get_user_encryption_key(None) {
+                Ok(previous_key) => Some(previous_key),
+                Err(err) => {
+                    print_warn("The current encryption key could not be read, it cannot be used to re-encrypt the save files");
+                    print_debug(&format!("Error: {:?}", err));
+                    None
+                }
+            }
+        };
+        if previous_key.is_some() {
+            print_info(
+                "Input 'R' to re-encrypt all the save files with a new encryption key (recommended)",
+            );
+            print_info("or");
+        }
         print_warn("Input 'Y' to delete all the save files and generate a new encryption key");
         print_info("or");
         print_info(
@@ -347,11 +397,50 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
         std::io::stdin().read_line(&mut input).unwrap();
         println!();
         let input = input.trim().to_lowercase();
-        if input == "y" || input == "yes" {
+        let rotate_requested = input == "r" || input == "rotate";
+        if let Some(previous_key) = previous_key.filter(|_| rotate_requested) {
+            print_info("Preparing to re-encrypt save files with a new encryption key...");
+            let key = generate_new_encryption_key();
+            match rotate_encryption_key_for_cloud_saves(
+                sync_backend,
+                &user_id,
+                &access_token,
+                &previous_key,
+                &key,
+                true,
+            )
+            .await
+            {
+                Ok(re_encrypted_saves) => {
+                    print_info(&format!(
+                        "{} save files re-encrypted with the new encryption key",
+                        re_encrypted_saves
+                    ));
+                    print_info(&format!(
+                        "New Key generated_at: {}",
+                        key_default_path.display()
+                    ));
+                    print_info(
+                        "Please keep this key safe as it will be required to access your save files",
+                    );
+                }
+                Err(err) => {
+                    print_error(&format!("Error rotating encryption key: {}", err));
+                    print_error("The previous encryption key has been kept");
+                    return Ok(());
+                }
+            }
+        } else if input == "y" || input == "yes" {
             for save_id in save_ids {
                 print_info(&format!("Deleting save file: {}", save_id.0));
-                let delete_status =
-                    delete_a_save_from_database(&access_token, true, save_id.2 as u64, None).await;
+                let delete_status = delete_a_save_from_database(
+                    sync_backend,
+                    &access_token,
+                    true,
+                    save_id.2 as u64,
+                    None,
+                )
+                .await;
                 if delete_status.is_err() {
                     print_error("Error deleting save file");
                     print_debug(&format!("Error: {:?}", delete_status.err()));
@@ -384,12 +473,356 @@ pub async fn gen_new_key_main(email_id: String, password: String) -> Result<()>
     Ok(())
 }
 
+/// Re-encrypts the cloud saves with a key derived from the passphrase, after
+/// which the key file is no longer needed
+async fn switch_to_passphrase_main(
+    sync_backend: &impl SyncBackend,
+    email_id: Option<String>,
+    password: Option<String>,
+    passphrase: String,
+) -> Result<()> {
+    let (access_token, user_id) =
+        if let Some(login) = login_for_key_change(sync_backend, email_id, password).await {
+            login
+        } else {
+            return Ok(());
+        };
+    let salt = match get_or_create_encryption_key_salt(sync_backend, &access_token, true).await {
+        Ok(salt) => salt,
+        Err(err) => {
+            print_error("Error getting encryption key salt");
+            print_debug(&format!("Error: {:?}", err));
+            return Ok(());
+        }
+    };
+    if let Err(err) = save_encryption_key_salt_locally(&salt) {
+        print_warn("Could not cache the encryption key salt, it will be fetched on login");
+        print_debug(&format!("Error: {:?}", err));
+    }
+    print_info("Deriving encryption key from passphrase...");
+    let new_key = derive_encryption_key_from_passphrase(&passphrase, &salt)?;
+    let save_ids = get_all_save_ids_and_creation_dates_for_user(
+        sync_backend,
+        user_id.to_owned(),
+        &access_token,
+        true,
+    )
+    .await?;
+    if save_ids.is_empty() {
+        print_info("No Cloud save files found, new save files will use the passphrase");
+        return Ok(());
+    }
+    print_info(&format!("{} save files found", save_ids.len()));
+    let previous_key = match get_user_encryption_key(None) {
+        Ok(previous_key) => previous_key,
+        Err(err) => {
+            print_error("The current encryption key could not be read, it is needed to re-encrypt the save files");
+            print_error("Use -g without a passphrase to delete the save files instead");
+            print_debug(&format!("Error: {:?}", err));
+            return Ok(());
+        }
+    };
+    if previous_key == new_key {
+        print_info("The save files are already encrypted with this passphrase");
+        return Ok(());
+    }
+    match rotate_encryption_key_for_cloud_saves(
+        sync_backend,
+        &user_id,
+        &access_token,
+        &previous_key,
+        &new_key,
+        false,
+    )
+    .await
+    {
+        Ok(re_encrypted_saves) => {
+            print_info(&format!(
+                "{} save files re-encrypted with the passphrase",
+                re_encrypted_saves
+            ));
+            print_info(&format!(
+                "Start the app with the passphrase to access them, the key file at {} is no longer needed",
+                get_config_dir()
+                    .map(|dir| dir.join(ENCRYPTION_KEY_FILE_NAME).display().to_string())
+                    .unwrap_or(ENCRYPTION_KEY_FILE_NAME.to_string())
+            ));
+        }
+        Err(err) => {
+            print_error(&format!("Error re-encrypting save files: {}", err));
+            print_error("The previous encryption key has been kept");
+        }
+    }
+    Ok(())
+}
+
+/// Reads the encryption passphrase from a file descriptor, an interactive
+/// prompt or the environment, in that order
+pub fn read_encryption_passphrase(
+    prompt: bool,
+    passphrase_fd: Option<i32>,
+) -> Result<Option<String>, String> {
+    let passphrase = if let Some(passphrase_fd) = passphrase_fd {
+        read_passphrase_from_fd(passphrase_fd)?
+    } else if prompt {
+        rpassword::prompt_password("Encryption passphrase: ")
+            .map_err(|e| format!("Error reading passphrase: {}", e))?
+    } else if let Ok(passphrase) = std::env::var(ENCRYPTION_PASSPHRASE_ENV_VAR) {
+        passphrase
+    } else {
+        return Ok(None);
+    };
+    if passphrase.is_empty() {
+        return Err("Encryption passphrase cannot be empty".to_string());
+    }
+    Ok(Some(passphrase))
+}
+
+#[cfg(unix)]
+fn read_passphrase_from_fd(passphrase_fd: i32) -> Result<String, String> {
+    use std::{
+        io::{BufRead, BufReader},
+        os::fd::FromRawFd,
+    };
+    // Safety: the descriptor is handed over by the caller through --passphrase-fd
+    // and is not used anywhere else in the app
+    let passphrase_file = unsafe { std::fs::File::from_raw_fd(passphrase_fd) };
+    let mut passphrase = String::new();
+    BufReader::new(passphrase_file)
+        .read_line(&mut passphrase)
+        .map_err(|e| format!("Error reading passphrase from fd {}: {}", passphrase_fd, e))?;
+    Ok(passphrase.trim_end_matches(['\n', '\r']).to_string())
+}
+
+#[cfg(not(unix))]
+fn read_passphrase_from_fd(_passphrase_fd: i32) -> Result<String, String> {
+    Err("Reading the passphrase from a file descriptor is only supported on unix".to_string())
+}
+
+/// Derives the encryption key before the app starts when the salt is cached,
+/// so the refresh token can be decrypted for auto login
+pub fn derive_encryption_key_from_cached_salt(passphrase: &str) -> Option<String> {
+    let salt = get_local_encryption_key_salt().ok()?;
+    let key = derive_encryption_key_from_passphrase(passphrase, &salt).ok()?;
+    let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    Some(base64::Engine::encode(&base64_engine, key))
+}
+
 pub fn reset_app_main() {
     print_info("🚀 Resetting config");
     reset_config();
     print_info("👍 Config reset");
 }
 
+pub fn migrate_save_file_main(file_path: &Path, encryption_key_override: Option<String>) {
+    print_info(&format!("🚀 Migrating {}", file_path.display()));
+    // Only encrypted saves are read and written back with the key
+    let encryption_key = if is_encrypted_file(file_path) {
+        get_user_encryption_key(encryption_key_override).ok()
+    } else {
+        None
+    };
+    // Older saves store dates formatted with the configured format
+    let date_time_format = get_config(true)
+        .map(|config| config.date_time_format)
+        .unwrap_or_default();
+    match migrate_save_file(file_path, encryption_key.as_deref(), date_time_format) {
+        Ok(Some((backup_file_path, migration_report))) => {
+            for dropped_date in &migration_report.dropped_dates {
+                print_warn(&format!("Dropped unreadable {}", dropped_date));
+            }
+            if !migration_report.dropped_dates.is_empty() {
+                print_warn(&format!(
+                    "The original dates are kept in {}",
+                    backup_file_path.display()
+                ));
+            }
+            print_info(&format!(
+                "👍 Migrated to schema version {}, the original was backed up to {}",
+                SAVE_FILE_SCHEMA_VERSION,
+                backup_file_path.display()
+            ))
+        }
+        Ok(None) => print_info(&format!(
+            "👍 Already at schema version {}, nothing to migrate",
+            SAVE_FILE_SCHEMA_VERSION
+        )),
+        Err(e) => print_error(&e),
+    }
+}
+
+/// only to be used as a cli argument function, runs the reminders that came due since the last
+/// run so it can be called from cron
+pub fn remind_main(window: u32, encryption_key_override: Option<String>) {
+    let config = get_config(true).unwrap_or_default();
+    let last_run_file_path =
+        get_config_dir().map(|config_dir| config_dir.join(REMINDER_LAST_RUN_FILE_NAME));
+    let now = Local::now();
+    let last_run = last_run_file_path
+        .as_ref()
+        .ok()
+        .and_then(|last_run_file_path| fs::read_to_string(last_run_file_path).ok())
+        .and_then(|last_run| DateTime::parse_from_rfc3339(last_run.trim()).ok())
+        .map(|last_run| last_run.with_timezone(&Local))
+        .unwrap_or(now - chrono::Duration::minutes(window as i64));
+    // Only needed for encrypted saves, read_local_file reports a missing key if it is
+    let encryption_key = get_user_encryption_key(encryption_key_override).ok();
+    let boards = match get_latest_local_boards(&config, encryption_key.as_deref()) {
+        Ok(boards) => boards,
+        Err(e) => {
+            print_error(&e);
+            return;
+        }
+    };
+    let due_reminders = get_due_reminders(&boards, last_run, now);
+    if due_reminders.is_empty() {
+        print_debug("No reminders due");
+    }
+    for reminder in due_reminders {
+        print_info(&format!("⏰ {}", reminder.message(config.date_time_format)));
+        if config.reminder_command.is_empty() {
+            continue;
+        }
+        match build_reminder_command(&config.reminder_command, &reminder, config.date_time_format) {
+            Ok((program, args)) => {
+                match std::process::Command::new(&program).args(&args).status() {
+                    Ok(status) if !status.success() => print_error(&format!(
+                        "Reminder command {} exited with {}",
+                        program, status
+                    )),
+                    Err(e) => print_error(&format!(
+                        "Could not run reminder command {}: {}",
+                        program, e
+                    )),
+                    _ => {}
+                }
+            }
+            Err(e) => print_error(&e),
+        }
+    }
+    match last_run_file_path {
+        Ok(last_run_file_path) => {
+            if let Err(e) = fs::write(&last_run_file_path, now.to_rfc3339()) {
+                print_error(&format!(
+                    "Could not record the reminder run in {}: {}",
+                    last_run_file_path.display(),
+                    e
+                ));
+            }
+        }
+        Err(e) => print_error(&e),
+    }
+}
+
+/// only to be used as a cli argument function, exports the boards of the latest local save
+pub fn export_main(
+    format: ExportFormat,
+    output: Option<&Path>,
+    encryption_key_override: Option<String>,
+) {
+    let config = get_config(true).unwrap_or_default();
+    let encryption_key = get_user_encryption_key(encryption_key_override).ok();
+    let boards = match get_latest_local_boards(&config, encryption_key.as_deref()) {
+        Ok(boards) => boards,
+        Err(e) => {
+            print_error(&e);
+            return;
+        }
+    };
+    let export_path = output
+        .map(|output| output.to_path_buf())
+        .unwrap_or(get_default_export_path(&config, format));
+    let mut all_themes = Theme::all_default_themes();
+    all_themes.extend(get_saved_themes().unwrap_or_default());
+    let theme = all_themes
+        .into_iter()
+        .find(|theme| theme.name == config.default_theme)
+        .unwrap_or_default();
+    match export_boards(&boards, format, &theme, &config, &export_path) {
+        Ok(()) => print_info(&format!("👍 Exported boards to {}", export_path.display())),
+        Err(e) => print_error(&format!(
+            "Cannot export boards to {}: {}",
+            export_path.display(),
+            e
+        )),
+    }
+}
+
+/// only to be used as a cli argument function, adds the cards of a todo.txt file to a board of
+/// the latest local save and writes the result as a new save
+pub fn import_todo_txt_main(
+    file_path: &Path,
+    board_name: &str,
+    encryption_key_override: Option<String>,
+) {
+    let config = get_config(true).unwrap_or_default();
+    let encryption_key = get_user_encryption_key(encryption_key_override).ok();
+    if config.encrypt_local_saves && encryption_key.is_none() {
+        print_error("Cannot encrypt local save, encryption key not found. Please use --encryption-key or --passphrase");
+        return;
+    }
+    let mut boards = match get_latest_local_boards(&config, encryption_key.as_deref()) {
+        Ok(boards) => boards,
+        Err(e) => {
+            print_error(&e);
+            return;
+        }
+    };
+    let (cards, skipped_lines) = match fs::read_to_string(file_path) {
+        Ok(content) => parse_todo_txt(&content),
+        Err(e) => {
+            print_error(&format!("Cannot read {}: {}", file_path.display(), e));
+            return;
+        }
+    };
+    for skipped_line in skipped_lines {
+        print_warn(&format!("Skipped {}", skipped_line));
+    }
+    let board = boards
+        .get_mut_boards()
+        .iter_mut()
+        .find(|board| board.name == board_name);
+    let board = if let Some(board) = board {
+        board
+    } else {
+        print_error(&format!(
+            "Board '{}' not found, available boards are: {}",
+            board_name,
+            boards
+                .get_boards()
+                .iter()
+                .map(|board| format!("'{}'", board.name))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ));
+        return;
+    };
+    let imported_cards = cards.len();
+    for card in cards {
+        board.cards.add_card(card);
+    }
+    let save_encryption_key = if config.encrypt_local_saves {
+        encryption_key.as_deref()
+    } else {
+        None
+    };
+    match save_kanban_state_locally(boards.get_boards().clone(), &config, save_encryption_key) {
+        Ok(()) => print_info(&format!(
+            "👍 Imported {} cards into '{}'",
+            imported_cards, board_name
+        )),
+        Err(e) => print_error(&format!("Cannot save imported cards: {}", e)),
+    }
+}
+
+fn get_latest_local_boards(
+    config: &AppConfig,
+    encryption_key: Option<&[u8]>,
+) -> Result<Boards, String> {
+    let latest_save_file = get_latest_save_file(config)?;
+    get_local_kanban_state(latest_save_file, true, config, encryption_key)
+}
+
 pub fn print_error(error: &str) {
     bunt::println!("{$red}[ERROR]{/$} - {}", error);
 }